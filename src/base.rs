@@ -102,4 +102,23 @@ pub trait CastImpl<T> {
     /// Returns `Err` if the cast is lossy; that is, if the casted value is not numerically equal
     /// to `self`
     fn cast_impl(self) -> Result<T, Self::Error>;
-}
\ No newline at end of file
+}
+
+/// Provides the base trait for [`CastSplit`](crate::casts::CastSplit); implement this to extend
+/// [`CastSplit`](crate::casts::CastSplit) to new types.
+///
+/// This relates to [`CastSplit`](crate::casts::CastSplit) just as [`CastImpl`] relates to
+/// [`Cast`](crate::casts::Cast).
+pub trait CastSplitImpl<T>: Sized {
+    /// Specifies the error type returned from [`cast_split_impl`](CastSplitImpl::cast_split_impl)
+    /// and by extension from [`CastSplit::cast_split`](crate::casts::CastSplit::cast_split)
+    type Error;
+
+    /// Splits `self` into an integral part of type `T` and a fractional remainder; see
+    /// [`CastSplit::cast_split`](crate::casts::CastSplit::cast_split) for details and invariants to
+    /// uphold.
+    ///
+    /// # Errors
+    /// Returns `Err` if the integral part is not representable by `T`
+    fn cast_split_impl(self) -> Result<(T, Self), Self::Error>;
+}
@@ -44,6 +44,7 @@
 //! | [`CastTo`]            | all primitives                |
 //! | [`CastToClosest`]     | all primitives + `NonZero*`   |
 //! | [`CastToLossless`]    | all primitives + `NonZero*` where guaranteed lossless by types alone |  
+//! | [`CastToPortableLossless`] | all primitives + `NonZero*` where lossless on every platform |
 //! 
 //! Casting traits supported by each bounding trait:
 //!   
@@ -52,11 +53,18 @@
 //! | [`CastTo`]         | ✔       | ✔                   | ✔          | ✔         |              | 
 //! | [`CastToClosest`]  | ✔       |                     | ✔           |           |              |
 //! | [`CastToLossless`] | ✔       |                     |             |           | ✔            |
+//! | [`CastToPortableLossless`] | ✔ |                  |             |           | ✔            |
 //!
 //! Note that [`Bitwise`](crate::casts::Bitwise) is not supported by any of the bounding traits.
+//!
+//! Note also that for the types parameterized by const generics, such as
+//! [`Ranged`](crate::types::Ranged), [`CastToLossless`] is satisfied for every combination of
+//! parameters, and an invalid combination is only rejected once the call to
+//! [`Lossless::lossless`] is instantiated; see
+//! [`Lossless`](crate::casts::Lossless#const-generic-types) for details.
 
 use crate::base::CastImpl;
-use crate::casts::{AssumedLossless, Cast, Closest, Lossless, Lossy};
+use crate::casts::{AssumedLossless, Cast, Closest, Lossless, Lossy, PortableLossless};
 use core::fmt::{Debug, Display};
 
 #[cfg(feature = "std")]
@@ -71,6 +79,7 @@ use std::error::Error;
 /// `NonZero*` family of numbers in addition to the primitives
 /// * [`CastToLossless`]: only supports a subset of source and target types, but guarantees 
 /// a lossless cast at compilation time via the [`Lossless`] trait
+/// * [`CastToPortableLossless`]: like [`CastToLossless`], but portable to every platform
 ///
 /// # Examples
 ///
@@ -205,6 +214,11 @@ pub trait CastToClosest<T> : Cast + CastImpl<T, Error = <Self as CastToClosest<T
 /// platform. This is powerful when applicable but ultimately limited in scope; if your use case 
 /// does not match consider using [`CastTo`] or [`CastToClosest`] instead.
 ///
+/// For types with const generic parameters, such as [`Ranged`](crate::types::Ranged), this bound is
+/// satisfied regardless of the parameters; an invalid combination fails to compile only when the
+/// function is instantiated with it and built, and is not reported by `cargo check`. See
+/// [`Lossless`](crate::casts::Lossless#const-generic-types) for details.
+///
 /// # Examples
 /// ```
 /// use cove::prelude::*;
@@ -223,6 +237,19 @@ pub trait CastToClosest<T> : Cast + CastImpl<T, Error = <Self as CastToClosest<T
 ///     assert_eq!(foo(u64::MAX), usize::MAX);
 /// }
 /// ```
+///
+/// ```compile_fail
+/// use cove::prelude::*;
+/// use cove::bounds::CastToLossless;
+/// use cove::types::Ranged;
+///
+/// fn foo(x: impl CastToLossless<u8>) -> u8 {
+///     x.cast().lossless()
+/// }
+///
+/// // The bound is satisfied, but the range could exceed u8, so building this fails
+/// foo(Ranged::<u16, 0, 300>::new(7).unwrap());
+/// ```
 pub trait CastToLossless<T>: Cast + CastImpl<T, Error = <Self as CastToLossless<T>>::_Error> {
     /// This associated type is intended for internal use only; it is part of a workaround for Rust
     /// not yet (as of 1.78.0) supporting trait aliases in stable, nor elaborating where clauses to 
@@ -234,4 +261,50 @@ pub trait CastToLossless<T>: Cast + CastImpl<T, Error = <Self as CastToLossless<
     #[doc(hidden)]
     #[cfg(not(feature = "std"))]
     type _Error: Copy + Debug + Display + Lossless<T>;
-}
\ No newline at end of file
+}
+
+/// Provides a convenience subtrait for use with bounding generic function parameters
+///
+/// This bounding trait only supports casts which are guaranteed to be lossless at compilation time
+/// on every platform as deduced from the types alone, such as u16 → usize or u8 → u32. Unlike
+/// [`CastToLossless`], a function bounded by this trait compiles for every target platform if it
+/// compiles for one; it also supports [`Lossless`] casts via its supertrait.
+///
+/// # Examples
+/// ```
+/// use cove::prelude::*;
+/// use cove::bounds::CastToPortableLossless;
+///
+/// /// Casts `x` to a usize losslessly on any platform
+/// fn foo(x: impl CastToPortableLossless<usize>) -> usize {
+///     x.cast().portable_lossless()
+/// }
+///
+/// assert_eq!(foo(8u8), 8usize);
+/// assert_eq!(foo(u16::MAX), 65_535usize);
+/// ```
+///
+/// ```compile_fail
+/// use cove::prelude::*;
+/// use cove::bounds::CastToPortableLossless;
+///
+/// fn foo(x: impl CastToPortableLossless<usize>) -> usize {
+///     x.cast().portable_lossless()
+/// }
+///
+/// // u64 -> usize is only lossless on platforms where usize is 64+ bits
+/// assert_eq!(foo(8u64), 8usize);
+/// ```
+pub trait CastToPortableLossless<T>:
+    Cast + CastImpl<T, Error = <Self as CastToPortableLossless<T>>::_Error> {
+    /// This associated type is intended for internal use only; it is part of a workaround for Rust
+    /// not yet (as of 1.78.0) supporting trait aliases in stable, nor elaborating where clauses to 
+    /// subtraits. Both are open issues, hence the workaround.
+    #[doc(hidden)]
+    #[cfg(feature = "std")]
+    type _Error: Copy + Debug + Display + Error + PortableLossless<T>;
+
+    #[doc(hidden)]
+    #[cfg(not(feature = "std"))]
+    type _Error: Copy + Debug + Display + PortableLossless<T>;
+}
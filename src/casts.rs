@@ -43,6 +43,10 @@
 //!     * Akin to [`From`]/[`Into`] but trades off portability guarantees for a broader scope (e.g.
 //!         support for [`usize`]/[`isize`])
 //!     * Zero-overhead: generally optimizes to the same assembly as the `as` keyword
+//! * [`PortableLossless`]: for compile-time lossless casts which are lossless on every platform
+//!     * Like [`Lossless`], but will not compile for casts which could be lossy on any platform
+//!     * Akin to [`From`]/[`Into`] but also supports [`usize`]/[`isize`] where always lossless
+//!     * Zero-overhead: generally optimizes to the same assembly as the `as` keyword
 //! * [`Lossy`]: for casts where lossiness is acceptable with no general guarantees on the accuracy
 //!     * Most akin to the `as` keyword but self-documents the intent and works in generic contexts
 //!     * Very situational: consider one of the other extension traits instead or check the 
@@ -59,10 +63,38 @@
 //!     assert_eq!(1_000_000_000u64.cast::<u8>().closest(), 255u8);
 //!     ```
 //!     * **NOT** zero-overhead: generally involves at least one branch over the `as` keyword
+//! * [`Rounded`]: for float-to-integer casts which round according to a chosen [`RoundingMode`]
+//!     * Like [`Closest`], saturates to the `MAX` or `MIN` of the target type
+//!     ```
+//!     # use cove::prelude::*;
+//!     # use cove::casts::RoundingMode;
+//!     assert_eq!(7.9f64.cast::<u8>().rounded(RoundingMode::Floor), 7u8);
+//!     ```
+//!     * **NOT** zero-overhead: generally involves several branches over the `as` keyword
+//! * [`Stochastic`]: for float-to-integer and float narrowing casts which round randomly
+//!     * Rounds to either neighbor with probability according to proximity, so is unbiased
+//!     * Draws randomness from a caller-supplied [`StochasticRng`]
+//!     * **NOT** zero-overhead: generally involves several branches over the `as` keyword
+//! * [`Within`]: for casts which are acceptable if they lose less than a [`Tolerance`]
+//!     * Returns the [`Result`], converting errors within the tolerance into the lossy value
+//!     * **NOT** zero-overhead: involves floating point arithmetic over the `as` keyword
 //! * [`Bitwise`]: for casts which preserve the bits rather than numerical value
 //!     * Most akin to [`core::mem::transmute`], but isn't unsafe
 //!     * Requires source and target types to be the same size
 //!     * Zero-overhead: generally optimizes to the same assembly as [`core::mem::transmute`] 
+//! * [`Wrapping`]: for casts to integers which wrap around modulo 2<sup>N</sup>
+//!     * Like [`Lossy`] for integer sources, but guarantees the two's complement semantics
+//!     * Defines wrapping for floating point sources: truncate, then wrap
+//!
+//! ## Splitting Casts
+//! For casts from floating point numbers to integers, [`CastSplit::cast_split`] offers an
+//! alternative to [`Cast::cast`] which keeps the fractional part rather than reporting it as loss:
+//! ```
+//! use cove::prelude::*;
+//!
+//! assert_eq!(7.25f64.cast_split::<u32>()?, (7u32, 0.25f64));
+//! # Ok::<(), cove::errors::LossyCastError<f64, u32>>(())
+//! ```
 //!
 //! # Supported Casts
 //! Not all follow-on cast types make sense for all numerical conversions; attempting to use an
@@ -71,14 +103,34 @@
 //!
 //! * [`Cast`] and [`Closest`] are supported for all casts between all primitive numerical types as 
 //!     well as the `NonZero*` family of non-zero integers from [`core::num`].
+//! * [`Rounded`] is supported for all casts from floating point primitives to integers.
+//! * [`Stochastic`] is supported for casts from floats to primitive integers and from f64 to f32.
 //! * [`Lossy`] and [`AssumedLossless`] are supported whenever the target type is a primitive 
 //!     number.
 //! * [`Lossless`] is supported whenever [`From`]/[`Into`] is supported as well as to/from [`usize`] 
 //!     / [`isize`] / [`NonZeroUsize`](core::num::NonZeroUsize) /
 //!     [`NonZeroIsize`](core::num::NonZeroIsize) when this is guaranteed lossless on the target
 //!     platform.
+//! * [`PortableLossless`] is supported whenever [`Lossless`] is supported on every platform.
 //! * [`Bitwise`] is supported whenever the source and target types are the same size and [`Lossy`] 
 //!     or [`Lossless`] is supported.
+//! * [`Wrapping`] is supported whenever the target type is a primitive integer.
+//! * [`Within`] is supported for all casts between numerical types which may be lossy.
+//! * The [`Wrapping`](core::num::Wrapping) and [`Saturating`](core::num::Saturating) wrappers
+//!     from [`core::num`] support the same casts as the types they wrap, except for
+//!     [`PortableLossless`], [`Rounded`], [`Stochastic`], and [`Wrapping`].
+//! * [`Cast`] and [`Closest`] are supported between [`char`] / [`bool`] and the primitive integers
+//!     and `NonZero*`; values which are not scalar values or not 0 / 1 fail, and [`Closest`] maps
+//!     invalid scalar values to [`char::REPLACEMENT_CHARACTER`]. Casts from [`char`] to integers
+//!     which cannot hold every scalar value are [`Lossy`], truncating as for the `as` keyword.
+//! * [`Lossless`] is supported from [`bool`] to every primitive integer, and between [`char`] and
+//!     every primitive integer which holds the range of the source type.
+//! * [`Duration`](core::time::Duration) supports casts to and from the time units in
+//!     [`types`](crate::types), such as [`Secs`](crate::types::Secs).
+//! * `Option<NonZero*>` supports the same casts as its underlying primitive, with [`None`] as
+//!     zero, except for [`Rounded`], [`Stochastic`], and [`Wrapping`]; [`Bitwise`] relies on the
+//!     guaranteed layout of [`None`] as zero.
+//! * The additional numerical [`types`](crate::types) provided by cove document their own support.
 //!
 //! # Guidelines
 //! It might seem challenging to determine which type of cast to use in which circumstances. While
@@ -96,17 +148,22 @@
 //!     * Favor cove's casts over [`TryFrom`]/[`TryInto`] or the `as` keyword
 //!     * Favor [`Lossless`] if provided for your use case and you'd rather detect portability 
 //!         errors at compile time than runtime
+//!     * Favor [`PortableLossless`] over [`Lossless`] if the code must build for every platform
 //!     * Favor [`AssumedLossless`] if confident the cast will always be lossless but [`Lossless`] 
 //!         isn't defined for the types
 //!     * Favor [`Cast`] with error handling if only lossless casts should proceed and runtime 
 //!         detection is required
 //!     * Favor [`Closest`] when best-effort lossiness is acceptable
+//!     * Favor [`Rounded`] over [`Closest`] when a float-to-integer cast must round a certain way
+//!     * Favor [`Stochastic`] over [`Closest`] when accumulated lossy casts must be unbiased
+//!     * Favor [`Within`] over [`Closest`] when only sufficiently accurate casts should proceed
 //!     * Favor [`Bitwise`] when only the bit values are relevant; this sees particular usage in FFI
+//!     * Favor [`Wrapping`] over [`Lossy`] when modular arithmetic is actually intended
 //!     * Use [`Lossy`] in niche circumstances; favor this over the `as` keyword
-//!         * Exception: in some const contexts it may be necessary to use the `as` keyword, since
-//!             const trait support is limited
+//!         * Exception: in const contexts, use the functions in [`konst`](crate::konst) instead,
+//!             since const trait support is limited
 
-use crate::base::CastImpl;
+use crate::base::{CastImpl, CastSplitImpl};
 
 /// Extension trait for fallibly casting between numerical types with error detection
 ///
@@ -136,8 +193,13 @@ pub trait Cast {
     /// * [`AssumedLossless`]: for when the cast is expected to always be lossless
     /// * [`Closest`]: for when an approximation of the value is acceptable
     /// * [`Lossless`]: for when the cast is guaranteed at compile time to be lossless
+    /// * [`PortableLossless`]: for when the cast is guaranteed lossless on every platform
     /// * [`Bitwise`]: for when the bits should be preserved rather than the numerical value
     /// * [`Lossy`]: for niche circumstances when behavior akin to `as` is desirable
+    /// * [`Rounded`]: for when a float-to-integer cast should round in a particular direction
+    /// * [`Stochastic`]: for when rounding should be random but unbiased
+    /// * [`Within`]: for when the cast should only proceed if the loss is within a tolerance
+    /// * [`Wrapping`]: for when the value should wrap around modulo 2<sup>N</sup>
     /// 
     /// # Errors
     /// Returns [`Err`] if the cast is lossy, meaning that the numerical value (in the
@@ -195,6 +257,54 @@ pub trait Cast {
     }
 }
 
+/// Extension trait for casting a floating point number to an integer while retaining its
+/// fractional part
+///
+/// Where [`Cast::cast`] reports any fractional part of the value as loss, [`CastSplit::cast_split`]
+/// splits the value into its integral part (truncated towards zero) and its fractional remainder,
+/// which are returned together. The remainder has the same sign as the origin value and is exact;
+/// that is, the integral part casted back to the floating point type plus the remainder always
+/// equals the origin value. This is useful for e.g. splitting seconds into whole seconds and
+/// subseconds or currency into whole units and cents.
+///
+/// # Support
+/// Cove provides support for [`CastSplit`] for all casts from floating point primitives to integer
+/// primitives.
+pub trait CastSplit {
+    /// Attempts to split this floating point number into an integer of type `T` and a fractional
+    /// remainder. Depending on the calling context, it may be necessary to disambiguate the target
+    /// type, as with the turbofish operator (`::<>`).
+    ///
+    /// # Errors
+    /// Returns [`Err`] if the integral part is not representable by `T`, including for NaN and
+    /// ±infinity. For the implementations provided by cove, the error is the same
+    /// [`LossyCastError`](crate::errors::LossyCastError) as would be returned by [`Cast::cast`],
+    /// so that the follow-on extension traits remain available on it.
+    ///
+    /// Note that the integral part of a negative value greater than -1 is 0, so such values can
+    /// be split for unsigned targets.
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    ///
+    /// // Split a float into its integral and fractional parts
+    /// assert_eq!(7.25f64.cast_split::<u32>()?, (7u32, 0.25f64));
+    /// assert_eq!((-7.25f32).cast_split::<i8>()?, (-7i8, -0.25f32));
+    /// assert_eq!((-0.5f64).cast_split::<u8>()?, (0u8, -0.5f64));
+    ///
+    /// // Integral parts outside of the target range are reported just as by Cast
+    /// assert_eq!(256.5f64.cast_split::<u8>().unwrap_err().to, 255u8);
+    /// assert_eq!((-1.5f32).cast_split::<u64>().unwrap_err().closest(), 0u64);
+    /// assert!(f64::NAN.cast_split::<i32>().is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    fn cast_split<T>(self) -> Result<(T, Self), Self::Error> where Self: Sized + CastSplitImpl<T> {
+        self.cast_split_impl()
+    }
+}
+
 /// Follow-on extension trait for assuming that the result of a [`Cast::cast`] is lossless
 ///
 /// As a follow-on extension trait, this is intended to be applied to a [`Result`] returned from
@@ -277,7 +387,10 @@ pub trait AssumedLossless<T> {
 /// # Support
 /// Cove provides support for [`crate::casts::Bitwise`] between all primitive numbers of the same 
 /// size, between all `NonZero*` integers of the same size, and from `NonZero*` to 
-/// primitive numerical types of the same size (but not the reverse).
+/// primitive numerical types of the same size (but not the reverse). Cove's floating point types
+/// are supported to and from the integers of the same size, but not between floating point
+/// formats of the same size, such as [`f16`](crate::f16) and [`bf16`](crate::bf16), since those
+/// casts do not preserve the payloads of NaNs.
 pub trait Bitwise<T> {
     /// Called on a [`Result`] returned from [`Cast::cast`] to use the bit-equivalent value of the 
     /// destination type.
@@ -417,6 +530,7 @@ pub trait Closest<T> {
 /// [`Lossless`] sacrifices a measure of portability to gain a broader scope. Be aware of this
 /// tradeoff when considering which mechanism to use. As a rule of thumb, you should not use
 /// [`Lossless`] if your use case allows for [`From`]/[`Into`]; just use one of those instead.
+/// If portability matters but [`From`]/[`Into`] is too restrictive, consider [`PortableLossless`].
 ///
 /// # Support
 /// Cove provides support for [`Lossless`] whenever [`From`]/[`Into`] is supported. In
@@ -434,7 +548,27 @@ pub trait Closest<T> {
 /// Note that [`Lossless`] does not support casting from primitives to the `NonZero*` family,
 /// since the origin value could be zero. It does support the reverse: `NonZero*` to primitive 
 /// numbers.
-/// 
+///
+/// # Const Generic Types
+/// For the types of [`types`](crate::types) which are parameterized by const generics, such as
+/// [`Ranged`](crate::types::Ranged), [`UInt`](crate::types::UInt) / [`Int`](crate::types::Int),
+/// [`Fixed`](crate::types::Fixed) and [`Decimal`](crate::types::Decimal), whether a cast is lossless
+/// depends on the values of the parameters, which stable Rust cannot express in trait
+/// implementations. [`Lossless`] is therefore implemented for every combination of parameters, and
+/// the parameters are checked only when a call to [`Lossless::lossless`] is instantiated with
+/// concrete values; an invalid combination is then a compilation error.
+///
+/// This is weaker than the guarantee for the primitives in two ways:
+///
+/// * The error is raised after monomorphization, so it is reported by `cargo build` but not by
+///   `cargo check`
+/// * Trait bounds such as [`CastToLossless`](crate::bounds::CastToLossless) are satisfied by such
+///   a cast even for invalid parameters; the error arises only once a generic function calling
+///   [`Lossless::lossless`] is instantiated with them
+///
+/// Wrappers of these types, such as [`Complex`](crate::types::Complex) and
+/// [`Wrapping`](core::num::Wrapping), inherit the same behavior.
+///
 /// # Safety
 /// Lossless should only be implemented for casts which are truly lossless or undefined behavior
 /// may result. Lossless is generally implemented on types used as error markers for lossless 
@@ -508,6 +642,79 @@ pub unsafe trait Lossless<T> {
     ///
     /// ```
     fn lossless(self) -> T;
+
+    /// This associated constant is intended for internal use only; it supports casts whose 
+    /// losslessness is proven from const generic parameters rather than from the types alone, as
+    /// described in the section on const generic types above. Evaluating it must fail compilation
+    /// if the cast could be lossy.
+    #[doc(hidden)]
+    const _PROOF: () = ();
+}
+
+/// Follow-on extension trait for infallibly casting between numerical types on every platform
+///
+/// As a follow-on extension trait, this is intended to be applied to a [`Result`] returned from
+/// [`Cast::cast`]. This trait is a restriction of [`Lossless`] to those casts which are guaranteed
+/// to be lossless by the involved types on every supported pointer width (16, 32, 64 and 128
+/// bits). Like [`From`]/[`Into`], a cast which compiles on one platform therefore compiles on all
+/// of them; unlike [`From`]/[`Into`], it covers the casts involving [`usize`] / [`isize`] /
+/// [`NonZeroUsize`](core::num::NonZeroUsize) / [`NonZeroIsize`](core::num::NonZeroIsize) which
+/// are lossless regardless of the pointer width, such as [`u16`] → [`usize`] or [`usize`] →
+/// [`u128`].
+///
+/// # Support
+/// Cove provides support for [`PortableLossless`] for every cast between the primitives and the
+/// `NonZero*` family in [`core::num`] which supports [`Lossless`] and does not involve [`usize`] /
+/// [`isize`] / [`NonZeroUsize`](core::num::NonZeroUsize) /
+/// [`NonZeroIsize`](core::num::NonZeroIsize). Casts involving those are supported only when they
+/// are lossless with a pointer width of anywhere from 16 to 128 bits:
+///
+/// * [`u8`] / [`u16`] → [`usize`], and [`u8`] / [`i8`] / [`i16`] → [`isize`]
+/// * [`usize`] → [`u128`] and [`isize`] → [`i128`]
+/// * The corresponding casts from the `NonZero*` family, to both primitives and `NonZero*` types
+///
+/// # Examples
+/// ```
+/// use cove::prelude::*;
+/// use core::num::{NonZeroI16, NonZeroIsize, NonZeroU8};
+///
+/// // Casts which compile on every platform
+/// assert_eq!(7u16.cast::<usize>().portable_lossless(), 7usize);
+/// assert_eq!((-7i8).cast::<isize>().portable_lossless(), -7isize);
+/// assert_eq!(usize::MAX.cast::<u128>().portable_lossless(), usize::MAX as u128);
+/// assert_eq!(3u32.cast::<f64>().portable_lossless(), 3f64);
+/// assert_eq!(NonZeroU8::new(5).unwrap().cast::<usize>().portable_lossless(), 5usize);
+/// assert_eq!(
+///     NonZeroI16::new(-5).unwrap().cast::<NonZeroIsize>().portable_lossless(),
+///     NonZeroIsize::new(-5).unwrap()
+/// );
+/// ```
+///
+/// ```compile_fail
+/// use cove::prelude::*;
+///
+/// // u32 -> usize is lossless on 32-bit and wider platforms, but not on 16-bit platforms
+/// assert_eq!(7u32.cast::<usize>().portable_lossless(), 7usize);
+/// ```
+pub trait PortableLossless<T>: Lossless<T> {
+    /// Unwraps a [`Result`] returned from [`Cast::cast`], extracting its [`Ok`] variant without
+    /// runtime overhead or any possibility of panic. Will only compile for casts for which this
+    /// guarantee can be made on every target platform.
+    ///
+    /// # Performance
+    /// As with [`Lossless::lossless`], the combination of [`Cast::cast`] and
+    /// [`PortableLossless::portable_lossless`] generally compiles to the same assembly as the `as`
+    /// keyword and thus is zero-overhead.
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    ///
+    /// // Index a slice with a value known to fit in a usize on any platform
+    /// let values = [1, 2, 3];
+    /// assert_eq!(values[2u8.cast::<usize>().portable_lossless()], 3);
+    /// ```
+    fn portable_lossless(self) -> T;
 }
 
 /// Follow-on extension trait for accepting the result of a [`Cast::cast`], even if it was lossy
@@ -566,4 +773,298 @@ pub trait Lossy<T> {
     /// assert_eq!(NonZeroI32::new(-300).unwrap().cast::<i8>().lossy(), -44i8);
     /// ```
     fn lossy(self) -> T;
-}
\ No newline at end of file
+}
+
+/// Follow-on extension trait for converting the result of a [`Cast::cast`] into a value rounded
+/// according to an explicit [`RoundingMode`]
+///
+/// As a follow-on extension trait, this is intended to be applied to a [`Result`] returned from
+/// [`Cast::cast`]. When the cast is lossless (that is, [`Ok`] is returned), this just returns
+/// the casted value. Otherwise, this rounds the origin value according to the supplied
+/// [`RoundingMode`] and then saturates it to the `MAX` or `MIN` of the target type if it is still
+/// out of range.
+///
+/// [`Rounded`] is a generalization of [`Closest`] for casts from floating point numbers to
+/// integers: [`Closest`] always rounds with `.5` rounding away from 0, which is equivalent to
+/// [`RoundingMode::HalfAwayFromZero`].
+///
+/// # Floating Point Special Cases
+/// Floating point special values are handled the same way as by [`Closest`]:
+/// * ±infinity saturates to the `MAX` or `MIN` of the target type
+/// * NaN is first converted to 0 and then processed from there
+///
+/// # `NonZero*` Targets
+/// If the rounded value would be zero and the target is one of the `NonZero*` family defined in
+/// [`core::num`], the value is adjusted in the same fashion as [`Closest`]: to 1 for unsigned
+/// targets, and to 1 or -1 according to the sign of the origin value for signed targets.
+///
+/// # Support
+/// Cove provides support for [`Rounded`] for all casts from floating point primitives to integer
+/// primitives and to the `NonZero*` family defined in [`core::num`].
+pub trait Rounded<T> {
+    /// Called on a [`Result`] returned from [`Cast::cast`] to accept the value rounded according
+    /// to `mode`, saturated to the range of the target type.
+    ///
+    /// # Performance
+    /// [`Rounded::rounded`] is **NOT** zero-overhead compared to the `as` keyword, as it involves
+    /// several branches. Passing a constant `mode` allows the optimizer to remove most of them.
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use cove::casts::RoundingMode;
+    /// use core::num::NonZeroU8;
+    ///
+    /// // Pick the rounding direction explicitly
+    /// assert_eq!(2.5f32.cast::<i32>().rounded(RoundingMode::Floor), 2i32);
+    /// assert_eq!(2.5f32.cast::<i32>().rounded(RoundingMode::Ceil), 3i32);
+    /// assert_eq!((-2.5f64).cast::<i32>().rounded(RoundingMode::TowardZero), -2i32);
+    /// assert_eq!((-2.5f64).cast::<i32>().rounded(RoundingMode::HalfAwayFromZero), -3i32);
+    /// assert_eq!(2.5f64.cast::<i32>().rounded(RoundingMode::HalfEven), 2i32);
+    ///
+    /// // Values outside of the target range still saturate
+    /// assert_eq!(300.2f32.cast::<u8>().rounded(RoundingMode::Floor), 255u8);
+    /// assert_eq!((-0.5f32).cast::<u8>().rounded(RoundingMode::Floor), 0u8);
+    ///
+    /// // NonZero* targets never round to zero
+    /// assert_eq!(0.2f64.cast::<NonZeroU8>().rounded(RoundingMode::Floor).get(), 1u8);
+    /// ```
+    fn rounded(self, mode: RoundingMode) -> T;
+}
+
+/// Specifies how [`Rounded`] treats the fractional part of a floating point number
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round towards negative infinity
+    Floor,
+
+    /// Round towards positive infinity
+    Ceil,
+
+    /// Round towards zero; that is, discard the fractional part. This is the rounding performed by
+    /// the `as` keyword.
+    TowardZero,
+
+    /// Round to the nearest integer, with `.5` rounding away from zero. This is the rounding
+    /// performed by [`Closest`].
+    HalfAwayFromZero,
+
+    /// Round to the nearest integer, with `.5` rounding to the nearest even integer. This is also
+    /// known as banker's rounding and corresponds to the `roundTiesToEven` mode of
+    /// `IEEE 754-2008 §4.3.1`.
+    HalfEven
+}
+
+/// Follow-on extension trait for converting the result of a [`Cast::cast`] into a value rounded
+/// stochastically to one of its two nearest representable neighbors
+///
+/// As a follow-on extension trait, this is intended to be applied to a [`Result`] returned from
+/// [`Cast::cast`]. When the cast is lossless (that is, [`Ok`] is returned), this just returns
+/// the casted value. Otherwise, this rounds the origin value either down or up to the adjacent
+/// value of the target type, with the probability of each direction proportional to the origin
+/// value's proximity. For example, `2.25` casts to `2` with probability `0.75` and to `3` with
+/// probability `0.25`.
+///
+/// Unlike [`Closest`], which is deterministic and therefore biased when many values are cast and
+/// then accumulated, the expected value of a stochastically rounded cast is the origin value
+/// itself. This makes [`Stochastic`] suitable for e.g. quantization in machine learning.
+///
+/// Randomness is drawn from a caller-supplied [`StochasticRng`], so that cove need not depend on
+/// any particular random number generator. Each call draws a single [`u64`] from the generator
+/// when the cast is lossy and draws nothing otherwise. The probabilities are resolved to within
+/// 2<sup>-53</sup>.
+///
+/// # Floating Point Special Cases
+/// Values outside of the range of the target type are handled the same way as by [`Closest`]:
+/// * Finite values and ±infinity saturate to the `MAX` or `MIN` of the target type
+/// * NaN is converted to 0 for integer targets and remains NaN for floating point targets
+///
+/// # Support
+/// Cove provides support for [`Stochastic`] for all casts from floating point primitives to integer
+/// primitives, as well as for [`f64`] → [`f32`].
+pub trait Stochastic<T> {
+    /// Called on a [`Result`] returned from [`Cast::cast`] to accept the value rounded
+    /// stochastically using randomness drawn from `rng`.
+    ///
+    /// # Performance
+    /// [`Stochastic::stochastic`] is **NOT** zero-overhead compared to the `as` keyword, as it
+    /// involves several branches and a call to the random number generator.
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use cove::casts::StochasticRng;
+    ///
+    /// // A simple xorshift generator; any source of uniformly random bits will do
+    /// struct XorShift(u64);
+    ///
+    /// impl StochasticRng for XorShift {
+    ///     fn next_u64(&mut self) -> u64 {
+    ///         self.0 ^= self.0 << 13;
+    ///         self.0 ^= self.0 >> 7;
+    ///         self.0 ^= self.0 << 17;
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    ///
+    /// // Values round to one of their two neighbors...
+    /// let value = 2.25f64.cast::<i32>().stochastic(&mut rng);
+    /// assert!(value == 2 || value == 3);
+    ///
+    /// // ...and are unbiased on average
+    /// let sum: i32 = (0..10_000).map(|_| 2.25f64.cast::<i32>().stochastic(&mut rng)).sum();
+    /// assert!((22_000..23_000).contains(&sum));
+    ///
+    /// // Lossless casts are unaffected, as are values outside of the target range
+    /// assert_eq!(7.0f32.cast::<u8>().stochastic(&mut rng), 7u8);
+    /// assert_eq!(300.5f32.cast::<u8>().stochastic(&mut rng), 255u8);
+    /// assert_eq!(1e300f64.cast::<f32>().stochastic(&mut rng), f32::MAX);
+    /// ```
+    fn stochastic<R: StochasticRng + ?Sized>(self, rng: &mut R) -> T;
+}
+
+/// Source of randomness for [`Stochastic`], to be implemented by the caller
+///
+/// Cove deliberately does not depend on any random number generation crate; instead, implement
+/// this trait for whichever generator is at hand (typically by forwarding to its own method of the
+/// same name). The generated bits should be uniformly distributed for [`Stochastic`] to be
+/// unbiased.
+pub trait StochasticRng {
+    /// Returns the next 64 uniformly random bits
+    fn next_u64(&mut self) -> u64;
+}
+
+/// Follow-on extension trait for accepting the result of a [`Cast::cast`] if its loss lies within
+/// a given [`Tolerance`]
+///
+/// As a follow-on extension trait, this is intended to be applied to a [`Result`] returned from
+/// [`Cast::cast`]. When the cast is lossless (that is, [`Ok`] is returned), this just returns
+/// the [`Result`] unchanged. Otherwise, the difference between the origin value and the lossy
+/// value is measured against the [`Tolerance`]: if it is inside the bound, [`Ok`] is returned
+/// with the lossy value, and otherwise the original error is returned.
+///
+/// The difference is computed exactly as a [`Loss`](crate::errors::Loss), even when neither the
+/// origin nor the target type could represent it, e.g. for [`u128`] → [`f32`]. Floating point
+/// special values never lie within any tolerance: NaN, ±infinity and overflow to ±infinity are all
+/// rejected, as are all lossy casts if the tolerance is itself NaN.
+///
+/// # Support
+/// Cove provides support for [`Within`] for all lossy casts between numerical types which report
+/// a [`LossyCastError`](crate::errors::LossyCastError), including the primitives, the `NonZero*`
+/// family defined in [`core::num`] and cove's [`Ranged`](crate::types::Ranged).
+pub trait Within<T>: Sized {
+    /// The error returned when the loss exceeds the tolerance
+    type Error;
+
+    /// Called on a [`Result`] returned from [`Cast::cast`] to accept the lossy value if the loss
+    /// lies within `tolerance`, or to retain the error otherwise.
+    ///
+    /// # Performance
+    /// [`Within::within`] is **NOT** zero-overhead compared to the `as` keyword, as it involves
+    /// floating point arithmetic and several branches.
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use cove::casts::Tolerance;
+    ///
+    /// // Absolute and relative tolerances
+    /// assert_eq!(3.25f64.cast::<i32>().within(Tolerance::Absolute(0.5)), Ok(3i32));
+    /// assert!(3.75f64.cast::<i32>().within(Tolerance::Absolute(0.5)).is_err());
+    /// assert_eq!(1_000_001u32.cast::<f32>().within(Tolerance::Relative(1e-6))?, 1_000_001f32);
+    /// assert!(16_777_217u32.cast::<f32>().within(Tolerance::Relative(1e-9)).is_err());
+    ///
+    /// // Tolerance in units of the last place of the target type
+    /// assert_eq!(0.1f64.cast::<f32>().within(Tolerance::Ulps(1))?, 0.1f32);
+    /// assert!(0.1f64.cast::<f32>().within(Tolerance::Ulps(0)).is_err());
+    ///
+    /// // The original error is retained when the loss is too great
+    /// assert_eq!(300u16.cast::<u8>().within(Tolerance::Ulps(255)).unwrap_err().to, 44u8);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the error produced by [`Cast::cast`] if the loss lies outside of `tolerance`.
+    fn within(self, tolerance: Tolerance) -> Result<T, Self::Error>;
+}
+
+/// Specifies the bound on the loss accepted by [`Within`]
+///
+/// Each variant bounds the absolute difference between the origin value and the lossy value; the
+/// bound is inclusive.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Tolerance {
+    /// Accept a difference of at most the given amount
+    Absolute(f64),
+
+    /// Accept a difference of at most the given fraction of the magnitude of the origin value
+    Relative(f64),
+
+    /// Accept a difference of at most the given number of units in the last place of the lossy
+    /// value. For integer targets this is equivalent to [`Tolerance::Absolute`]; for floating
+    /// point targets the unit is the spacing between adjacent values at the lossy value's
+    /// exponent.
+    Ulps(u64)
+}
+
+/// Follow-on extension trait for casting to an integer with wraparound modulo 2<sup>N</sup>
+///
+/// As a follow-on extension trait, this is intended to be applied to a [`Result`] returned from
+/// [`Cast::cast`]. When the cast is lossless (that is, [`Ok`] is returned), this just returns
+/// the casted value. Otherwise, this reduces the origin value modulo 2<sup>N</sup>, where N is the
+/// bit width of the target integer type, and interprets the result in the target type as two's 
+/// complement. This is the behavior wanted for e.g. hash mixing or sequence numbers.
+///
+/// Unlike [`Lossy`], which makes no general guarantees on the accuracy of its result, [`Wrapping`]
+/// defines its semantics for every supported cast and these semantics will not change between
+/// versions of cove:
+/// * Integer and `NonZero*` sources are reduced modulo 2<sup>N</sup>, just as by the `as` keyword
+/// * Floating point sources are truncated towards zero and then reduced modulo 2<sup>N</sup>
+/// * Floating point NaN and ±infinity have no meaningful remainder and yield 0
+///
+/// Note that for floating point sources this differs from the `as` keyword, which saturates.
+///
+/// # Support
+/// Cove provides support for [`Wrapping`] for all casts to primitive integers from primitive 
+/// numbers and from the `NonZero*` family defined in [`core::num`].
+///
+/// # Name Collisions
+/// This trait shares its name with [`core::num::Wrapping`], which cove also supports casting. The
+/// [`prelude`](crate::prelude) additionally exports this trait anonymously, so importing
+/// [`core::num::Wrapping`] alongside the prelude does not hide the
+/// [`wrapping`](Wrapping::wrapping) method:
+/// ```
+/// use cove::prelude::*;
+/// use core::num::Wrapping;
+///
+/// assert_eq!(300u32.cast::<u8>().wrapping(), 44u8);
+/// assert_eq!(Wrapping(300u32).cast::<u8>().lossy(), 44u8);
+/// ```
+pub trait Wrapping<T> {
+    /// Called on a [`Result`] returned from [`Cast::cast`] to accept the value reduced modulo 
+    /// 2<sup>N</sup> in the target type.
+    ///
+    /// # Performance
+    /// For integer sources, the combination of [`Cast::cast`] and [`Wrapping::wrapping`] generally
+    /// compiles to the same assembly as the `as` keyword and thus is zero-overhead. For floating
+    /// point sources it is **NOT** zero-overhead, as it must decompose the float.
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use core::num::NonZeroU16;
+    ///
+    /// // Integers wrap around just as with the `as` keyword
+    /// assert_eq!(300u32.cast::<u8>().wrapping(), 44u8);
+    /// assert_eq!((-1i64).cast::<u16>().wrapping(), u16::MAX);
+    /// assert_eq!(NonZeroU16::new(u16::MAX).unwrap().cast::<i8>().wrapping(), -1i8);
+    ///
+    /// // Floats are truncated and then wrapped, rather than saturated
+    /// assert_eq!(257.9f32.cast::<u8>().wrapping(), 1u8);
+    /// assert_eq!((-1.5f64).cast::<u32>().wrapping(), u32::MAX);
+    /// assert_eq!(f64::NAN.cast::<i32>().wrapping(), 0i32);
+    /// ```
+    fn wrapping(self) -> T;
+}
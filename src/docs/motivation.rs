@@ -189,7 +189,8 @@
 //!
 //! Printing the resulting error via `Display` yields:
 //! * **conv:** `conversion resulted in positive overflow`
-//! * **cove:** `Numerical cast was lossy [16777217 (u32) -> 16777216 (f32)]`
+//! * **cove:** `Numerical cast was lossy [16777217 (u32) -> 16777216 (f32)]`, or via `detailed`:
+//!   `Numerical cast was lossy (precision) [16777217 (u32) -> 16777216 (f32)]`
 //!
 //! Printing the resulting error via `Debug` yields:
 //! * **conv:** `PosOverflow(..)`
@@ -12,6 +12,18 @@
 //!
 //! let _ = core::num::NonZeroI128::new(1).unwrap().cast::<i64>().lossless();
 //! ```
+//!
+//! ```compile_fail
+//! use cove::prelude::*;
+//!
+//! let _ = 5u16.cast::<cove::types::Ranged<u8, 0, 200>>().lossless();
+//! ```
+//!
+//! ```compile_fail
+//! use cove::prelude::*;
+//!
+//! let _ = 5.0f32.cast::<cove::types::Ranged<i128, { i128::MIN }, { i128::MAX }>>().lossless();
+//! ```
 
 #[cfg(target_pointer_width = "16")]
 /// ```compile_fail
@@ -2,4 +2,5 @@
 //! doctests can check for compilation failure while normal integration tests cannot. It has no
 //! other bearing on Cove's interface or implementation.
 mod bitwise;
-mod lossless;
\ No newline at end of file
+mod lossless;
+mod portable_lossless;
\ No newline at end of file
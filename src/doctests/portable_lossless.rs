@@ -0,0 +1,64 @@
+//! Test expected compilation failures for `PortableLossless`; success cases are tested in normal
+//! integration tests. These must fail to compile on every platform, including those on which the
+//! casts support `Lossless`.
+//!
+//! ```compile_fail
+//! use cove::prelude::*;
+//!
+//! let _ = 0u16.cast::<u8>().portable_lossless();
+//! ```
+//!
+//! ```compile_fail
+//! use cove::prelude::*;
+//!
+//! let _ = 0u32.cast::<usize>().portable_lossless();
+//! ```
+//!
+//! ```compile_fail
+//! use cove::prelude::*;
+//!
+//! let _ = 0u64.cast::<usize>().portable_lossless();
+//! ```
+//!
+//! ```compile_fail
+//! use cove::prelude::*;
+//!
+//! let _ = 0i32.cast::<isize>().portable_lossless();
+//! ```
+//!
+//! ```compile_fail
+//! use cove::prelude::*;
+//!
+//! let _ = 0usize.cast::<u64>().portable_lossless();
+//! ```
+//!
+//! ```compile_fail
+//! use cove::prelude::*;
+//!
+//! let _ = 0isize.cast::<i64>().portable_lossless();
+//! ```
+//!
+//! ```compile_fail
+//! use cove::prelude::*;
+//!
+//! let _ = 0usize.cast::<f64>().portable_lossless();
+//! ```
+//!
+//! ```compile_fail
+//! use cove::prelude::*;
+//!
+//! let _ = core::num::NonZeroU32::new(1).unwrap().cast::<core::num::NonZeroUsize>()
+//!     .portable_lossless();
+//! ```
+//!
+//! ```compile_fail
+//! use cove::prelude::*;
+//!
+//! let _ = core::num::NonZeroIsize::new(1).unwrap().cast::<i64>().portable_lossless();
+//! ```
+//!
+//! ```compile_fail
+//! use cove::prelude::*;
+//!
+//! let _ = 5u16.cast::<cove::types::Ranged<u8, 0, 200>>().portable_lossless();
+//! ```
@@ -14,8 +14,8 @@
 //!     assert_eq!(260u32.cast::<u8>().unwrap_err().from, 260u32);
 //!     assert_eq!(260u32.cast::<u8>().unwrap_err().to, 4u8);
 //!     ```
-//!     * Provides a descriptive message
-//!         * e.g. `"Numerical cast was lossy [260 (u32) -> 4 (u8)]"`
+//!     * Classifies the loss via [`LossyCastError::kind`] and provides a descriptive message
+//!         * e.g. `"Numerical cast was lossy (overflow) [260 (u32) -> 4 (u8)]"`
 //! * [`FailedCastError`]: for lossy casts which are unable to represent
 //!     the lossy value as the target type
 //!     * Used for certain `NonZero*` casts, where representing e.g.
@@ -26,9 +26,28 @@
 //!     # use std::num::NonZeroU8;
 //!     assert_eq!(0u32.cast::<NonZeroU8>().unwrap_err().from, 0u32);
 //!     ```
-//!     * Provides as descriptive an error message as possible
-//!         * e.g. `"Numerical cast failed [0 (u32) -> (core::num::nonzero::NonZeroU8)]"`
+//!     * Classifies the loss via [`FailedCastError::kind`] and provides a descriptive message
+//!         * e.g. `"Numerical cast failed (zero) [0 (u32) -> (core::num::nonzero::NonZeroU8)]"`
+//!
+//! Both [`LossyCastError`] and [`FailedCastError`] report the cause of the loss as a [`LossKind`].
+//! Their [`Display`] implementations omit it, since they are available for any types which
+//! implement [`Display`], including those defined outside of cove; for cove's numerical types,
+//! `detailed` returns a [`Detailed`] adapter whose message includes it:
+//!
+//! ```
+//! # use cove::prelude::*;
+//! let error = 260u32.cast::<u8>().unwrap_err();
+//! assert_eq!(error.to_string(), "Numerical cast was lossy [260 (u32) -> 4 (u8)]");
+//! assert_eq!(
+//!     error.detailed().to_string(),
+//!     "Numerical cast was lossy (overflow) [260 (u32) -> 4 (u8)]"
+//! );
+//! ```
 
+use crate::base::CastImpl;
+use crate::impls::numeric::{classify, difference, grow, Numeric, PARTS};
+use crate::types::{bf16, f16, f128, f8e4m3, f8e5m2, Complex};
+use core::cmp::Ordering;
 use core::fmt::{Debug, Display, Formatter};
 use core::marker::PhantomData;
 
@@ -58,7 +77,12 @@ std::error::Error for LosslessCastError<CastFrom, CastTo> {}
 
 /// Indicates that a cast between numeric types lost data.
 ///
-/// This is used for a majority of cove's casts.
+/// This is used for a majority of cove's casts. When both types are numerical types supported by
+/// cove (the primitives, the `NonZero*` family and [`Ranged`](crate::types::Ranged)), the cause
+/// of the loss is available via [`LossyCastError::kind`], its magnitude via
+/// [`LossyCastError::loss`] (and [`LossyCastError::ulps`] for float targets) and a message
+/// including the cause via [`LossyCastError::detailed`]. [`Display`] is implemented whenever both
+/// types implement it.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct LossyCastError<CastFrom, CastTo> {
     /// The original value before the cast
@@ -68,6 +92,219 @@ pub struct LossyCastError<CastFrom, CastTo> {
     pub to: CastTo
 }
 
+impl<CastFrom: Numeric, CastTo: Numeric> LossyCastError<CastFrom, CastTo> {
+    /// Classifies why the cast was lossy
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use cove::errors::LossKind;
+    ///
+    /// assert_eq!(260u32.cast::<u8>().unwrap_err().kind(), LossKind::Overflow);
+    /// assert_eq!((-1i8).cast::<u64>().unwrap_err().kind(), LossKind::Underflow);
+    /// assert_eq!(2.5f32.cast::<i32>().unwrap_err().kind(), LossKind::Fraction);
+    /// assert_eq!(16_777_217u32.cast::<f32>().unwrap_err().kind(), LossKind::Precision);
+    /// assert_eq!(f64::NAN.cast::<u16>().unwrap_err().kind(), LossKind::NaN);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn kind(&self) -> LossKind {
+        classify::<CastFrom, CastTo>(self.from)
+    }
+
+    /// Returns an adapter which displays the error along with its [`LossKind`]
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    ///
+    /// let error = 16_777_217u32.cast::<f32>().unwrap_err();
+    /// assert_eq!(
+    ///     error.detailed().to_string(),
+    ///     "Numerical cast was lossy (precision) [16777217 (u32) -> 16777216 (f32)]"
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn detailed(&self) -> Detailed<'_, Self> {
+        Detailed(self)
+    }
+
+    /// Measures the signed difference `from - to` of the cast
+    ///
+    /// The difference is computed exactly and without overflow, even when it fits in neither type
+    /// nor in [`f64`]; see [`Loss`]. Casts from NaN yield NaN, and casts from or to ±infinity
+    /// yield ±infinity.
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    ///
+    /// assert_eq!(260u32.cast::<u8>().unwrap_err().loss(), 256.0);
+    /// assert_eq!((-1i8).cast::<u64>().unwrap_err().loss(), -18_446_744_073_709_551_616.0);
+    /// assert_eq!((-2.75f32).cast::<i32>().unwrap_err().loss(), -0.75);
+    /// assert_eq!((u128::MAX >> 1).cast::<f32>().unwrap_err().loss(), -1.0);
+    /// assert!(f64::NAN.cast::<u16>().unwrap_err().loss().to_f64().is_nan());
+    ///
+    /// // The difference of 2^103 - 1 is exact, although f64 would round it to 2^103
+    /// let loss = ((1u128 << 127) + (1 << 103) - 1).cast::<f32>().unwrap_err().loss();
+    /// assert!(loss != 2f64.powi(103) && loss.to_f64() == 2f64.powi(103));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn loss(&self) -> Loss {
+        difference(self.from, self.to)
+    }
+}
+
+impl<CastFrom: Numeric> LossyCastError<CastFrom, f32> {
+    /// Measures the signed difference `from - to` of the cast in units in the last place of `to`
+    ///
+    /// This is [`LossyCastError::loss`] scaled by the spacing of [`f32`] values at `to`, so a cast
+    /// which rounds to the nearest [`f32`] is at most half an ulp away.
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    ///
+    /// assert_eq!(16_777_217u32.cast::<f32>().unwrap_err().ulps(), 0.5);
+    /// assert_eq!((-16_777_219i64).cast::<f32>().unwrap_err().ulps(), 0.5);
+    /// assert_eq!(1e300f64.cast::<f32>().unwrap_err().ulps(), f64::NEG_INFINITY);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ulps(&self) -> f64 {
+        self.loss().to_f64() / self.to.ulp()
+    }
+}
+
+impl<CastFrom: Numeric> LossyCastError<CastFrom, f64> {
+    /// Measures the signed difference `from - to` of the cast in units in the last place of `to`
+    ///
+    /// This is [`LossyCastError::loss`] scaled by the spacing of [`f64`] values at `to`, so a cast
+    /// which rounds to the nearest [`f64`] is at most half an ulp away.
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    ///
+    /// assert_eq!((u64::MAX - 1000).cast::<f64>().unwrap_err().ulps(), -1001.0 / 4096.0);
+    /// assert_eq!(u128::MAX.cast::<f64>().unwrap_err().ulps(), -2f64.powi(-76));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ulps(&self) -> f64 {
+        self.loss().to_f64() / self.to.ulp()
+    }
+}
+
+impl<CastFrom: Numeric> LossyCastError<CastFrom, f16> {
+    /// Measures the signed difference `from - to` of the cast in units in the last place of `to`
+    ///
+    /// This is [`LossyCastError::loss`] scaled by the spacing of [`f16`] values at `to`, so a cast
+    /// which rounds to the nearest [`f16`] is at most half an ulp away.
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use cove::f16;
+    ///
+    /// assert_eq!(2049u32.cast::<f16>().unwrap_err().ulps(), 0.5);
+    /// assert_eq!((-2051i64).cast::<f16>().unwrap_err().ulps(), 0.5);
+    /// assert_eq!(1e5f64.cast::<f16>().unwrap_err().ulps(), f64::NEG_INFINITY);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ulps(&self) -> f64 {
+        self.loss().to_f64() / self.to.ulp()
+    }
+}
+
+impl<CastFrom: Numeric> LossyCastError<CastFrom, bf16> {
+    /// Measures the signed difference `from - to` of the cast in units in the last place of `to`
+    ///
+    /// This is [`LossyCastError::loss`] scaled by the spacing of [`bf16`] values at `to`, so a cast
+    /// which rounds to the nearest [`bf16`] is at most half an ulp away.
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use cove::bf16;
+    ///
+    /// assert_eq!(257u32.cast::<bf16>().unwrap_err().ulps(), 0.5);
+    /// assert_eq!(1.00390625f64.cast::<bf16>().unwrap_err().ulps(), 0.5);
+    /// assert_eq!(f32::MAX.cast::<bf16>().unwrap_err().ulps(), f64::NEG_INFINITY);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ulps(&self) -> f64 {
+        self.loss().to_f64() / self.to.ulp()
+    }
+}
+
+impl<CastFrom: Numeric> LossyCastError<CastFrom, f8e4m3> {
+    /// Measures the signed difference `from - to` of the cast in units in the last place of `to`
+    ///
+    /// This is [`LossyCastError::loss`] scaled by the spacing of [`f8e4m3`] values at `to`, so a
+    /// cast which rounds to the nearest [`f8e4m3`] is at most half an ulp away unless it saturated.
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use cove::types::f8e4m3;
+    ///
+    /// assert_eq!(17u32.cast::<f8e4m3>().unwrap_err().ulps(), 0.5);
+    /// assert_eq!(480f32.cast::<f8e4m3>().unwrap_err().ulps(), 1.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ulps(&self) -> f64 {
+        self.loss().to_f64() / self.to.ulp()
+    }
+}
+
+impl<CastFrom: Numeric> LossyCastError<CastFrom, f8e5m2> {
+    /// Measures the signed difference `from - to` of the cast in units in the last place of `to`
+    ///
+    /// This is [`LossyCastError::loss`] scaled by the spacing of [`f8e5m2`] values at `to`, so a
+    /// cast which rounds to the nearest [`f8e5m2`] is at most half an ulp away.
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use cove::types::f8e5m2;
+    ///
+    /// assert_eq!(9u32.cast::<f8e5m2>().unwrap_err().ulps(), 0.5);
+    /// assert_eq!((-11i64).cast::<f8e5m2>().unwrap_err().ulps(), 0.5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ulps(&self) -> f64 {
+        self.loss().to_f64() / self.to.ulp()
+    }
+}
+
+impl<CastFrom: Numeric> LossyCastError<CastFrom, f128> {
+    /// Measures the signed difference `from - to` of the cast in units in the last place of `to`
+    ///
+    /// This is [`LossyCastError::loss`] scaled by the spacing of [`f128`] values at `to`, so a cast
+    /// which rounds to the nearest [`f128`] is at most half an ulp away.
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use cove::f128;
+    ///
+    /// assert_eq!(((1u128 << 113) + 1).cast::<f128>().unwrap_err().ulps(), 0.5);
+    /// assert_eq!((-(1i128 << 114) - 3).cast::<f128>().unwrap_err().ulps(), 0.25);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ulps(&self) -> f64 {
+        self.loss().to_f64() / self.to.ulp()
+    }
+}
+
 impl<CastFrom: Display, CastTo: Display> Display for LossyCastError<CastFrom, CastTo> {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
         write!(
@@ -83,6 +320,61 @@ impl<CastFrom: Display, CastTo: Display> Display for LossyCastError<CastFrom, Ca
 impl<CastFrom: Debug + Display, CastTo: Debug + Display>
 std::error::Error for LossyCastError<CastFrom, CastTo> {}
 
+impl<T: Copy + Numeric + CastImpl<U>, U: Numeric> LossyCastError<Complex<T>, Complex<U>> {
+    /// Classifies why each component of the cast was lossy, with [`None`] for components which
+    /// were casted exactly
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use cove::errors::LossKind;
+    /// use cove::types::Complex;
+    ///
+    /// let error = Complex::new(300i32, 7).cast::<Complex<u8>>().unwrap_err();
+    /// assert_eq!(error.kinds(), Complex::new(Some(LossKind::Overflow), None));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn kinds(&self) -> Complex<Option<LossKind>> {
+        Complex::new(component_kind::<T, U>(self.from.re), component_kind::<T, U>(self.from.im))
+    }
+
+    /// Measures the signed difference `from - to` of each component of the cast, as for
+    /// [`LossyCastError::loss`]
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use cove::types::Complex;
+    ///
+    /// let losses = Complex::new(300i32, -2).cast::<Complex<u8>>().unwrap_err().losses();
+    /// assert_eq!((losses.re.to_f64(), losses.im.to_f64()), (256.0, -256.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn losses(&self) -> Complex<Loss> {
+        let (from, to) = (self.from, self.to);
+        Complex::new(difference(from.re, to.re), difference(from.im, to.im))
+    }
+
+    /// Returns an adapter which displays the error along with the [`LossKind`] of each component
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use cove::types::Complex;
+    ///
+    /// let error = Complex::new(300i32, 7).cast::<Complex<u8>>().unwrap_err();
+    /// let message = error.detailed().to_string();
+    /// assert!(message.starts_with("Numerical cast was lossy (re: overflow, im: exact) [300+7i"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn detailed(&self) -> Detailed<'_, Self> {
+        Detailed(self)
+    }
+}
+
 // -- FailedCastError -- //
 /// Indicates that a cast between numeric types would have lost data but could not even create the
 /// lossy value.
@@ -90,6 +382,9 @@ std::error::Error for LossyCastError<CastFrom, CastTo> {}
 /// This is generally used for casts from primitives to the `NonZero*` family in [`core::num`], as 
 /// there is no way to create the associated `NonZero*` in the face of a `0` value without invoking 
 /// undefined behavior.
+///
+/// As with [`LossyCastError`], [`FailedCastError::kind`] and [`FailedCastError::detailed`] are
+/// available when both types are numerical types supported by cove.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct FailedCastError<CastFrom, CastTo> {
     /// The original value before the cast
@@ -109,6 +404,43 @@ impl<CastFrom, CastTo> FailedCastError<CastFrom, CastTo> {
     }
 }
 
+impl<CastFrom: Numeric, CastTo: Numeric> FailedCastError<CastFrom, CastTo> {
+    /// Classifies why the cast failed
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use cove::errors::LossKind;
+    /// use core::num::{NonZeroI8, NonZeroU32};
+    ///
+    /// assert_eq!(0u64.cast::<NonZeroU32>().unwrap_err().kind(), LossKind::Zero);
+    /// assert_eq!(200u8.cast::<NonZeroI8>().unwrap_err().kind(), LossKind::Overflow);
+    /// assert_eq!(1.5f32.cast::<NonZeroU32>().unwrap_err().kind(), LossKind::Fraction);
+    /// assert_eq!(f64::INFINITY.cast::<NonZeroI8>().unwrap_err().kind(), LossKind::Infinity);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn kind(&self) -> LossKind {
+        classify::<CastFrom, CastTo>(self.from)
+    }
+
+    /// Returns an adapter which displays the error along with its [`LossKind`]
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use core::num::NonZeroU8;
+    ///
+    /// let error = 0u32.cast::<NonZeroU8>().unwrap_err();
+    /// assert!(error.detailed().to_string().starts_with("Numerical cast failed (zero) [0 (u32)"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn detailed(&self) -> Detailed<'_, Self> {
+        Detailed(self)
+    }
+}
+
 impl<CastFrom: Display, CastTo> Display for FailedCastError<CastFrom, CastTo> {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
         write!(
@@ -123,4 +455,336 @@ impl<CastFrom: Display, CastTo> Display for FailedCastError<CastFrom, CastTo> {
 
 #[cfg(feature = "std")]
 impl<CastFrom: Debug + Display, CastTo: Debug>
-std::error::Error for FailedCastError<CastFrom, CastTo> {}
\ No newline at end of file
+std::error::Error for FailedCastError<CastFrom, CastTo> {}
+
+impl<T: Copy + Numeric + CastImpl<U>, U: Numeric> FailedCastError<Complex<T>, Complex<U>> {
+    /// Classifies why each component of the cast failed, with [`None`] for components which were
+    /// casted exactly
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use cove::errors::LossKind;
+    /// use cove::types::Complex;
+    /// use core::num::NonZeroU8;
+    ///
+    /// let error = Complex::new(0u32, 3).cast::<Complex<NonZeroU8>>().unwrap_err();
+    /// assert_eq!(error.kinds(), Complex::new(Some(LossKind::Zero), None));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn kinds(&self) -> Complex<Option<LossKind>> {
+        Complex::new(component_kind::<T, U>(self.from.re), component_kind::<T, U>(self.from.im))
+    }
+
+    /// Returns an adapter which displays the error along with the [`LossKind`] of each component
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use cove::types::Complex;
+    /// use core::num::NonZeroU8;
+    ///
+    /// let error = Complex::new(0u32, 3).cast::<Complex<NonZeroU8>>().unwrap_err();
+    /// let message = error.detailed().to_string();
+    /// assert!(message.starts_with("Numerical cast failed (re: zero, im: exact) [0+3i"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn detailed(&self) -> Detailed<'_, Self> {
+        Detailed(self)
+    }
+}
+
+// -- Detailed -- //
+/// Displays a [`LossyCastError`] or [`FailedCastError`] along with the [`LossKind`] of the loss,
+/// such as `"Numerical cast was lossy (overflow) [260 (u32) -> 4 (u8)]"`
+///
+/// This is returned from [`LossyCastError::detailed`] and [`FailedCastError::detailed`]. For
+/// casts between [`Complex`] numbers, the kind of each component is named instead, such as
+/// `"re: overflow, im: exact"`.
+#[derive(Copy, Clone, Debug)]
+pub struct Detailed<'a, E>(&'a E);
+
+impl<CastFrom: Display + Numeric, CastTo: Display + Numeric> Display
+for Detailed<'_, LossyCastError<CastFrom, CastTo>> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            formatter,
+            "Numerical cast was lossy ({}) [{} ({}) -> {} ({})]",
+            self.0.kind(),
+            self.0.from, core::any::type_name::<CastFrom>(),
+            self.0.to, core::any::type_name::<CastTo>()
+        )
+    }
+}
+
+impl<CastFrom: Display + Numeric, CastTo: Numeric> Display
+for Detailed<'_, FailedCastError<CastFrom, CastTo>> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            formatter,
+            "Numerical cast failed ({}) [{} ({}) -> ({})]",
+            self.0.kind(),
+            self.0.from,
+            core::any::type_name::<CastFrom>(),
+            core::any::type_name::<CastTo>()
+        )
+    }
+}
+
+impl<T: Copy + Display + Numeric + CastImpl<U>, U: Display + Numeric> Display
+for Detailed<'_, LossyCastError<Complex<T>, Complex<U>>> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            formatter,
+            "Numerical cast was lossy ({}) [{} ({}) -> {} ({})]",
+            ComponentKinds(self.0.kinds()),
+            self.0.from, core::any::type_name::<Complex<T>>(),
+            self.0.to, core::any::type_name::<Complex<U>>()
+        )
+    }
+}
+
+impl<T: Copy + Display + Numeric + CastImpl<U>, U: Numeric> Display
+for Detailed<'_, FailedCastError<Complex<T>, Complex<U>>> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            formatter,
+            "Numerical cast failed ({}) [{} ({}) -> ({})]",
+            ComponentKinds(self.0.kinds()),
+            self.0.from,
+            core::any::type_name::<Complex<T>>(),
+            core::any::type_name::<Complex<U>>()
+        )
+    }
+}
+
+// -- Complex -- //
+/// Classifies the loss of casting a single component, if any
+#[inline]
+fn component_kind<T: Numeric + CastImpl<U>, U: Numeric>(from: T) -> Option<LossKind> {
+    match from.cast_impl() {
+        Ok(_) => None,
+        Err(_) => Some(classify::<T, U>(from))
+    }
+}
+
+/// Formats the classification of each component of a cast, such as `"re: overflow, im: exact"`
+struct ComponentKinds(Complex<Option<LossKind>>);
+
+impl Display for ComponentKinds {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        let describe = |kind: Option<LossKind>| kind.map_or("exact", LossKind::name);
+        write!(formatter, "re: {}, im: {}", describe(self.0.re), describe(self.0.im))
+    }
+}
+
+// -- LossKind -- //
+/// Classifies the cause of the loss reported by a [`LossyCastError`] or [`FailedCastError`]
+///
+/// The classification considers only the origin value and the range of the target type, so the
+/// kinds are checked in the order listed here: e.g. casting NaN is always [`LossKind::NaN`] and
+/// casting 300.5 to [`u8`] is [`LossKind::Overflow`] rather than [`LossKind::Fraction`].
+///
+/// The [`Display`] implementation yields a short lowercase description, such as `"overflow"`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LossKind {
+    /// The origin value is NaN, which the target type cannot represent
+    NaN,
+
+    /// The origin value is ±infinity, which the target type cannot represent
+    Infinity,
+
+    /// The origin value is zero, which the target type (one of the `NonZero*` family defined in
+    /// [`core::num`]) cannot represent
+    Zero,
+
+    /// The origin value is greater than the maximum of the target type
+    Overflow,
+
+    /// The origin value is less than the minimum of the target type
+    Underflow,
+
+    /// The origin value lies within the range of the target type but is not a value of it, such as
+    /// a surrogate code point for [`char`]
+    Invalid,
+
+    /// The origin value lies within the range of the target type but has a fractional part which
+    /// the integral target type cannot represent
+    Fraction,
+
+    /// The origin value lies within the range of the target type but has more significant digits
+    /// than the floating point target type can represent
+    Precision
+}
+
+impl LossKind {
+    /// Returns the short lowercase description of the kind
+    fn name(self) -> &'static str {
+        match self {
+            Self::NaN => "NaN",
+            Self::Infinity => "infinity",
+            Self::Zero => "zero",
+            Self::Overflow => "overflow",
+            Self::Underflow => "underflow",
+            Self::Invalid => "invalid",
+            Self::Fraction => "fraction",
+            Self::Precision => "precision"
+        }
+    }
+}
+
+impl Display for LossKind {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str(self.name())
+    }
+}
+// -- Loss -- //
+/// The exact signed difference `from - to` of a lossy cast, as measured by
+/// [`LossyCastError::loss`]
+///
+/// The difference may fit in neither type of the cast nor in [`f64`], as for [`u128`] → [`f32`],
+/// so it is represented as a sum of non-overlapping [`f64`] components. Comparisons with other
+/// losses and with [`f64`] values are exact, while [`Loss::to_f64`] rounds. NaN and ±infinity are
+/// represented by a single component, and compare as [`f64`] does.
+///
+/// [`Display`] shows the value of [`Loss::to_f64`], while [`Debug`] shows the components.
+///
+/// # Examples
+/// ```
+/// use cove::prelude::*;
+///
+/// // 2^127 + 2^103 - 1 rounds to 2^127, which is 2^103 - 1 away; f64 cannot represent that
+/// let value = (1u128 << 127) + (1 << 103) - 1;
+/// let loss = value.cast::<f32>().unwrap_err().loss();
+/// assert_eq!(loss.components(), [2f64.powi(103), -1.0]);
+/// assert!(loss < 2f64.powi(103) && loss > 2f64.powi(103) - 2f64.powi(51));
+/// assert_eq!(loss.to_f64(), 2f64.powi(103));
+/// ```
+#[derive(Copy, Clone)]
+pub struct Loss {
+    /// The non-zero components in order of decreasing magnitude, followed by zeros
+    components: [f64; 2 * PARTS],
+
+    /// The number of non-zero components
+    len: usize
+}
+
+impl Loss {
+    /// Creates a loss from non-overlapping components in order of increasing magnitude, which may
+    /// be interspersed with zeros
+    #[inline]
+    pub(crate) fn new(expansion: &[f64]) -> Self {
+        let mut loss = Self {components: [0.0; 2 * PARTS], len: 0};
+
+        for &component in expansion.iter().rev().filter(|&&component| component != 0.0) {
+            loss.components[loss.len] = component;
+            loss.len += 1;
+        }
+
+        loss
+    }
+
+    /// Returns the non-zero components of the loss, in order of decreasing magnitude, which sum to
+    /// exactly the loss; the slice is empty for a loss of zero
+    ///
+    /// Each component is smaller in magnitude than the lowest non-zero bit of the one before it.
+    #[inline]
+    #[must_use]
+    pub fn components(&self) -> &[f64] {
+        &self.components[.. self.len]
+    }
+
+    /// Returns the loss rounded to [`f64`], which is within one unit in the last place of the
+    /// exact loss
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    ///
+    /// assert_eq!(260u32.cast::<u8>().unwrap_err().loss().to_f64(), 256.0);
+    /// assert_eq!((-1i8).cast::<u128>().unwrap_err().loss().to_f64(), -2f64.powi(128));
+    /// assert!(f32::NAN.cast::<u8>().unwrap_err().loss().to_f64().is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_f64(&self) -> f64 {
+        self.components().iter().rev().sum()
+    }
+
+    /// Returns the absolute value of the loss
+    #[inline]
+    #[must_use]
+    pub fn abs(mut self) -> Self {
+        if matches!(self.components().first(), Some(leading) if leading.is_sign_negative()) {
+            for component in &mut self.components[.. self.len] {
+                *component = -*component;
+            }
+        }
+
+        self
+    }
+
+    /// Compares the exact sum of `self` and the negation of `other`'s components against zero
+    #[inline]
+    fn compare(&self, other: &[f64]) -> Option<Ordering> {
+        // Special values are represented alone, so their comparisons are those of f64
+        let special = |components: &[f64]| matches!(components.first(), Some(c) if !c.is_finite());
+        if special(self.components()) || special(other) {
+            return self.to_f64().partial_cmp(&other.iter().rev().sum());
+        }
+
+        let mut terms = [0.0; 4 * PARTS];
+        let components = self.components().iter().copied();
+        let negated = other.iter().map(|&component| -component);
+        for (term, component) in terms.iter_mut().zip(components.chain(negated)) {
+            *term = component;
+        }
+
+        // The sign of an expansion is the sign of its largest component
+        grow(&mut terms);
+        let leading = terms.iter().rev().find(|&&term| term != 0.0).copied().unwrap_or(0.0);
+        leading.partial_cmp(&0.0)
+    }
+}
+
+impl PartialEq for Loss {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.compare(other.components()) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Loss {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.compare(other.components())
+    }
+}
+
+impl PartialEq<f64> for Loss {
+    #[inline]
+    fn eq(&self, other: &f64) -> bool {
+        self.compare(&[*other]) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd<f64> for Loss {
+    #[inline]
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        self.compare(&[*other])
+    }
+}
+
+impl Debug for Loss {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        formatter.debug_tuple("Loss").field(&self.components()).finish()
+    }
+}
+
+impl Display for Loss {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_f64(), formatter)
+    }
+}
@@ -0,0 +1,393 @@
+//! This module provides implementations of the casting traits for the UInt and Int types
+
+use crate::base::CastImpl;
+use crate::casts::{
+    Bitwise, Cast, Closest, Lossless, Lossy, Rounded, RoundingMode, Stochastic, StochasticRng,
+    Wrapping
+};
+use crate::errors::{FailedCastError, LossyCastError};
+use crate::types::{BitRange, Bits, Int, UInt, Width};
+
+use core::marker::PhantomData;
+use core::num::{
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+};
+
+impl<const BITS: u32> Cast for UInt<BITS> where Bits<BITS>: Width {}
+impl<const BITS: u32> Cast for Int<BITS> where Bits<BITS>: Width {}
+
+// -- Proof -- //
+/// Helper type for proving at compile time that casts involving [`UInt`] and [`Int`] are lossless;
+/// the proof is an associated constant which fails to evaluate if the cast could be lossy
+struct Proof<From, To>(PhantomData<(From, To)>);
+
+impl<From: BitRange, To: BitRange> Proof<From, To> {
+    /// Proves that every value of `From` is exactly representable by `To`
+    const LOSSLESS: () = assert!(
+        From::UNSIGNED_FITS <= To::UNSIGNED_HOLDS || From::SIGNED_FITS <= To::SIGNED_HOLDS,
+        "Cast is not guaranteed lossless by the bit widths"
+    );
+}
+
+// -- Casts -- //
+macro_rules! bits {
+    // Implements Cast, Closest, Lossless and Wrapping for $bit -> $int via LossyCastError and
+    // Cast, Closest and Lossless for $bit -> $float via LossyCastError. Casts are performed via
+    // $wide, which holds every value of $bit; Lossless relies on a proof which the blanket
+    // implementation for Result evaluates.
+    (into $bit:ident as $wide:ty => {$($int:ty),+}; {$($float:ty),+}) => {
+        $(
+            bits!(into $bit as $wide => $int);
+
+            impl<const BITS: u32> Wrapping<$int> for LossyCastError<$bit<BITS>, $int>
+            where Bits<BITS>: Width {
+                #[inline]
+                fn wrapping(self) -> $int {
+                    // Between integers the lossy value is already the wrapped value
+                    self.from.widen().cast::<$int>().lossy()
+                }
+            }
+        )*
+
+        $(bits!(into $bit as $wide => $float);)*
+    };
+
+    (into $bit:ident as $wide:ty => $to:ty) => {
+        impl<const BITS: u32> CastImpl<$to> for $bit<BITS> where Bits<BITS>: Width {
+            type Error = LossyCastError<Self, $to>;
+
+            #[inline]
+            fn cast_impl(self) -> Result<$to, Self::Error> {
+                self.widen().cast::<$to>().map_err(|error| LossyCastError {
+                    from: self,
+                    to: error.lossy()
+                })
+            }
+        }
+
+        impl<const BITS: u32> Closest<$to> for LossyCastError<$bit<BITS>, $to>
+        where Bits<BITS>: Width {
+            #[inline]
+            fn closest(self) -> $to {
+                self.from.widen().cast::<$to>().closest()
+            }
+        }
+
+        unsafe impl<const BITS: u32> Lossless<$to> for LossyCastError<$bit<BITS>, $to>
+        where Bits<BITS>: Width {
+            // Fail compilation unless the widths prove the cast to be lossless
+            const _PROOF: () = Proof::<$bit<BITS>, $to>::LOSSLESS;
+
+            #[inline]
+            fn lossless(self) -> $to {
+                // The proof guarantees that cove never produces this error, but it could still be
+                // constructed by hand; the closest value is exact for valid values
+                #[allow(clippy::let_unit_value)]
+                let () = Self::_PROOF;
+                self.closest()
+            }
+        }
+    };
+
+    // Implements Cast and Closest for $bit -> $nonzero via FailedCastError, in terms of the casts
+    // from $wide; such casts are never lossless since every width includes zero
+    (nonzero $bit:ident as $wide:ty => {$($nonzero:ty),+}) => {
+        $(
+            impl<const BITS: u32> CastImpl<$nonzero> for $bit<BITS> where Bits<BITS>: Width {
+                type Error = FailedCastError<Self, $nonzero>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$nonzero, Self::Error> {
+                    self.widen().cast::<$nonzero>().map_err(|_error| FailedCastError::new(self))
+                }
+            }
+
+            impl<const BITS: u32> Closest<$nonzero> for FailedCastError<$bit<BITS>, $nonzero>
+            where Bits<BITS>: Width {
+                #[inline]
+                fn closest(self) -> $nonzero {
+                    self.from.widen().cast::<$nonzero>().closest()
+                }
+            }
+        )*
+    };
+
+    // Implements Cast, Closest, Lossless and Wrapping for $int -> $bit via LossyCastError. The
+    // lossy value is truncated to the width as for the `as` keyword, while Closest saturates.
+    (from_int $bit:ident as $wide:ty => {$($int:ty),+}) => {
+        $(
+            impl<const BITS: u32> CastImpl<$bit<BITS>> for $int where Bits<BITS>: Width {
+                type Error = LossyCastError<Self, $bit<BITS>>;
+
+                #[inline]
+                #[allow(clippy::cast_lossless, clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+                fn cast_impl(self) -> Result<$bit<BITS>, Self::Error> {
+                    let value = self as $wide;
+                    let to = <$bit<BITS>>::truncate(value);
+
+                    match self.cast::<$wide>().is_ok() && to.widen() == value {
+                        true => Ok(to),
+                        false => Err(LossyCastError {from: self, to})
+                    }
+                }
+            }
+
+            impl<const BITS: u32> Closest<$bit<BITS>> for LossyCastError<$int, $bit<BITS>>
+            where Bits<BITS>: Width {
+                #[inline]
+                fn closest(self) -> $bit<BITS> {
+                    <$bit<BITS>>::saturate(self.from.cast::<$wide>().closest())
+                }
+            }
+
+            impl<const BITS: u32> Wrapping<$bit<BITS>> for LossyCastError<$int, $bit<BITS>>
+            where Bits<BITS>: Width {
+                #[inline]
+                #[allow(clippy::cast_lossless, clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+                fn wrapping(self) -> $bit<BITS> {
+                    <$bit<BITS>>::truncate(self.from as $wide)
+                }
+            }
+
+            bits!(lossless $int => $bit);
+        )*
+    };
+
+    // Implements Cast, Closest, Lossless and Wrapping for $nonzero -> $bit via LossyCastError, in
+    // terms of the casts from the underlying primitive
+    (from_nonzero $bit:ident => {$($nonzero:ty),+}) => {
+        $(
+            impl<const BITS: u32> CastImpl<$bit<BITS>> for $nonzero where Bits<BITS>: Width {
+                type Error = LossyCastError<Self, $bit<BITS>>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$bit<BITS>, Self::Error> {
+                    self.get().cast::<$bit<BITS>>().map_err(|error| LossyCastError {
+                        from: self,
+                        to: error.to
+                    })
+                }
+            }
+
+            impl<const BITS: u32> Closest<$bit<BITS>> for LossyCastError<$nonzero, $bit<BITS>>
+            where Bits<BITS>: Width {
+                #[inline]
+                fn closest(self) -> $bit<BITS> {
+                    self.from.get().cast::<$bit<BITS>>().closest()
+                }
+            }
+
+            impl<const BITS: u32> Wrapping<$bit<BITS>> for LossyCastError<$nonzero, $bit<BITS>>
+            where Bits<BITS>: Width {
+                #[inline]
+                fn wrapping(self) -> $bit<BITS> {
+                    self.from.get().cast::<$bit<BITS>>().wrapping()
+                }
+            }
+
+            bits!(lossless $nonzero => $bit);
+        )*
+    };
+
+    // Implements Cast, Closest, Rounded, Stochastic and Wrapping for $float -> $bit via
+    // LossyCastError, in terms of the casts to $wide. The lossy value saturates as for the `as`
+    // keyword; since clamping is monotonic, clamping each rounded value of $wide to the width
+    // yields the same value as rounding directly.
+    (from_float $bit:ident as $wide:ty => {$($float:ty),+}) => {
+        $(
+            impl<const BITS: u32> CastImpl<$bit<BITS>> for $float where Bits<BITS>: Width {
+                type Error = LossyCastError<Self, $bit<BITS>>;
+
+                #[inline]
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                fn cast_impl(self) -> Result<$bit<BITS>, Self::Error> {
+                    let to = <$bit<BITS>>::saturate(self as $wide);
+
+                    match self.cast::<$wide>() {
+                        Ok(value) if value == to.widen() => Ok(to),
+                        _ => Err(LossyCastError {from: self, to})
+                    }
+                }
+            }
+
+            impl<const BITS: u32> Closest<$bit<BITS>> for LossyCastError<$float, $bit<BITS>>
+            where Bits<BITS>: Width {
+                #[inline]
+                fn closest(self) -> $bit<BITS> {
+                    <$bit<BITS>>::saturate(self.from.cast::<$wide>().closest())
+                }
+            }
+
+            impl<const BITS: u32> Rounded<$bit<BITS>> for LossyCastError<$float, $bit<BITS>>
+            where Bits<BITS>: Width {
+                #[inline]
+                fn rounded(self, mode: RoundingMode) -> $bit<BITS> {
+                    <$bit<BITS>>::saturate(self.from.cast::<$wide>().rounded(mode))
+                }
+            }
+
+            impl<const BITS: u32> Stochastic<$bit<BITS>> for LossyCastError<$float, $bit<BITS>>
+            where Bits<BITS>: Width {
+                #[inline]
+                fn stochastic<R: StochasticRng + ?Sized>(self, rng: &mut R) -> $bit<BITS> {
+                    <$bit<BITS>>::saturate(self.from.cast::<$wide>().stochastic(rng))
+                }
+            }
+
+            impl<const BITS: u32> Wrapping<$bit<BITS>> for LossyCastError<$float, $bit<BITS>>
+            where Bits<BITS>: Width {
+                #[inline]
+                fn wrapping(self) -> $bit<BITS> {
+                    <$bit<BITS>>::truncate(self.from.cast::<$wide>().wrapping())
+                }
+            }
+        )*
+    };
+
+    // Implements Cast, Closest, Lossless and Wrapping for $from -> $to between any widths via
+    // LossyCastError, as for the primitive integers
+    (between $from:ident as $from_wide:ty => $to:ident as $to_wide:ty) => {
+        impl<const FROM: u32, const TO: u32> CastImpl<$to<TO>> for $from<FROM>
+        where Bits<FROM>: Width, Bits<TO>: Width {
+            type Error = LossyCastError<Self, $to<TO>>;
+
+            #[inline]
+            #[allow(clippy::unnecessary_cast, clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+            fn cast_impl(self) -> Result<$to<TO>, Self::Error> {
+                let value = self.widen() as $to_wide;
+                let to = <$to<TO>>::truncate(value);
+
+                match self.widen().cast::<$to_wide>().is_ok() && to.widen() == value {
+                    true => Ok(to),
+                    false => Err(LossyCastError {from: self, to})
+                }
+            }
+        }
+
+        impl<const FROM: u32, const TO: u32> Closest<$to<TO>>
+        for LossyCastError<$from<FROM>, $to<TO>>
+        where Bits<FROM>: Width, Bits<TO>: Width {
+            #[inline]
+            fn closest(self) -> $to<TO> {
+                <$to<TO>>::saturate(self.from.widen().cast::<$to_wide>().closest())
+            }
+        }
+
+        impl<const FROM: u32, const TO: u32> Wrapping<$to<TO>>
+        for LossyCastError<$from<FROM>, $to<TO>>
+        where Bits<FROM>: Width, Bits<TO>: Width {
+            #[inline]
+            #[allow(clippy::unnecessary_cast, clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+            fn wrapping(self) -> $to<TO> {
+                <$to<TO>>::truncate(self.from.widen() as $to_wide)
+            }
+        }
+
+        unsafe impl<const FROM: u32, const TO: u32> Lossless<$to<TO>>
+        for LossyCastError<$from<FROM>, $to<TO>>
+        where Bits<FROM>: Width, Bits<TO>: Width {
+            // Fail compilation unless the widths prove the cast to be lossless
+            const _PROOF: () = Proof::<$from<FROM>, $to<TO>>::LOSSLESS;
+
+            #[inline]
+            fn lossless(self) -> $to<TO> {
+                // The proof guarantees that cove never produces this error, but it could still be
+                // constructed by hand; the closest value is exact for valid values
+                #[allow(clippy::let_unit_value)]
+                let () = Self::_PROOF;
+                self.closest()
+            }
+        }
+    };
+
+    // Implements Lossless for $from -> $bit via LossyCastError, relying on a proof
+    (lossless $from:ty => $bit:ident) => {
+        unsafe impl<const BITS: u32> Lossless<$bit<BITS>> for LossyCastError<$from, $bit<BITS>>
+        where Bits<BITS>: Width {
+            // Fail compilation unless the widths prove the cast to be lossless
+            const _PROOF: () = Proof::<$from, $bit<BITS>>::LOSSLESS;
+
+            #[inline]
+            fn lossless(self) -> $bit<BITS> {
+                // The proof guarantees that cove never produces this error, but it could still be
+                // constructed by hand; the closest value is exact for valid values
+                #[allow(clippy::let_unit_value)]
+                let () = Self::_PROOF;
+                self.closest()
+            }
+        }
+    };
+
+    // Generates all implementations for each `$bit`
+    ($($bit:ident as $wide:ty),+) => {
+        $(
+            bits!(
+                into $bit as $wide =>
+                {u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize};
+                {f32, f64}
+            );
+
+            bits!(
+                nonzero $bit as $wide =>
+                {
+                    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+                    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+                }
+            );
+
+            bits!(
+                from_int $bit as $wide =>
+                {u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize}
+            );
+
+            bits!(
+                from_nonzero $bit =>
+                {
+                    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+                    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+                }
+            );
+
+            bits!(from_float $bit as $wide => {f32, f64});
+        )*
+    }
+}
+
+bits!(UInt as u128, Int as i128);
+
+bits!(between UInt as u128 => UInt as u128);
+bits!(between UInt as u128 => Int as i128);
+bits!(between Int as i128 => UInt as u128);
+bits!(between Int as i128 => Int as i128);
+
+// -- Bitwise -- //
+impl<const BITS: u32> Bitwise<Int<BITS>>
+for Result<Int<BITS>, LossyCastError<UInt<BITS>, Int<BITS>>>
+where Bits<BITS>: Width {
+    #[inline]
+    #[allow(clippy::cast_possible_wrap)]
+    fn bitwise(self) -> Int<BITS> {
+        // A successful cast preserves the value, and hence the bits, since it is nonnegative. If
+        // not, the original value is available in the error itself.
+        match self {
+            Ok(value) => value,
+            Err(error) => Int::truncate(error.from.widen() as i128)
+        }
+    }
+}
+
+impl<const BITS: u32> Bitwise<UInt<BITS>>
+for Result<UInt<BITS>, LossyCastError<Int<BITS>, UInt<BITS>>>
+where Bits<BITS>: Width {
+    #[inline]
+    #[allow(clippy::cast_sign_loss)]
+    fn bitwise(self) -> UInt<BITS> {
+        // A successful cast preserves the value, and hence the bits, since it is nonnegative. If
+        // not, the original value is available in the error itself.
+        match self {
+            Ok(value) => value,
+            Err(error) => UInt::truncate(error.from.widen() as u128)
+        }
+    }
+}
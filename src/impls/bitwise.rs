@@ -2,6 +2,7 @@
 
 use crate::casts::{AssumedLossless, Bitwise, Cast};
 use crate::errors::{LosslessCastError, LossyCastError};
+use crate::types::{bf16, f16, f128, f8e4m3, f8e5m2, i256, u256};
 
 use core::num::{
     NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
@@ -186,18 +187,27 @@ macro_rules! bitwise {
 }
 
 // -- Platform-independent -- //
+// Floats of different formats are excluded, since their casts are numerical and so do not preserve
+// the payloads of NaNs
 bitwise!(primitive u8, i8);
+bitwise!(primitive primitive u8, i8 => {f8e4m3, f8e5m2});
+bitwise!(primitive primitive f8e4m3 => {u8, i8, f8e4m3});
+bitwise!(primitive primitive f8e5m2 => {u8, i8, f8e5m2});
 bitwise!(primitive u16, i16);
+bitwise!(primitive primitive u16, i16 => {f16, bf16});
+bitwise!(primitive primitive f16 => {u16, i16, f16});
+bitwise!(primitive primitive bf16 => {u16, i16, bf16});
 bitwise!(primitive u32, i32, f32);
 bitwise!(primitive u64, i64, f64);
-bitwise!(primitive u128, i128);
+bitwise!(primitive u128, i128, f128);
 bitwise!(primitive usize, isize);
+bitwise!(primitive u256, i256);
 
-bitwise!(nonzero primitive NonZeroU8, NonZeroI8 => {u8, i8});
-bitwise!(nonzero primitive NonZeroU16, NonZeroI16 => {u16, i16});
+bitwise!(nonzero primitive NonZeroU8, NonZeroI8 => {u8, i8, f8e4m3, f8e5m2});
+bitwise!(nonzero primitive NonZeroU16, NonZeroI16 => {u16, i16, f16, bf16});
 bitwise!(nonzero primitive NonZeroU32, NonZeroI32 => {u32, i32, f32});
 bitwise!(nonzero primitive NonZeroU64, NonZeroI64 => {u64, i64, f64});
-bitwise!(nonzero primitive NonZeroU128, NonZeroI128 => {u128, i128});
+bitwise!(nonzero primitive NonZeroU128, NonZeroI128 => {u128, i128, f128});
 bitwise!(nonzero primitive NonZeroUsize, NonZeroIsize => {usize, isize});
 
 bitwise!(nonzero NonZeroU8, NonZeroI8);
@@ -213,11 +223,11 @@ bitwise!(nonzero NonZeroUsize, NonZeroIsize);
 mod platform_dependent {
     use super::*;
 
-    bitwise!(primitive primitive u16, i16 => {usize, isize});
-    bitwise!(primitive primitive usize, isize => {u16, i16});
+    bitwise!(primitive primitive u16, i16, f16, bf16 => {usize, isize});
+    bitwise!(primitive primitive usize, isize => {u16, i16, f16, bf16});
 
     bitwise!(nonzero primitive NonZeroU16, NonZeroI16 => {usize, isize});
-    bitwise!(nonzero primitive NonZeroUsize, NonZeroIsize => {u16, i16});
+    bitwise!(nonzero primitive NonZeroUsize, NonZeroIsize => {u16, i16, f16, bf16});
 
     bitwise!(nonzero nonzero NonZeroU16, NonZeroI16 => {NonZeroUsize, NonZeroIsize});
     bitwise!(nonzero nonzero NonZeroUsize, NonZeroIsize => {NonZeroU16, NonZeroI16});
@@ -258,11 +268,11 @@ mod platform_dependent {
 mod platform_dependent {
     use super::*;
 
-    bitwise!(primitive primitive u128, i128 => {usize, isize});
-    bitwise!(primitive primitive usize, isize => {u128, i128});
+    bitwise!(primitive primitive u128, i128, f128 => {usize, isize});
+    bitwise!(primitive primitive usize, isize => {u128, i128, f128});
 
     bitwise!(nonzero primitive NonZeroU128, NonZeroI128 => {usize, isize});
-    bitwise!(nonzero primitive NonZeroUsize, NonZeroIsize => {u128, i128});
+    bitwise!(nonzero primitive NonZeroUsize, NonZeroIsize => {u128, i128, f128});
 
     bitwise!(nonzero nonzero NonZeroU128, NonZeroI128 => {NonZeroUsize, NonZeroIsize});
     bitwise!(nonzero nonzero NonZeroUsize, NonZeroIsize => {NonZeroU128, NonZeroI128});
@@ -1,8 +1,11 @@
 //! This module provides blanket implementations of certain casting traits where applicable
 
 use crate::base::CastImpl;
-use crate::bounds::{CastTo, CastToClosest, CastToLossless};
-use crate::casts::{AssumedLossless, Cast, Closest, Lossless, Lossy};
+use crate::bounds::{CastTo, CastToClosest, CastToLossless, CastToPortableLossless};
+use crate::casts::{
+    AssumedLossless, Cast, Closest, Lossless, Lossy, PortableLossless, Rounded, RoundingMode,
+    Stochastic, StochasticRng, Tolerance, Within, Wrapping
+};
 use crate::errors::{LosslessCastError, LossyCastError};
 use core::fmt::{Debug, Display};
 
@@ -82,6 +85,11 @@ CastTo> {
 unsafe impl<T, Error: Lossless<T>> Lossless<T> for Result<T, Error> {
     #[inline]
     fn lossless(self) -> T {
+        // Fail compilation if the error type cannot prove the cast to be lossless; this is a no-op
+        // for error types which are lossless by their types alone
+        #[allow(clippy::let_unit_value)]
+        let () = Error::_PROOF;
+
         // Provide a debug assertion to catch implementation errors
         debug_assert!(
             self.is_ok(),
@@ -126,6 +134,66 @@ impl<T, Error: Lossy<T>> Lossy<T> for Result<T, Error> {
     }
 }
 
+// -- PortableLossless -- //
+// Blanket implementation for Results containing Err variants which implement PortableLossless
+impl<T, Error: PortableLossless<T>> PortableLossless<T> for Result<T, Error> {
+    #[inline]
+    fn portable_lossless(self) -> T {
+        self.lossless()
+    }
+}
+
+// -- Rounded -- //
+// Blanket implementation for Results containing Err variants which implement Rounded
+impl<T, Error: Rounded<T>> Rounded<T> for Result<T, Error> {
+    #[inline]
+    fn rounded(self, mode: RoundingMode) -> T {
+        self.unwrap_or_else(|error| error.rounded(mode))
+    }
+}
+
+// -- Stochastic -- //
+// Blanket implementation for Results containing Err variants which implement Stochastic
+impl<T, Error: Stochastic<T>> Stochastic<T> for Result<T, Error> {
+    #[inline]
+    fn stochastic<R: StochasticRng + ?Sized>(self, rng: &mut R) -> T {
+        self.unwrap_or_else(|error| error.stochastic(rng))
+    }
+}
+
+// -- Within -- //
+// Blanket implementation for Within applied to all LosslessCastErrors. We need to implement this
+// even though it is impossible to construct a LosslessCastError in order to trigger the blanket
+// implementation for Results.
+impl<CastFrom, CastTo> Within<CastTo> for LosslessCastError<CastFrom, CastTo> {
+    type Error = Self;
+
+    #[inline]
+    fn within(self, _tolerance: Tolerance) -> Result<CastTo, Self::Error> {
+        // This is safe because LosslessCastError cannot be instantiated
+        unsafe {core::hint::unreachable_unchecked()}
+    }
+}
+
+// Blanket implementation for Results containing Err variants which implement Within
+impl<T, Error: Within<T, Error = Error>> Within<T> for Result<T, Error> {
+    type Error = Error;
+
+    #[inline]
+    fn within(self, tolerance: Tolerance) -> Result<T, Self::Error> {
+        self.or_else(|error| error.within(tolerance))
+    }
+}
+
+// -- Wrapping -- //
+// Blanket implementation for Results containing Err variants which implement Wrapping
+impl<T, Error: Wrapping<T>> Wrapping<T> for Result<T, Error> {
+    #[inline]
+    fn wrapping(self) -> T {
+        self.unwrap_or_else(Wrapping::wrapping)
+    }
+}
+
 // -- Bounds -- //
 // Blanket implementations for bounds traits using std
 #[cfg(feature = "std")]
@@ -160,6 +228,15 @@ mod bounds_impl {
     > CastToLossless<TO> for FROM {
         type _Error = ERROR;
     }
+
+    // Blanket implementation for the CastToPortableLossless subtrait
+    impl<
+        TO,
+        ERROR: Copy + Debug + Display + Error + PortableLossless<TO>,
+        FROM: Cast + CastImpl<TO, Error = ERROR>
+    > CastToPortableLossless<TO> for FROM {
+        type _Error = ERROR;
+    }
 }
 
 // Blanket implementations for bounds traits using core
@@ -194,4 +271,13 @@ mod bounds_impl {
     > CastToLossless<TO> for FROM {
         type _Error = ERROR;
     }
+
+    // Blanket implementation for the CastToPortableLossless subtrait
+    impl<
+        TO,
+        ERROR: Copy + Debug + Display + PortableLossless<TO>,
+        FROM: Cast + CastImpl<TO, Error = ERROR>
+    > CastToPortableLossless<TO> for FROM {
+        type _Error = ERROR;
+    }
 }
\ No newline at end of file
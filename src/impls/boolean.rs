@@ -0,0 +1,101 @@
+//! This module provides implementations of the casting traits for bool, which casts as 0 or 1
+
+use crate::base::CastImpl;
+use crate::casts::{Cast, Closest, PortableLossless};
+use crate::errors::{FailedCastError, LosslessCastError};
+
+use core::num::{
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+};
+
+impl Cast for bool {}
+
+macro_rules! boolean {
+    // Implements Cast and Closest for $from -> bool via FailedCastError, where $from is a
+    // primitive integer or NonZero*. The value must be 0 or 1; Closest clamps to that range.
+    (from $($from:ty),+) => {
+        $(
+            impl CastImpl<bool> for $from {
+                type Error = FailedCastError<Self, bool>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<bool, Self::Error> {
+                    match self.cast::<u8>() {
+                        Ok(0) => Ok(false),
+                        Ok(1) => Ok(true),
+                        _ => Err(FailedCastError::new(self))
+                    }
+                }
+            }
+
+            impl Closest<bool> for FailedCastError<$from, bool> {
+                #[inline]
+                fn closest(self) -> bool {
+                    // Negative values saturate to 0 and positive values to at least 1
+                    self.from.cast::<u8>().closest() > 0
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and PortableLossless for bool -> $to via LosslessCastError, where $to is a
+    // primitive integer
+    (into lossless $($to:ty),+) => {
+        $(
+            impl CastImpl<$to> for bool {
+                type Error = LosslessCastError<Self, $to>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$to, Self::Error> {
+                    Ok(<$to>::from(self))
+                }
+            }
+
+            impl PortableLossless<$to> for LosslessCastError<bool, $to> {
+                #[inline]
+                fn portable_lossless(self) -> $to {
+                    // This is safe because LosslessCastError cannot be instantiated
+                    unsafe {core::hint::unreachable_unchecked()}
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and Closest for bool -> $to via FailedCastError, where $to is NonZero*.
+    // Casts are performed via u8, so Closest yields 1 for false.
+    (into $($to:ty),+) => {
+        $(
+            impl CastImpl<$to> for bool {
+                type Error = FailedCastError<Self, $to>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$to, Self::Error> {
+                    u8::from(self).cast::<$to>().map_err(|_error| FailedCastError::new(self))
+                }
+            }
+
+            impl Closest<$to> for FailedCastError<bool, $to> {
+                #[inline]
+                fn closest(self) -> $to {
+                    u8::from(self.from).cast::<$to>().closest()
+                }
+            }
+        )*
+    };
+}
+
+boolean!(
+    from
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize,
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+);
+
+boolean!(into lossless u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+boolean!(
+    into
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+);
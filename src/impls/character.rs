@@ -0,0 +1,170 @@
+//! This module provides implementations of the casting traits for char, which casts as its Unicode
+//! scalar value
+
+use crate::base::CastImpl;
+use crate::casts::{Cast, Closest, PortableLossless};
+use crate::errors::{FailedCastError, LosslessCastError, LossyCastError};
+
+use core::num::{
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+};
+
+impl Cast for char {}
+
+macro_rules! character {
+    // Implements Cast and Closest for $from -> char via FailedCastError, where $from is a
+    // primitive integer or NonZero*. The value must be a Unicode scalar value, which excludes the
+    // surrogates and anything above char::MAX; Closest replaces any other value with
+    // char::REPLACEMENT_CHARACTER.
+    (from $($from:ty),+) => {
+        $(
+            impl CastImpl<char> for $from {
+                type Error = FailedCastError<Self, char>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<char, Self::Error> {
+                    self.cast::<u32>()
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| FailedCastError::new(self))
+                }
+            }
+
+            impl Closest<char> for FailedCastError<$from, char> {
+                #[inline]
+                fn closest(self) -> char {
+                    char::REPLACEMENT_CHARACTER
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and PortableLossless for $from -> char via LosslessCastError, where every
+    // value of $from is a Latin-1 code point
+    (from lossless $($from:ty),+) => {
+        $(
+            impl CastImpl<char> for $from {
+                type Error = LosslessCastError<Self, char>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<char, Self::Error> {
+                    Ok(char::from(u8::from(self)))
+                }
+            }
+
+            impl PortableLossless<char> for LosslessCastError<$from, char> {
+                #[inline]
+                fn portable_lossless(self) -> char {
+                    // This is safe because LosslessCastError cannot be instantiated
+                    unsafe {core::hint::unreachable_unchecked()}
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and Closest for char -> $to via LossyCastError, where $to is a primitive
+    // integer which cannot hold every scalar value. The lossy value truncates as for the `as`
+    // keyword, so char -> u8 is exact only for Latin-1.
+    (into lossy $($to:ty),+) => {
+        $(
+            impl CastImpl<$to> for char {
+                type Error = LossyCastError<Self, $to>;
+
+                #[inline]
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                fn cast_impl(self) -> Result<$to, Self::Error> {
+                    match u32::from(self).cast::<$to>() {
+                        Ok(value) => Ok(value),
+                        Err(_error) => Err(LossyCastError {from: self, to: self as $to})
+                    }
+                }
+            }
+
+            impl Closest<$to> for LossyCastError<char, $to> {
+                #[inline]
+                fn closest(self) -> $to {
+                    u32::from(self.from).cast::<$to>().closest()
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and Closest for char -> $to via FailedCastError, where $to is NonZero*.
+    // Casts are performed via u32.
+    (into $($to:ty),+) => {
+        $(
+            impl CastImpl<$to> for char {
+                type Error = FailedCastError<Self, $to>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$to, Self::Error> {
+                    u32::from(self).cast::<$to>().map_err(|_error| FailedCastError::new(self))
+                }
+            }
+
+            impl Closest<$to> for FailedCastError<char, $to> {
+                #[inline]
+                fn closest(self) -> $to {
+                    u32::from(self.from).cast::<$to>().closest()
+                }
+            }
+        )*
+    };
+
+    // Implements Cast for char -> $to via LosslessCastError, where $to is a primitive integer
+    // which holds every scalar value
+    (into lossless $($to:ty),+) => {
+        $(
+            impl CastImpl<$to> for char {
+                type Error = LosslessCastError<Self, $to>;
+
+                #[inline]
+                #[allow(clippy::cast_lossless)]
+                fn cast_impl(self) -> Result<$to, Self::Error> {
+                    Ok(self as $to)
+                }
+            }
+        )*
+    };
+
+    // Implements PortableLossless for char -> $to, where the Cast is lossless on every platform
+    (into portable $($to:ty),+) => {
+        $(
+            impl PortableLossless<$to> for LosslessCastError<char, $to> {
+                #[inline]
+                fn portable_lossless(self) -> $to {
+                    // This is safe because LosslessCastError cannot be instantiated
+                    unsafe {core::hint::unreachable_unchecked()}
+                }
+            }
+        )*
+    };
+}
+
+character!(
+    from
+    u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+);
+
+character!(from lossless u8, NonZeroU8);
+
+character!(into lossy u8, u16, i8, i16);
+
+character!(
+    into
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+);
+
+character!(into lossless u32, u64, u128, i32, i64, i128);
+character!(into portable u32, u64, u128, i32, i64, i128);
+
+// The largest scalar value needs 21 bits, so only 16-bit platforms lose any
+#[cfg(target_pointer_width = "16")]
+character!(into lossy usize, isize);
+
+#[cfg(not(target_pointer_width = "16"))]
+character!(into lossless usize, isize);
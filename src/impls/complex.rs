@@ -0,0 +1,140 @@
+//! This module provides implementations of the casting traits for the Complex type, which casts
+//! each of its components separately
+
+use crate::base::CastImpl;
+use crate::casts::{Cast, Closest, Lossless, Lossy, PortableLossless};
+use crate::errors::{FailedCastError, LosslessCastError, LossyCastError};
+use crate::types::Complex;
+
+use core::fmt::Debug;
+
+impl<T> Cast for Complex<T> {}
+
+// -- Componentwise -- //
+/// Helper trait for combining the results of the casts of the components of a [`Complex`] into
+/// the result of the cast of the whole, preserving the kind of error
+///
+/// This is public only so that it may appear in the bounds of public implementations; it is not
+/// reachable from outside of the crate.
+pub trait Componentwise<T, U>: Sized {
+    /// The combined error type
+    type Error;
+
+    /// Combines the results of casting the real and imaginary parts of `from`
+    fn combine(
+        from: Complex<T>,
+        re: Result<U, Self>,
+        im: Result<U, Self>
+    ) -> Result<Complex<U>, Self::Error>;
+}
+
+impl<T, U> Componentwise<T, U> for LosslessCastError<T, U> {
+    type Error = LosslessCastError<Complex<T>, Complex<U>>;
+
+    #[inline]
+    fn combine(
+        _from: Complex<T>,
+        re: Result<U, Self>,
+        im: Result<U, Self>
+    ) -> Result<Complex<U>, Self::Error> {
+        match (re, im) {
+            (Ok(re), Ok(im)) => Ok(Complex::new(re, im)),
+
+            // This is safe because LosslessCastError cannot be instantiated
+            _ => unsafe {core::hint::unreachable_unchecked()}
+        }
+    }
+}
+
+impl<T, U> Componentwise<T, U> for LossyCastError<T, U> {
+    type Error = LossyCastError<Complex<T>, Complex<U>>;
+
+    #[inline]
+    fn combine(
+        from: Complex<T>,
+        re: Result<U, Self>,
+        im: Result<U, Self>
+    ) -> Result<Complex<U>, Self::Error> {
+        match (re, im) {
+            (Ok(re), Ok(im)) => Ok(Complex::new(re, im)),
+            (re, im) => Err(LossyCastError {from, to: Complex::new(re.lossy(), im.lossy())})
+        }
+    }
+}
+
+impl<T, U> Componentwise<T, U> for FailedCastError<T, U> {
+    type Error = FailedCastError<Complex<T>, Complex<U>>;
+
+    #[inline]
+    fn combine(
+        from: Complex<T>,
+        re: Result<U, Self>,
+        im: Result<U, Self>
+    ) -> Result<Complex<U>, Self::Error> {
+        match (re, im) {
+            (Ok(re), Ok(im)) => Ok(Complex::new(re, im)),
+            _ => Err(FailedCastError::new(from))
+        }
+    }
+}
+
+// -- Casts -- //
+impl<T: Copy + CastImpl<U>, U> CastImpl<Complex<U>> for Complex<T>
+where T::Error: Componentwise<T, U> {
+    type Error = <T::Error as Componentwise<T, U>>::Error;
+
+    #[inline]
+    fn cast_impl(self) -> Result<Complex<U>, Self::Error> {
+        Componentwise::combine(self, self.re.cast_impl(), self.im.cast_impl())
+    }
+}
+
+impl<T: Copy + CastImpl<U>, U> Closest<Complex<U>> for LossyCastError<Complex<T>, Complex<U>>
+where Result<U, T::Error>: Closest<U> {
+    #[inline]
+    fn closest(self) -> Complex<U> {
+        Complex::new(self.from.re.cast_impl().closest(), self.from.im.cast_impl().closest())
+    }
+}
+
+impl<T: Copy + CastImpl<U>, U> Closest<Complex<U>> for FailedCastError<Complex<T>, Complex<U>>
+where Result<U, T::Error>: Closest<U> {
+    #[inline]
+    fn closest(self) -> Complex<U> {
+        Complex::new(self.from.re.cast_impl().closest(), self.from.im.cast_impl().closest())
+    }
+}
+
+unsafe impl<T, U> Lossless<Complex<U>> for LossyCastError<Complex<T>, Complex<U>>
+where LossyCastError<T, U>: Lossless<U> {
+    // Defer to the proof of the component types, if any
+    const _PROOF: () = <LossyCastError<T, U> as Lossless<U>>::_PROOF;
+
+    #[inline]
+    fn lossless(self) -> Complex<U> {
+        let (from, to) = (self.from, self.to);
+        let re = LossyCastError {from: from.re, to: to.re}.lossless();
+        Complex::new(re, LossyCastError {from: from.im, to: to.im}.lossless())
+    }
+}
+
+unsafe impl<T, U> Lossless<Complex<U>> for FailedCastError<Complex<T>, Complex<U>>
+where FailedCastError<T, U>: Lossless<U> {
+    // Defer to the proof of the component types, if any
+    const _PROOF: () = <FailedCastError<T, U> as Lossless<U>>::_PROOF;
+
+    #[inline]
+    fn lossless(self) -> Complex<U> {
+        let re = FailedCastError::<T, U>::new(self.from.re).lossless();
+        Complex::new(re, FailedCastError::<T, U>::new(self.from.im).lossless())
+    }
+}
+
+impl<T: Debug, U: Debug> PortableLossless<Complex<U>> for LosslessCastError<Complex<T>, Complex<U>>
+where LosslessCastError<T, U>: PortableLossless<U> {
+    #[inline]
+    fn portable_lossless(self) -> Complex<U> {
+        // This is safe because LosslessCastError cannot be instantiated
+        unsafe {core::hint::unreachable_unchecked()}
+    }
+}
@@ -0,0 +1,540 @@
+//! This module provides implementations of the casting traits for the Decimal type
+
+use super::fixed::absolute;
+use super::ratio::{product, quotient};
+use crate::base::CastImpl;
+use crate::casts::{Bitwise, Cast, Closest, Lossless};
+use crate::errors::LossyCastError;
+use crate::types::{Decimal, Wide};
+
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+
+impl<I, const SCALE: u32> Cast for Decimal<I, SCALE> {}
+
+// -- Scaled -- //
+/// Helper trait for the integers and decimals, which cast between one another by scaling their
+/// underlying integers by powers of ten; the integers themselves have a scale of zero
+trait Scaled: Copy {
+    /// The number of decimal places of the type
+    const SCALE: u32;
+
+    /// The largest magnitude of a negative underlying integer
+    const NEGATIVE: u128;
+
+    /// The largest magnitude of a positive underlying integer
+    const POSITIVE: u128;
+
+    /// Returns the sign and magnitude of the underlying integer
+    fn to_magnitude(self) -> (bool, u128);
+
+    /// Creates a value from the sign and magnitude of its underlying integer, wrapping around as
+    /// for the `as` keyword if out of range
+    fn from_magnitude(negative: bool, magnitude: u128) -> Self;
+
+    /// Creates a value from the sign and magnitude of its underlying integer if in range, or else
+    /// returns the closest value as an error; a magnitude of [`None`] exceeds every range
+    #[inline]
+    fn saturating(negative: bool, magnitude: Option<u128>) -> Result<Self, Self> {
+        let bound = match negative {
+            true => Self::NEGATIVE,
+            false => Self::POSITIVE
+        };
+
+        match magnitude {
+            Some(magnitude) if magnitude <= bound => Ok(Self::from_magnitude(negative, magnitude)),
+            _ => Err(Self::from_magnitude(negative, bound))
+        }
+    }
+}
+
+macro_rules! scaled {
+    ($($int:ty as $wide:ty),+) => {
+        $(
+            #[allow(clippy::cast_lossless, clippy::cast_sign_loss)]
+            impl Scaled for $int {
+                const SCALE: u32 = 0;
+                const NEGATIVE: u128 = (<$int>::MIN as i128).unsigned_abs();
+                const POSITIVE: u128 = <$int>::MAX as u128;
+
+                #[inline]
+                fn to_magnitude(self) -> (bool, u128) {
+                    let wide = self as $wide;
+                    (wide.is_negative(), wide.magnitude())
+                }
+
+                #[inline]
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                fn from_magnitude(negative: bool, magnitude: u128) -> Self {
+                    match negative {
+                        true => (magnitude as $int).wrapping_neg(),
+                        false => magnitude as $int
+                    }
+                }
+            }
+
+            impl<const SCALE: u32> Scaled for Decimal<$int, SCALE> {
+                const SCALE: u32 = SCALE;
+                const NEGATIVE: u128 = <$int as Scaled>::NEGATIVE;
+                const POSITIVE: u128 = <$int as Scaled>::POSITIVE;
+
+                #[inline]
+                fn to_magnitude(self) -> (bool, u128) {
+                    self.to_bits().to_magnitude()
+                }
+
+                #[inline]
+                fn from_magnitude(negative: bool, magnitude: u128) -> Self {
+                    Self::from_bits(<$int>::from_magnitude(negative, magnitude))
+                }
+            }
+        )*
+    };
+}
+
+scaled!(
+    u8 as u128, u16 as u128, u32 as u128, u64 as u128, u128 as u128, usize as u128,
+    i8 as i128, i16 as i128, i32 as i128, i64 as i128, i128 as i128, isize as i128
+);
+
+// -- Proof -- //
+/// Helper type for proving at compile time that casts involving [`Decimal`] are lossless; the
+/// proof is an associated constant which fails to evaluate if the cast could be lossy
+struct Proof<From, To>(PhantomData<(From, To)>);
+
+impl<From: Scaled, To: Scaled> Proof<From, To> {
+    /// Proves that every value of `From` is exactly representable by `To`
+    const LOSSLESS: () = assert!(
+        widens(From::NEGATIVE, From::SCALE, To::NEGATIVE, To::SCALE)
+            && widens(From::POSITIVE, From::SCALE, To::POSITIVE, To::SCALE),
+        "Cast is not guaranteed lossless by the ranges and scales of the decimal type"
+    );
+}
+
+/// Returns whether every magnitude up to `from` at `from_scale` is a magnitude up to `to` at
+/// `to_scale`
+const fn widens(from: u128, from_scale: u32, to: u128, to_scale: u32) -> bool {
+    if from == 0 {
+        return true;
+    }
+
+    if to_scale < from_scale {
+        return false;
+    }
+
+    match 10u128.checked_pow(to_scale - from_scale) {
+        Some(power) => match from.checked_mul(power) {
+            Some(scaled) => scaled <= to,
+            None => false
+        },
+        None => false
+    }
+}
+
+// -- Arithmetic -- //
+/// Rounds `quotient + remainder / divisor` to nearest with ties to even, where the remainder is
+/// less than the divisor, returning [`None`] if the result exceeds 128 bits
+#[inline]
+fn even(quotient: u128, remainder: u128, divisor: u128) -> Option<u128> {
+    let rest = divisor - remainder;
+    let up = remainder > rest || (remainder == rest && quotient & 1 == 1);
+    quotient.checked_add(u128::from(up))
+}
+
+/// Casts `from` to `To` by scaling its underlying integer by a power of ten, returning the lossy
+/// value (truncated towards zero and wrapped) and the closest value (rounded to nearest with ties
+/// to even and saturated) as an error if the cast is not exact
+#[inline]
+fn rescale<From: Scaled, To: Scaled>(from: From) -> Result<To, (To, To)> {
+    let (negative, magnitude) = from.to_magnitude();
+
+    // Scaling up is exact unless the magnitude overflows, in which case it wraps modulo 2^128 as
+    // for the `as` keyword
+    if To::SCALE >= From::SCALE {
+        let exponent = To::SCALE - From::SCALE;
+        let scaled = magnitude.checked_mul(10u128.pow(exponent));
+
+        return To::saturating(negative, scaled).map_err(|closest| {
+            let wrapped = magnitude.wrapping_mul(10u128.pow(exponent));
+            (To::from_magnitude(negative, wrapped), closest)
+        });
+    }
+
+    // Scaling down is exact unless there is a remainder
+    let power = 10u128.pow(From::SCALE - To::SCALE);
+    let (truncated, remainder) = (magnitude / power, magnitude % power);
+
+    match (remainder, To::saturating(negative, even(truncated, remainder, power))) {
+        (0, Ok(value)) => Ok(value),
+        (_, Ok(closest) | Err(closest)) => Err((To::from_magnitude(negative, truncated), closest))
+    }
+}
+
+// -- Floats -- //
+/// Returns the magnitude of `value` times `power`, truncated towards zero and rounded to nearest
+/// with ties to even, where [`None`] exceeds 128 bits; `value` must not be NaN
+///
+/// The float is exactly `mantissa * 2^exponent`, so the product with `power` is exact in 256 bits
+/// and the shift by the exponent determines the rounding.
+fn scale(value: f64, power: u128) -> (Option<u128>, Option<u128>) {
+    let bits = value.to_bits();
+    let (field, fraction) = ((bits >> 52) & 0x7ff, bits & ((1 << 52) - 1));
+
+    #[allow(clippy::cast_possible_truncation)]
+    let (mantissa, exponent) = match field {
+        0x7ff => return (None, None),
+        0 => (fraction, -1074),
+        _ => (fraction | (1 << 52), field as i32 - 1075)
+    };
+
+    let (high, low) = product(u128::from(mantissa), power);
+
+    // Scaling up by 2^exponent is exact unless it overflows; zero has no significant bits, so its
+    // exponent is always negative
+    if let Ok(shift) = u32::try_from(exponent) {
+        let scaled = match high == 0 && shift <= low.leading_zeros() {
+            true => Some(low << shift),
+            false => None
+        };
+
+        return (scaled, scaled);
+    }
+
+    // Scaling down splits the product into the integral part, the dropped bits and half of the
+    // least significant bit of the integral part, where the latter two are compared as words
+    let (integral, fits, dropped, half) = match exponent.unsigned_abs() {
+        shift @ 1 ..= 127 => (
+            (high << (128 - shift)) | (low >> shift),
+            high >> shift == 0,
+            (0, low & ((1 << shift) - 1)),
+            (0, 1 << (shift - 1))
+        ),
+        128 => (high, true, (0, low), (0, 1 << 127)),
+        shift @ 129 ..= 255 => (
+            high >> (shift - 128),
+            true,
+            (high & ((1 << (shift - 128)) - 1), low),
+            (1 << (shift - 129), 0)
+        ),
+
+        // The product is below 2^181, so is less than half of the least significant bit
+        _ => return (Some(0), Some(0))
+    };
+
+    let truncated = match fits {
+        true => Some(integral),
+        false => None
+    };
+
+    let nearest = match dropped.cmp(&half) {
+        Ordering::Less => truncated,
+        Ordering::Equal if integral & 1 == 0 => truncated,
+        _ => truncated.and_then(|integral| integral.checked_add(1))
+    };
+
+    (truncated, nearest)
+}
+
+/// Casts `value` to `To`, rounding to a float with `precision` significant bits whose least
+/// significant bit is at least `2^min_exponent` to check that `value` is the nearest such float to
+/// the result, and otherwise returning the lossy value (truncated towards zero and saturated) and
+/// the closest value (rounded to nearest with ties to even and saturated) as an error
+fn from_float<To: Scaled>(value: f64, precision: u32, min_exponent: i32) -> Result<To, (To, To)> {
+    if value.is_nan() {
+        return Err((To::from_magnitude(false, 0), To::from_magnitude(false, 0)));
+    }
+
+    let (negative, power) = (value.is_sign_negative(), 10u128.pow(To::SCALE));
+    let (truncated, nearest) = scale(value, power);
+    let lossy = To::saturating(negative, truncated).unwrap_or_else(|closest| closest);
+
+    // The result is exact only if it rounds back to the float
+    match (To::saturating(negative, nearest), nearest) {
+        (Ok(decimal), Some(magnitude)) => {
+            match round_trips(value, magnitude, power, precision, min_exponent) {
+                true => Ok(decimal),
+                false => Err((lossy, decimal))
+            }
+        },
+        (Ok(closest) | Err(closest), _) => Err((lossy, closest))
+    }
+}
+
+/// Returns whether `magnitude / power` rounds to the magnitude of `value` as a float with
+/// `precision` significant bits whose least significant bit is at least `2^min_exponent`
+#[inline]
+fn round_trips(
+    value: f64,
+    magnitude: u128,
+    power: u128,
+    precision: u32,
+    min_exponent: i32
+) -> bool {
+    match magnitude {
+        0 => value == 0.0,
+        _ => quotient(magnitude, power, precision, min_exponent).0 == absolute(value)
+    }
+}
+
+/// Casts `from` to the nearest float with `precision` significant bits whose least significant bit
+/// is at least `2^min_exponent`, returning it as an [`f64`] along with whether `from` is in turn
+/// the nearest value of its type to the float
+fn into_float<From: Scaled>(from: From, precision: u32, min_exponent: i32) -> (f64, bool) {
+    let (negative, magnitude) = from.to_magnitude();
+    let power = 10u128.pow(From::SCALE);
+
+    let value = match magnitude {
+        0 => 0.0,
+        _ => quotient(magnitude, power, precision, min_exponent).0
+    };
+
+    let exact = scale(value, power).1 == Some(magnitude);
+
+    match negative {
+        true => (-value, exact),
+        false => (value, exact)
+    }
+}
+
+// -- Casts -- //
+macro_rules! decimal {
+    // Implements Cast, Closest and Lossless for Decimal<$int> -> $to via LossyCastError, where
+    // $to is a primitive integer
+    (into $int:ty => integer $($to:ty),+) => {
+        $(
+            impl<const SCALE: u32> CastImpl<$to> for Decimal<$int, SCALE> {
+                type Error = LossyCastError<Self, $to>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$to, Self::Error> {
+                    rescale(self).map_err(|(lossy, _)| LossyCastError {from: self, to: lossy})
+                }
+            }
+
+            impl<const SCALE: u32> Closest<$to> for LossyCastError<Decimal<$int, SCALE>, $to> {
+                #[inline]
+                fn closest(self) -> $to {
+                    rescale(self.from).unwrap_or_else(|(_, closest)| closest)
+                }
+            }
+
+            unsafe impl<const SCALE: u32> Lossless<$to>
+            for LossyCastError<Decimal<$int, SCALE>, $to> {
+                // Fail compilation unless the ranges and scales prove the cast to be lossless
+                const _PROOF: () = Proof::<Decimal<$int, SCALE>, $to>::LOSSLESS;
+
+                #[inline]
+                fn lossless(self) -> $to {
+                    // The proof guarantees that cove never produces this error, but it could still
+                    // be constructed by hand; the closest value is exact for valid values
+                    #[allow(clippy::let_unit_value)]
+                    let () = Self::_PROOF;
+                    self.closest()
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and Closest for Decimal<$int> -> $to via LossyCastError, where $to is a
+    // primitive float
+    (into $int:ty => float $($to:ty),+) => {
+        $(
+            impl<const SCALE: u32> CastImpl<$to> for Decimal<$int, SCALE> {
+                type Error = LossyCastError<Self, $to>;
+
+                #[inline]
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                fn cast_impl(self) -> Result<$to, Self::Error> {
+                    let (value, exact) = into_float(
+                        self,
+                        <$to>::MANTISSA_DIGITS,
+                        <$to>::MIN_EXP - <$to>::MANTISSA_DIGITS as i32
+                    );
+
+                    match exact {
+                        true => Ok(value as $to),
+                        false => Err(LossyCastError {from: self, to: value as $to})
+                    }
+                }
+            }
+
+            impl<const SCALE: u32> Closest<$to> for LossyCastError<Decimal<$int, SCALE>, $to> {
+                #[inline]
+                fn closest(self) -> $to {
+                    // The lossy value is rounded to nearest, save that it may overflow
+                    self.to.clamp(<$to>::MIN, <$to>::MAX)
+                }
+            }
+        )*
+    };
+
+    // Implements Cast, Closest and Lossless for $from -> Decimal<$int> via LossyCastError, where
+    // $from is a primitive integer
+    (from $int:ty => integer $($from:ty),+) => {
+        $(
+            impl<const SCALE: u32> CastImpl<Decimal<$int, SCALE>> for $from {
+                type Error = LossyCastError<Self, Decimal<$int, SCALE>>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<Decimal<$int, SCALE>, Self::Error> {
+                    rescale(self).map_err(|(lossy, _)| LossyCastError {from: self, to: lossy})
+                }
+            }
+
+            impl<const SCALE: u32> Closest<Decimal<$int, SCALE>>
+            for LossyCastError<$from, Decimal<$int, SCALE>> {
+                #[inline]
+                fn closest(self) -> Decimal<$int, SCALE> {
+                    rescale(self.from).unwrap_or_else(|(_, closest)| closest)
+                }
+            }
+
+            unsafe impl<const SCALE: u32> Lossless<Decimal<$int, SCALE>>
+            for LossyCastError<$from, Decimal<$int, SCALE>> {
+                // Fail compilation unless the ranges and scales prove the cast to be lossless
+                const _PROOF: () = Proof::<$from, Decimal<$int, SCALE>>::LOSSLESS;
+
+                #[inline]
+                fn lossless(self) -> Decimal<$int, SCALE> {
+                    // The proof guarantees that cove never produces this error, but it could still
+                    // be constructed by hand; the closest value is exact for valid values
+                    #[allow(clippy::let_unit_value)]
+                    let () = Self::_PROOF;
+                    self.closest()
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and Closest for $from -> Decimal<$int> via LossyCastError, where $from is a
+    // primitive float
+    (from $int:ty => float $($from:ty),+) => {
+        $(
+            impl<const SCALE: u32> CastImpl<Decimal<$int, SCALE>> for $from {
+                type Error = LossyCastError<Self, Decimal<$int, SCALE>>;
+
+                #[inline]
+                #[allow(clippy::cast_possible_wrap)]
+                fn cast_impl(self) -> Result<Decimal<$int, SCALE>, Self::Error> {
+                    from_float(
+                        f64::from(self),
+                        <$from>::MANTISSA_DIGITS,
+                        <$from>::MIN_EXP - <$from>::MANTISSA_DIGITS as i32
+                    ).map_err(|(lossy, _)| LossyCastError {from: self, to: lossy})
+                }
+            }
+
+            impl<const SCALE: u32> Closest<Decimal<$int, SCALE>>
+            for LossyCastError<$from, Decimal<$int, SCALE>> {
+                #[inline]
+                #[allow(clippy::cast_possible_wrap)]
+                fn closest(self) -> Decimal<$int, SCALE> {
+                    from_float(
+                        f64::from(self.from),
+                        <$from>::MANTISSA_DIGITS,
+                        <$from>::MIN_EXP - <$from>::MANTISSA_DIGITS as i32
+                    ).unwrap_or_else(|(_, closest)| closest)
+                }
+            }
+        )*
+    };
+
+    // Implements Cast, Closest and Lossless for Decimal<$int> -> Decimal<$to> via LossyCastError,
+    // between any two scales
+    (between $int:ty => $($to:ty),+) => {
+        $(
+            impl<const FROM: u32, const TO: u32> CastImpl<Decimal<$to, TO>>
+            for Decimal<$int, FROM> {
+                type Error = LossyCastError<Self, Decimal<$to, TO>>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<Decimal<$to, TO>, Self::Error> {
+                    rescale(self).map_err(|(lossy, _)| LossyCastError {from: self, to: lossy})
+                }
+            }
+
+            impl<const FROM: u32, const TO: u32> Closest<Decimal<$to, TO>>
+            for LossyCastError<Decimal<$int, FROM>, Decimal<$to, TO>> {
+                #[inline]
+                fn closest(self) -> Decimal<$to, TO> {
+                    rescale(self.from).unwrap_or_else(|(_, closest)| closest)
+                }
+            }
+
+            unsafe impl<const FROM: u32, const TO: u32> Lossless<Decimal<$to, TO>>
+            for LossyCastError<Decimal<$int, FROM>, Decimal<$to, TO>> {
+                // Fail compilation unless the ranges and scales prove the cast to be lossless
+                const _PROOF: () = Proof::<Decimal<$int, FROM>, Decimal<$to, TO>>::LOSSLESS;
+
+                #[inline]
+                fn lossless(self) -> Decimal<$to, TO> {
+                    // The proof guarantees that cove never produces this error, but it could still
+                    // be constructed by hand; the closest value is exact for valid values
+                    #[allow(clippy::let_unit_value)]
+                    let () = Self::_PROOF;
+                    self.closest()
+                }
+            }
+        )*
+    };
+
+    // Implements Bitwise in both directions between Decimal<$int> and $int
+    (bitwise $int:ty) => {
+        impl<const SCALE: u32> Bitwise<$int>
+        for Result<$int, LossyCastError<Decimal<$int, SCALE>, $int>> {
+            #[inline]
+            #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+            fn bitwise(self) -> $int {
+                match self {
+                    // The cast was exact, so scaling the value back up is too; the product is in
+                    // range, so it can be computed modulo the width of $int
+                    Ok(value) => value.wrapping_mul(Decimal::<$int, SCALE>::POWER as $int),
+                    Err(error) => error.from.to_bits()
+                }
+            }
+        }
+
+        impl<const SCALE: u32> Bitwise<Decimal<$int, SCALE>>
+        for Result<Decimal<$int, SCALE>, LossyCastError<$int, Decimal<$int, SCALE>>> {
+            #[inline]
+            fn bitwise(self) -> Decimal<$int, SCALE> {
+                match self {
+                    // The cast was exact, so scaling the value back down is too
+                    Ok(value) => {
+                        let (negative, magnitude) = value.to_magnitude();
+                        let power = Decimal::<$int, SCALE>::POWER;
+                        Decimal::<$int, SCALE>::from_magnitude(negative, magnitude / power)
+                    },
+                    Err(error) => Decimal::<$int, SCALE>::from_bits(error.from)
+                }
+            }
+        }
+    };
+
+    // Generates all implementations for each `$int`
+    ($($int:ty),+) => {
+        $(
+            decimal!(
+                into $int =>
+                integer u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+            );
+
+            decimal!(into $int => float f32, f64);
+
+            decimal!(
+                from $int =>
+                integer u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+            );
+
+            decimal!(from $int => float f32, f64);
+            decimal!(
+                between $int =>
+                u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+            );
+
+            decimal!(bitwise $int);
+        )*
+    }
+}
+
+decimal!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
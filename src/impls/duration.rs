@@ -0,0 +1,314 @@
+//! This module provides implementations of the casting traits between Duration and the time unit
+//! types
+
+use super::fixed::power_of_two;
+use super::numeric::{two_product, PARTS};
+use super::wide;
+use crate::base::CastImpl;
+use crate::casts::{Cast, Closest, Lossless, Lossy, PortableLossless};
+use crate::errors::{LosslessCastError, LossyCastError};
+use crate::types::{i256, Micros, Millis, Nanos, Secs};
+
+use core::time::Duration;
+
+impl Cast for Duration {}
+
+/// The number of nanoseconds in the longest [`Duration`]
+const MAX_NANOS: u128 = Duration::MAX.as_nanos();
+
+/// The number of nanoseconds per second
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Returns the [`Duration`] of `nanos` nanoseconds, which must be at most [`MAX_NANOS`]
+#[inline]
+#[allow(clippy::cast_possible_truncation)]
+fn duration(nanos: u128) -> Duration {
+    Duration::new((nanos / NANOS_PER_SEC) as u64, (nanos % NANOS_PER_SEC) as u32)
+}
+
+/// Scales `value` by `unit` to a whole number of nanoseconds, returning the nanoseconds if they are
+/// exact; otherwise, returns them truncated towards zero and rounded to nearest (with ties away
+/// from zero), both saturated to the range of [`Duration`] and with NaN yielding zero
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss, clippy::cast_sign_loss)]
+fn nanoseconds(value: f64, unit: u128) -> Result<u128, (u128, u128)> {
+    let (product, error) = two_product(value, unit as f64);
+
+    match product {
+        product if product.is_nan() || product < 0.0 => return Err((0, 0)),
+        product if product >= power_of_two(128) => return Err((MAX_NANOS, MAX_NANOS)),
+        _ => ()
+    }
+
+    // The product is the nearest f64 to the scaled value, so no integer lies between them; the
+    // floor of the value is hence that of the product, unless the product is itself an integer
+    // and the error is negative. Likewise, the error only decides rounding at a tie. Truncating
+    // casts are exact for the integral parts, which makes the fractional parts exact too.
+    let truncated = product as u128;
+    let (floor, exact, up) = match product - truncated as f64 {
+        0.0 => {
+            let whole = error as i128;
+            let fraction = error - whole as f64;
+            let floor = truncated.checked_add_signed(whole - i128::from(fraction < 0.0));
+            (floor, fraction == 0.0, fraction >= 0.5 || (-0.5 .. 0.0).contains(&fraction))
+        },
+        fraction => {
+            let up = fraction > 0.5 || (fraction == 0.5 && error >= 0.0);
+            (Some(truncated), false, up)
+        }
+    };
+
+    match floor {
+        Some(floor) if floor <= MAX_NANOS => match exact {
+            true => Ok(floor),
+            false => Err((floor, (floor + u128::from(up)).min(MAX_NANOS)))
+        },
+        _ => Err((MAX_NANOS, MAX_NANOS))
+    }
+}
+
+/// Divides `nanos` by `unit`, returning an integer and a binary exponent whose product is the
+/// quotient, along with whether the quotient is exact
+///
+/// The integer has at least 56 significant bits, the last of which is set if the division has a
+/// remainder, so that rounding it to a float rounds the exact quotient correctly.
+#[allow(clippy::cast_possible_wrap)]
+fn quotient(nanos: u128, unit: u128) -> (u128, i32, bool) {
+    let shift = (184 - unit.leading_zeros()).saturating_sub(128 - nanos.leading_zeros());
+    let dividend = nanos << shift;
+    let remainder = dividend % unit;
+    (((dividend / unit) << 1) | u128::from(remainder != 0), -(shift as i32) - 1, remainder == 0)
+}
+
+/// Splits the integer `value` scaled by `unit` into parts as for `Numeric::parts`, which is exact
+/// since the product fits in 256 bits
+#[inline]
+pub(super) fn integer_parts(value: i256, unit: u128) -> [f64; PARTS] {
+    // The product modulo 2^256 is the same for two's complement, and the low word of the value is
+    // multiplied in halves so that no product exceeds 128 bits
+    let [low, high] = value.to_words();
+    let (low_half, high_half) = ((low & u128::from(u64::MAX)) * unit, (low >> 64) * unit);
+    let (word, carry) = low_half.overflowing_add(high_half << 64);
+    let high = high.wrapping_mul(unit).wrapping_add(high_half >> 64);
+    let words = [word, high.wrapping_add(u128::from(carry))];
+    wide::parts(words, value.is_negative())
+}
+
+/// Splits the float `value` scaled by `unit` into parts as for `Numeric::parts`, which is exact
+/// unless the product overflows to infinity
+#[inline]
+#[allow(clippy::cast_precision_loss)]
+pub(super) fn float_parts(value: f64, unit: u128) -> [f64; PARTS] {
+    match two_product(value, unit as f64) {
+        (product, error) if product.is_finite() => [product, error, 0.0, 0.0, 0.0],
+        (product, _error) => [product, 0.0, 0.0, 0.0, 0.0]
+    }
+}
+
+// -- Casts -- //
+macro_rules! duration {
+    // Implements Cast and Closest for Duration -> $unit<$to> via LossyCastError, where $to is a
+    // primitive integer. Casts are performed via the number of whole units.
+    (into $unit:ident => integer $($to:ty),+) => {
+        $(
+            impl CastImpl<$unit<$to>> for Duration {
+                type Error = LossyCastError<Self, $unit<$to>>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$unit<$to>, Self::Error> {
+                    let (nanos, unit) = (self.as_nanos(), $unit::<$to>::NANOS);
+
+                    match (nanos % unit == 0, (nanos / unit).cast::<$to>()) {
+                        (true, Ok(value)) => Ok($unit(value)),
+                        (_, result) => Err(LossyCastError {from: self, to: $unit(result.lossy())})
+                    }
+                }
+            }
+
+            impl Closest<$unit<$to>> for LossyCastError<Duration, $unit<$to>> {
+                #[inline]
+                fn closest(self) -> $unit<$to> {
+                    let unit = $unit::<$to>::NANOS;
+                    $unit(((self.from.as_nanos() + unit / 2) / unit).cast::<$to>().closest())
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and PortableLossless for Duration -> $unit<$to> via LosslessCastError, where
+    // $unit is the nanosecond and $to holds the 95 bits of any Duration
+    (into $unit:ident => lossless $($to:ty),+) => {
+        $(
+            impl CastImpl<$unit<$to>> for Duration {
+                type Error = LosslessCastError<Self, $unit<$to>>;
+
+                #[inline]
+                #[allow(clippy::cast_possible_wrap)]
+                fn cast_impl(self) -> Result<$unit<$to>, Self::Error> {
+                    Ok($unit((self.as_nanos() / $unit::<$to>::NANOS) as $to))
+                }
+            }
+
+            impl PortableLossless<$unit<$to>> for LosslessCastError<Duration, $unit<$to>> {
+                #[inline]
+                fn portable_lossless(self) -> $unit<$to> {
+                    // This is safe because LosslessCastError cannot be instantiated
+                    unsafe {core::hint::unreachable_unchecked()}
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and Closest for Duration -> $unit<$to> via LossyCastError, where $to is a
+    // primitive float. The quotient is rounded to nearest, which never overflows.
+    (into $unit:ident => float $($to:ty),+) => {
+        $(
+            impl CastImpl<$unit<$to>> for Duration {
+                type Error = LossyCastError<Self, $unit<$to>>;
+
+                #[inline]
+                #[allow(clippy::cast_possible_truncation)]
+                fn cast_impl(self) -> Result<$unit<$to>, Self::Error> {
+                    let (bits, exponent, exact) = quotient(self.as_nanos(), $unit::<$to>::NANOS);
+                    let scale = power_of_two(exponent) as $to;
+
+                    match bits.cast::<$to>() {
+                        Ok(value) if exact => Ok($unit(value * scale)),
+                        result => Err(LossyCastError {
+                            from: self,
+                            to: $unit(result.lossy() * scale)
+                        })
+                    }
+                }
+            }
+
+            impl Closest<$unit<$to>> for LossyCastError<Duration, $unit<$to>> {
+                #[inline]
+                fn closest(self) -> $unit<$to> {
+                    self.to
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and Closest for $unit<$from> -> Duration via LossyCastError, where $from is a
+    // primitive integer. The lossy value saturates, so it is also the closest.
+    (from $unit:ident => integer $($from:ty),+) => {
+        $(
+            impl CastImpl<Duration> for $unit<$from> {
+                type Error = LossyCastError<Self, Duration>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<Duration, Self::Error> {
+                    let nanos = self.0.cast::<u128>().map(|value| value.checked_mul(Self::NANOS));
+
+                    match nanos {
+                        Ok(Some(nanos)) if nanos <= MAX_NANOS => Ok(duration(nanos)),
+                        Ok(_) => Err(LossyCastError {from: self, to: Duration::MAX}),
+                        Err(_error) => Err(LossyCastError {from: self, to: Duration::ZERO})
+                    }
+                }
+            }
+
+            impl Closest<Duration> for LossyCastError<$unit<$from>, Duration> {
+                #[inline]
+                fn closest(self) -> Duration {
+                    self.to
+                }
+            }
+        )*
+    };
+
+    // Implements Cast for $unit<$from> -> Duration via LosslessCastError, where $from is a
+    // primitive integer of at most 64 bits
+    (from $unit:ident => lossless $($from:ty),+) => {
+        $(
+            impl CastImpl<Duration> for $unit<$from> {
+                type Error = LosslessCastError<Self, Duration>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<Duration, Self::Error> {
+                    Ok(duration(self.0.cast::<u128>().lossless() * Self::NANOS))
+                }
+            }
+        )*
+    };
+
+    // Implements PortableLossless for $unit<$from> -> Duration, where the Cast is lossless on
+    // every platform
+    (from $unit:ident => portable $($from:ty),+) => {
+        $(
+            impl PortableLossless<Duration> for LosslessCastError<$unit<$from>, Duration> {
+                #[inline]
+                fn portable_lossless(self) -> Duration {
+                    // This is safe because LosslessCastError cannot be instantiated
+                    unsafe {core::hint::unreachable_unchecked()}
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and Closest for $unit<$from> -> Duration via LossyCastError, where $from is a
+    // primitive float. The value is scaled to nanoseconds exactly in f64.
+    (from $unit:ident => float $($from:ty),+) => {
+        $(
+            impl CastImpl<Duration> for $unit<$from> {
+                type Error = LossyCastError<Self, Duration>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<Duration, Self::Error> {
+                    match nanoseconds(self.0.into(), Self::NANOS) {
+                        Ok(nanos) => Ok(duration(nanos)),
+                        Err((truncated, _nearest)) => Err(LossyCastError {
+                            from: self,
+                            to: duration(truncated)
+                        })
+                    }
+                }
+            }
+
+            impl Closest<Duration> for LossyCastError<$unit<$from>, Duration> {
+                #[inline]
+                fn closest(self) -> Duration {
+                    match nanoseconds(self.from.0.into(), $unit::<$from>::NANOS) {
+                        Ok(nanos) | Err((_, nanos)) => duration(nanos)
+                    }
+                }
+            }
+        )*
+    };
+
+    // Generates all implementations for each `$unit`
+    ($($unit:ident),+) => {
+        $(
+            impl<T> Cast for $unit<T> {}
+
+            duration!(into $unit => integer u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+            duration!(into $unit => float f32, f64);
+
+            duration!(from $unit => integer u128, i8, i16, i32, i64, i128, isize);
+            duration!(from $unit => lossless u8, u16, u32, u64);
+            duration!(from $unit => portable u8, u16, u32, u64);
+            duration!(from $unit => float f32, f64);
+
+            #[cfg(any(
+                target_pointer_width = "16",
+                target_pointer_width = "32",
+                target_pointer_width = "64"
+            ))]
+            duration!(from $unit => lossless usize);
+
+            #[cfg(not(any(
+                target_pointer_width = "16",
+                target_pointer_width = "32",
+                target_pointer_width = "64"
+            )))]
+            duration!(from $unit => integer usize);
+        )*
+    };
+}
+
+duration!(Secs, Millis, Micros, Nanos);
+duration!(into Secs => integer u128, i128);
+duration!(into Millis => integer u128, i128);
+duration!(into Micros => integer u128, i128);
+duration!(into Nanos => lossless u128, i128);
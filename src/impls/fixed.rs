@@ -0,0 +1,285 @@
+//! This module provides implementations of the casting traits for the Fixed type
+
+use crate::base::CastImpl;
+use crate::casts::{Bitwise, Cast, Closest, Lossless, Lossy};
+use crate::errors::LossyCastError;
+use crate::types::{BitRange, Fixed, Wide};
+
+use core::marker::PhantomData;
+
+impl<I, const FRAC: u32> Cast for Fixed<I, FRAC> {}
+
+// -- Proof -- //
+/// Helper type for proving at compile time that casts involving [`Fixed`] are lossless; the proof
+/// is an associated constant which fails to evaluate if the cast could be lossy
+struct Proof<From, To>(PhantomData<(From, To)>);
+
+impl<From: BitRange, To: BitRange> Proof<From, To> {
+    /// Proves that every value of `From` is exactly representable by `To`
+    const LOSSLESS: () = assert!(
+        From::UNSIGNED_FITS <= To::UNSIGNED_HOLDS || From::SIGNED_FITS <= To::SIGNED_HOLDS,
+        "Cast is not guaranteed lossless by the bits of the fixed-point type"
+    );
+}
+
+impl<I: BitRange, const FRAC: u32, To: BitRange> Proof<Fixed<I, FRAC>, To> {
+    /// Proves that every underlying integer of `Fixed<I, FRAC>` is exactly representable by `To`,
+    /// which suffices for floats since scaling by `2^-FRAC` is then exact
+    const SCALED: () = assert!(
+        I::UNSIGNED_FITS <= To::UNSIGNED_HOLDS || I::SIGNED_FITS <= To::SIGNED_HOLDS,
+        "Cast is not guaranteed lossless by the bits of the fixed-point type"
+    );
+}
+
+/// Returns `2^exponent` for exponents within the range of normal [`f64`] values
+#[inline]
+#[allow(clippy::cast_sign_loss)]
+pub(super) fn power_of_two(exponent: i32) -> f64 {
+    f64::from_bits(((1023 + exponent) as u64) << 52)
+}
+
+/// Returns the absolute value of `value`, as [`f64::abs`] is not available in `core` before 1.85
+#[inline]
+pub(super) fn absolute(value: f64) -> f64 {
+    f64::from_bits(value.to_bits() & !(1 << 63))
+}
+
+// -- Casts -- //
+macro_rules! fixed {
+    // Implements Cast, Closest and Lossless for Fixed<$int> -> $to via LossyCastError, where $to
+    // is a primitive integer. Casts are performed via the integral part of the value in $wide.
+    (into $int:ty as $wide:ty => integer $($to:ty),+) => {
+        $(
+            impl<const FRAC: u32> CastImpl<$to> for Fixed<$int, FRAC> {
+                type Error = LossyCastError<Self, $to>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$to, Self::Error> {
+                    let (integral, exact) = self.widen().truncated(FRAC);
+
+                    match (exact, integral.cast::<$to>()) {
+                        (true, Ok(value)) => Ok(value),
+                        (_, result) => Err(LossyCastError {from: self, to: result.lossy()})
+                    }
+                }
+            }
+
+            impl<const FRAC: u32> Closest<$to> for LossyCastError<Fixed<$int, FRAC>, $to> {
+                #[inline]
+                fn closest(self) -> $to {
+                    self.from.widen().nearest(FRAC).cast::<$to>().closest()
+                }
+            }
+
+            unsafe impl<const FRAC: u32> Lossless<$to> for LossyCastError<Fixed<$int, FRAC>, $to> {
+                // Fail compilation unless the bits prove the cast to be lossless
+                const _PROOF: () = Proof::<Fixed<$int, FRAC>, $to>::LOSSLESS;
+
+                #[inline]
+                fn lossless(self) -> $to {
+                    // The proof guarantees that cove never produces this error, but it could still
+                    // be constructed by hand; the closest value is exact for valid values
+                    #[allow(clippy::let_unit_value)]
+                    let () = Self::_PROOF;
+                    self.closest()
+                }
+            }
+        )*
+    };
+
+    // Implements Cast, Closest and Lossless for Fixed<$int> -> $to via LossyCastError, where $to
+    // is a primitive float. The underlying integer is rounded to $to and then scaled by 2^-FRAC,
+    // which is exact since the rounded integer has no bits below the least significant bit of the
+    // smallest value.
+    (into $int:ty as $wide:ty => float $($to:ty),+) => {
+        $(
+            impl<const FRAC: u32> CastImpl<$to> for Fixed<$int, FRAC> {
+                type Error = LossyCastError<Self, $to>;
+
+                #[inline]
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                fn cast_impl(self) -> Result<$to, Self::Error> {
+                    let scale = power_of_two(-(FRAC as i32)) as $to;
+
+                    match self.widen().cast::<$to>() {
+                        Ok(value) => Ok(value * scale),
+
+                        // Rounding may carry a u128 to 2^128, which overflows f32; the scaled
+                        // value is then computed exactly in f64 instead
+                        Err(error) => Err(LossyCastError {
+                            from: self,
+                            to: match error.to.is_infinite() {
+                                true => power_of_two(128 - FRAC as i32) as $to,
+                                false => error.to * scale
+                            }
+                        })
+                    }
+                }
+            }
+
+            impl<const FRAC: u32> Closest<$to> for LossyCastError<Fixed<$int, FRAC>, $to> {
+                #[inline]
+                fn closest(self) -> $to {
+                    // The lossy value is rounded to nearest, save that it may overflow
+                    self.to.min(<$to>::MAX)
+                }
+            }
+
+            unsafe impl<const FRAC: u32> Lossless<$to> for LossyCastError<Fixed<$int, FRAC>, $to> {
+                // Fail compilation unless the bits prove the cast to be lossless
+                const _PROOF: () = Proof::<Fixed<$int, FRAC>, $to>::SCALED;
+
+                #[inline]
+                fn lossless(self) -> $to {
+                    // The proof guarantees that cove never produces this error, but it could still
+                    // be constructed by hand; the closest value is exact for valid values
+                    #[allow(clippy::let_unit_value)]
+                    let () = Self::_PROOF;
+                    self.closest()
+                }
+            }
+        )*
+    };
+
+    // Implements Cast, Closest and Lossless for $from -> Fixed<$int> via LossyCastError, where
+    // $from is a primitive integer. The value fits if it fits in $int and shifting it into place
+    // is reversible.
+    (from $int:ty => integer $($from:ty),+) => {
+        $(
+            impl<const FRAC: u32> CastImpl<Fixed<$int, FRAC>> for $from {
+                type Error = LossyCastError<Self, Fixed<$int, FRAC>>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<Fixed<$int, FRAC>, Self::Error> {
+                    let integral = self.cast::<$int>();
+                    let bits = integral.lossy().checked_shl(FRAC).unwrap_or(0);
+                    let fixed = Fixed::<$int, FRAC>::from_bits(bits);
+
+                    let reversible = bits.checked_shr(FRAC).unwrap_or(0) == integral.lossy();
+
+                    match integral.is_ok() && reversible {
+                        true => Ok(fixed),
+                        false => Err(LossyCastError {from: self, to: fixed})
+                    }
+                }
+            }
+
+            impl<const FRAC: u32> Closest<Fixed<$int, FRAC>>
+            for LossyCastError<$from, Fixed<$int, FRAC>> {
+                #[inline]
+                fn closest(self) -> Fixed<$int, FRAC> {
+                    // Values which do not fit are too large in magnitude, so saturate
+                    match self.from > 0 {
+                        true => Fixed::<$int, FRAC>::MAX,
+                        false => Fixed::<$int, FRAC>::MIN
+                    }
+                }
+            }
+
+            unsafe impl<const FRAC: u32> Lossless<Fixed<$int, FRAC>>
+            for LossyCastError<$from, Fixed<$int, FRAC>> {
+                // Fail compilation unless the bits prove the cast to be lossless
+                const _PROOF: () = Proof::<$from, Fixed<$int, FRAC>>::LOSSLESS;
+
+                #[inline]
+                fn lossless(self) -> Fixed<$int, FRAC> {
+                    // The proof guarantees that cove never produces this error, but it could still
+                    // be constructed by hand; the closest value is exact for valid values
+                    #[allow(clippy::let_unit_value)]
+                    let () = Self::_PROOF;
+                    self.closest()
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and Closest for $from -> Fixed<$int> via LossyCastError, where $from is a
+    // primitive float. The value is scaled by 2^FRAC in f64, which is exact short of overflowing
+    // to infinity, and the underlying integer is then casted from the scaled value.
+    (from $int:ty => float $($from:ty),+) => {
+        $(
+            impl<const FRAC: u32> CastImpl<Fixed<$int, FRAC>> for $from {
+                type Error = LossyCastError<Self, Fixed<$int, FRAC>>;
+
+                #[inline]
+                #[allow(clippy::cast_possible_wrap)]
+                fn cast_impl(self) -> Result<Fixed<$int, FRAC>, Self::Error> {
+                    let scaled = self.cast::<f64>().lossless() * power_of_two(FRAC as i32);
+
+                    match scaled.cast::<$int>() {
+                        Ok(bits) => Ok(Fixed::<$int, FRAC>::from_bits(bits)),
+                        Err(error) => Err(LossyCastError {
+                            from: self,
+                            to: Fixed::<$int, FRAC>::from_bits(error.to)
+                        })
+                    }
+                }
+            }
+
+            impl<const FRAC: u32> Closest<Fixed<$int, FRAC>>
+            for LossyCastError<$from, Fixed<$int, FRAC>> {
+                #[inline]
+                #[allow(clippy::cast_possible_wrap)]
+                fn closest(self) -> Fixed<$int, FRAC> {
+                    let scaled = self.from.cast::<f64>().lossless() * power_of_two(FRAC as i32);
+                    Fixed::<$int, FRAC>::from_bits(scaled.cast::<$int>().closest())
+                }
+            }
+        )*
+    };
+
+    // Implements Bitwise in both directions between Fixed<$int> and $int
+    (bitwise $int:ty) => {
+        impl<const FRAC: u32> Bitwise<$int>
+        for Result<$int, LossyCastError<Fixed<$int, FRAC>, $int>> {
+            #[inline]
+            fn bitwise(self) -> $int {
+                match self {
+                    // The cast was exact, so shifting the value back into place is too
+                    Ok(value) => value.checked_shl(FRAC).unwrap_or(0),
+                    Err(error) => error.from.to_bits()
+                }
+            }
+        }
+
+        impl<const FRAC: u32> Bitwise<Fixed<$int, FRAC>>
+        for Result<Fixed<$int, FRAC>, LossyCastError<$int, Fixed<$int, FRAC>>> {
+            #[inline]
+            fn bitwise(self) -> Fixed<$int, FRAC> {
+                match self {
+                    // The cast was exact, so shifting the value back out of place is too
+                    Ok(value) => {
+                        let bits = value.to_bits().checked_shr(FRAC).unwrap_or(0);
+                        Fixed::<$int, FRAC>::from_bits(bits)
+                    },
+                    Err(error) => Fixed::<$int, FRAC>::from_bits(error.from)
+                }
+            }
+        }
+    };
+
+    // Generates all implementations for each `$int`
+    ($($int:ty as $wide:ty),+) => {
+        $(
+            fixed!(
+                into $int as $wide =>
+                integer u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+            );
+
+            fixed!(into $int as $wide => float f32, f64);
+
+            fixed!(
+                from $int =>
+                integer u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+            );
+
+            fixed!(from $int => float f32, f64);
+            fixed!(bitwise $int);
+        )*
+    }
+}
+
+fixed!(
+    u8 as u128, u16 as u128, u32 as u128, u64 as u128, u128 as u128, usize as u128,
+    i8 as i128, i16 as i128, i32 as i128, i64 as i128, i128 as i128, isize as i128
+);
@@ -0,0 +1,346 @@
+//! This module provides implementations of the casting traits for cove's software floating point
+//! types
+
+use super::numeric::Numeric;
+use crate::base::{CastImpl, CastSplitImpl};
+use crate::casts::{
+    AssumedLossless, Cast, CastSplit, Closest, Rounded, RoundingMode, Stochastic, StochasticRng,
+    Wrapping
+};
+use crate::errors::{FailedCastError, LosslessCastError, LossyCastError};
+use crate::types::{bf16, f16, f8e4m3, f8e5m2};
+
+use core::num::{
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+};
+
+macro_rules! minifloat {
+    // Implements Cast, CastSplit, Closest, Rounded, Stochastic and Wrapping for $float -> $int via
+    // LossyCastError. Every value of $float is exactly representable by f32, so these are all
+    // implemented in terms of the f32 implementations.
+    (to_int $float:ty => $($int:ty),+) => {
+        $(
+            impl CastImpl<$int> for $float {
+                type Error = LossyCastError<Self, $int>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$int, Self::Error> {
+                    f32::from(self).cast::<$int>().map_err(|error| LossyCastError {
+                        from: self,
+                        to: error.to
+                    })
+                }
+            }
+
+            impl CastSplitImpl<$int> for $float {
+                type Error = LossyCastError<Self, $int>;
+
+                #[inline]
+                fn cast_split_impl(self) -> Result<($int, Self), Self::Error> {
+                    // The bits of the fractional part are a subset of those of the value, so it is
+                    // exactly representable by $float
+                    match f32::from(self).cast_split::<$int>() {
+                        Ok((integral, fraction)) => Ok((
+                            integral,
+                            fraction.cast::<$float>().assumed_lossless()
+                        )),
+                        Err(error) => Err(LossyCastError {from: self, to: error.to})
+                    }
+                }
+            }
+
+            impl Closest<$int> for LossyCastError<$float, $int> {
+                #[inline]
+                fn closest(self) -> $int {
+                    f32::from(self.from).cast::<$int>().closest()
+                }
+            }
+
+            impl Rounded<$int> for LossyCastError<$float, $int> {
+                #[inline]
+                fn rounded(self, mode: RoundingMode) -> $int {
+                    f32::from(self.from).cast::<$int>().rounded(mode)
+                }
+            }
+
+            impl Stochastic<$int> for LossyCastError<$float, $int> {
+                #[inline]
+                fn stochastic<R: StochasticRng + ?Sized>(self, rng: &mut R) -> $int {
+                    f32::from(self.from).cast::<$int>().stochastic(rng)
+                }
+            }
+
+            impl Wrapping<$int> for LossyCastError<$float, $int> {
+                #[inline]
+                fn wrapping(self) -> $int {
+                    f32::from(self.from).cast::<$int>().wrapping()
+                }
+            }
+        )*
+    };
+
+    // Implements Cast, Closest and Rounded for $float -> $nonzero via FailedCastError, in terms of
+    // the f32 implementations
+    (to_nonzero $float:ty => $($nonzero:ty),+) => {
+        $(
+            impl CastImpl<$nonzero> for $float {
+                type Error = FailedCastError<Self, $nonzero>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$nonzero, Self::Error> {
+                    f32::from(self)
+                        .cast::<$nonzero>()
+                        .map_err(|_error| FailedCastError::new(self))
+                }
+            }
+
+            impl Closest<$nonzero> for FailedCastError<$float, $nonzero> {
+                #[inline]
+                fn closest(self) -> $nonzero {
+                    f32::from(self.from).cast::<$nonzero>().closest()
+                }
+            }
+
+            impl Rounded<$nonzero> for FailedCastError<$float, $nonzero> {
+                #[inline]
+                fn rounded(self, mode: RoundingMode) -> $nonzero {
+                    f32::from(self.from).cast::<$nonzero>().rounded(mode)
+                }
+            }
+        )*
+    };
+
+    // Implements Cast for $float -> $to via LosslessCastError, where $to is a wider float
+    (to_float $float:ty => $($to:ty),+) => {
+        $(
+            impl CastImpl<$to> for $float {
+                type Error = LosslessCastError<Self, $to>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$to, Self::Error> {
+                    Ok(<$to>::from(self))
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and Closest for $from -> $float via LossyCastError, where $from is a
+    // primitive or another software float. The value is first rounded to odd at the precision of
+    // f64, which preserves the correct rounding to the much narrower $float; it is inexact only if
+    // $from is an integer too wide for f64, in which case the odd f64 is not representable by
+    // $float either.
+    (from_numeric $float:ty => $($from:ty),+) => {
+        $(
+            impl CastImpl<$float> for $from {
+                type Error = LossyCastError<Self, $float>;
+
+                #[inline]
+                #[allow(clippy::float_cmp)]
+                fn cast_impl(self) -> Result<$float, Self::Error> {
+                    let value = rounded_to_odd(self);
+                    let to = <$float>::round_from(value);
+
+                    // NaN is not equal to itself, but casting it between floats is lossless
+                    match f64::from(to) == value || value.is_nan() {
+                        true => Ok(to),
+                        false => Err(LossyCastError {from: self, to})
+                    }
+                }
+            }
+
+            impl Closest<$float> for LossyCastError<$from, $float> {
+                #[inline]
+                fn closest(self) -> $float {
+                    // Overflow yields infinity, which saturates to the finite extremes unless the
+                    // origin value was itself infinite
+                    match self.to.is_infinite() && self.from.parts()[0].is_finite() {
+                        true if self.to.is_sign_positive() => <$float>::MAX,
+                        true => <$float>::MIN,
+                        false => self.to
+                    }
+                }
+            }
+        )*
+    };
+
+    // Implements Cast for $from -> $float via LosslessCastError, where $from is a primitive whose
+    // values are all exactly representable by $float
+    (lossless_primitive $float:ty => $($from:ty),*) => {
+        $(
+            impl CastImpl<$float> for $from {
+                type Error = LosslessCastError<Self, $float>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$float, Self::Error> {
+                    Ok(<$float>::round_from(f64::from(self)))
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and Closest for $from -> $float via LossyCastError, where $from is a
+    // NonZero*, in terms of the underlying primitive implementations
+    (from_nonzero $float:ty => $($from:ty),+) => {
+        $(
+            impl CastImpl<$float> for $from {
+                type Error = LossyCastError<Self, $float>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$float, Self::Error> {
+                    self.get().cast::<$float>().map_err(|error| LossyCastError {
+                        from: self,
+                        to: error.to
+                    })
+                }
+            }
+
+            impl Closest<$float> for LossyCastError<$from, $float> {
+                #[inline]
+                fn closest(self) -> $float {
+                    self.from.get().cast::<$float>().closest()
+                }
+            }
+        )*
+    };
+
+    // Implements Cast for $from -> $float via LosslessCastError, where $from is a NonZero* whose
+    // values are all exactly representable by $float
+    (lossless_nonzero $float:ty => $($from:ty),*) => {
+        $(
+            impl CastImpl<$float> for $from {
+                type Error = LosslessCastError<Self, $float>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$float, Self::Error> {
+                    Ok(<$float>::round_from(f64::from(self.get())))
+                }
+            }
+        )*
+    };
+
+    // Implements Cast for $float -> $to via LosslessCastError, where $to is a wider software float
+    (to_minifloat $float:ty => $($to:ty),+) => {
+        $(
+            impl CastImpl<$to> for $float {
+                type Error = LosslessCastError<Self, $to>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$to, Self::Error> {
+                    Ok(<$to>::round_from(f64::from(self)))
+                }
+            }
+        )*
+    };
+
+    // Implements Cast for $float -> $float via LosslessCastError
+    (identity $float:ty) => {
+        impl CastImpl<$float> for $float {
+            type Error = LosslessCastError<Self, $float>;
+
+            #[inline]
+            fn cast_impl(self) -> Result<$float, Self::Error> {
+                Ok(self)
+            }
+        }
+    };
+
+    // Generates all implementations for `$float`, given the primitives and NonZero* which it
+    // represents exactly
+    ($float:ty; lossless {$($primitive:ty),*}; {$($nonzero:ty),*}) => {
+        impl Cast for $float {}
+        impl CastSplit for $float {}
+
+        minifloat!(identity $float);
+        minifloat!(to_float $float => f32, f64);
+        minifloat!(from_numeric $float => f32, f64);
+
+        minifloat!(to_int $float => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+        minifloat!(
+            to_nonzero $float =>
+            NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+            NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+        );
+
+        minifloat!(lossless_primitive $float => $($primitive),*);
+        minifloat!(lossless_nonzero $float => $($nonzero),*);
+    };
+
+    // Generates the lossy casts into `$float` from primitive integers and NonZero*
+    (lossy $float:ty; {$($primitive:ty),*}; {$($nonzero:ty),*}) => {
+        minifloat!(from_numeric $float => $($primitive),*);
+        minifloat!(from_nonzero $float => $($nonzero),*);
+    }
+}
+
+minifloat!(f16; lossless {u8, i8}; {NonZeroU8, NonZeroI8});
+
+minifloat!(
+    lossy f16;
+    {u16, u32, u64, u128, usize, i16, i32, i64, i128, isize};
+    {
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+        NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+    }
+);
+
+// -- Rounding -- //
+/// Rounds `value` to [`f64`], resolving any inexact result to whichever neighbor has an odd
+/// significand
+///
+/// Rounding to odd at 53 bits and then to nearest at a narrower precision yields the same result as
+/// rounding directly to the narrower precision, which avoids the double rounding error of rounding
+/// to nearest twice.
+#[inline]
+fn rounded_to_odd<T: Numeric>(value: T) -> f64 {
+    let [leading, middle, ..] = value.parts();
+
+    // The leading part is the nearest f64 and the middle part is zero only if it is exact; when
+    // inexact, the other neighbor lies in the direction of the middle part
+    match middle == 0.0 || leading.to_bits() & 1 == 1 {
+        true => leading,
+        false if (middle > 0.0) == (leading > 0.0) => f64::from_bits(leading.to_bits() + 1),
+        false => f64::from_bits(leading.to_bits() - 1)
+    }
+}
+
+minifloat!(bf16; lossless {u8, i8}; {NonZeroU8, NonZeroI8});
+
+minifloat!(
+    lossy bf16;
+    {u16, u32, u64, u128, usize, i16, i32, i64, i128, isize};
+    {
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+        NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+    }
+);
+
+minifloat!(from_numeric f16 => bf16);
+minifloat!(from_numeric bf16 => f16);
+
+minifloat!(f8e4m3; lossless {}; {});
+minifloat!(f8e5m2; lossless {}; {});
+
+minifloat!(
+    lossy f8e4m3;
+    {u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize};
+    {
+        NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+        NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+    }
+);
+
+minifloat!(
+    lossy f8e5m2;
+    {u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize};
+    {
+        NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+        NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+    }
+);
+
+minifloat!(from_numeric f8e4m3 => f16, bf16, f8e5m2);
+minifloat!(from_numeric f8e5m2 => f16, bf16, f8e4m3);
+minifloat!(to_minifloat f8e4m3 => f16, bf16);
+minifloat!(to_minifloat f8e5m2 => f16, bf16);
@@ -1,6 +1,24 @@
 //! Parent module for trait implementations provided directly by this crate
 
+mod bits;
 mod blanket;
+mod boolean;
+mod character;
+mod complex;
+mod decimal;
+mod duration;
+mod fixed;
 mod nonzero;
+mod option;
+pub(crate) mod numeric;
 mod primitives;
-mod bitwise;
\ No newline at end of file
+mod quad;
+mod ranged;
+mod ratio;
+mod refined;
+mod bitwise;
+mod minifloat;
+mod wide;
+mod within;
+mod wrapping;
+mod wrappers;
\ No newline at end of file
@@ -2,7 +2,7 @@
 
 #![allow(clippy::wildcard_imports)]
 
-use crate::casts::{Cast, Closest, Lossless};
+use crate::casts::{Cast, Closest, Lossless, PortableLossless, Rounded, RoundingMode};
 use crate::errors::{FailedCastError, LosslessCastError, LossyCastError};
 use crate::base::CastImpl;
 
@@ -89,6 +89,36 @@ macro_rules! cast {
         $(cast!(from_nonzero $from => $nonzero; $primitive);)*
     };
 
+    // Implements Cast and PortableLossless for casts which are lossless on every platform
+    (portable lossless $from:ty => $($nonzero:ty),*; $($primitive:ty),*) => {
+        cast!(lossless $from => $($nonzero),*; $($primitive),*);
+        cast!(portable $from => $($nonzero),*; $($primitive),*);
+    };
+
+    // Implements PortableLossless for casts whose Cast is implemented elsewhere; these must be
+    // lossless under every `platform_dependent` table
+    (portable $from:ty => $($nonzero:ty),*; $($primitive:ty),*) => {
+        $(
+            impl PortableLossless<$nonzero> for LosslessCastError<$from, $nonzero> {
+                #[inline]
+                fn portable_lossless(self) -> $nonzero {
+                    // This is safe because LosslessCastError cannot be instantiated
+                    unsafe {core::hint::unreachable_unchecked()}
+                }
+            }
+        )*
+
+        $(
+            impl PortableLossless<$primitive> for LosslessCastError<$from, $primitive> {
+                #[inline]
+                fn portable_lossless(self) -> $primitive {
+                    // This is safe because LosslessCastError cannot be instantiated
+                    unsafe {core::hint::unreachable_unchecked()}
+                }
+            }
+        )*
+    };
+
     // $from must be NonZero* because there is no Lossless available from primitive -> NonZero*
     (lossless $from:ty => $($nonzero:ty),*; $($primitive:ty),*) => {
         $(
@@ -153,11 +183,36 @@ macro_rules! cast {
         $(cast!(from_primitive $primitive => $nonzero);)*
     };
 
-    // Implements Cast and Closest for each pair via FailedCastError:
+    // Implements Cast, Closest and Rounded for each pair via FailedCastError:
+    // `$float` -> `$unsigned_nonzero` where `$float` is a floating point primitive and 
+    // `$unsigned_nonzero` is a NonZeroU* (that is, an unsigned NonZero* type)
+    // Rounded is implemented in terms of the underlying primitive implementation of Rounded, but
+    // mapping 0 -> 1.
+    (from_float_to_unsigned $float:ty => ($($unsigned_nonzero:ty),+)) => {
+        cast!(from_primitive $float => ($($unsigned_nonzero),+));
+
+        $(
+            impl Rounded<$unsigned_nonzero> for FailedCastError<$float, $unsigned_nonzero> {
+                #[inline]
+                fn rounded(self, mode: RoundingMode) -> $unsigned_nonzero {
+                    // Create the NonZero from the rounded primitive, using a value of 1 if 0
+                    <$unsigned_nonzero>::new(self.from.cast().rounded(mode))
+                        .unwrap_or_else(|| unsafe {<$unsigned_nonzero>::new_unchecked(1)})
+                }
+            }
+        )*
+    };
+
+    (from_float_to_unsigned $first:ty, $($float:ty),+ => $unsigned_nonzero:tt) => {
+        cast!(from_float_to_unsigned $first => $unsigned_nonzero);
+        $(cast!(from_float_to_unsigned $float => $unsigned_nonzero);)*
+    };
+
+    // Implements Cast, Closest and Rounded for each pair via FailedCastError:
     // `$float` -> `$signed_nonzero` where `$float` is a float point primitive and `$signed_nonzero`
     // is a NonZeroI* (that is, a signed NonZero* type)
-    // Closest is implemented in terms of the underlying primitive implementation of Closest, but
-    // mapping +0 -> 1 and -0 to -1.
+    // Closest and Rounded are implemented in terms of the underlying primitive implementations, 
+    // but mapping +0 -> 1 and -0 to -1.
     (from_float_to_signed $float:ty => ($($signed_nonzero:ty),+)) => {
         $(
             impl CastImpl<$signed_nonzero> for $float {
@@ -185,6 +240,21 @@ macro_rules! cast {
                         )})
                 }
             }
+
+            impl Rounded<$signed_nonzero> for FailedCastError<$float, $signed_nonzero> {
+                #[inline]
+                fn rounded(self, mode: RoundingMode) -> $signed_nonzero {
+                    // Create the NonZero from the rounded primitive
+                    <$signed_nonzero>::new(self.from.cast().rounded(mode))
+                        .unwrap_or_else(|| unsafe {<$signed_nonzero>::new_unchecked(
+                            // Use a value of 1 if positive or -1 otherwise
+                            match self.from.is_sign_positive() {
+                                true => 1,
+                                false => -1
+                            }
+                        )})
+                }
+            }
         )*
     };
 
@@ -201,7 +271,7 @@ cast!(
 );
 
 cast!(
-    lossless NonZeroU8 =>
+    portable lossless NonZeroU8 =>
     NonZeroU8,  NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128,
     NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128;
     u8, u16, u32, u64, u128,
@@ -212,7 +282,7 @@ cast!(
 cast!(from_nonzero NonZeroU8 => NonZeroI8; i8);
 
 cast!(
-    lossless NonZeroU16 =>
+    portable lossless NonZeroU16 =>
     NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroI32, NonZeroI64, NonZeroI128;
     u16, u32, u64, u128,
     i32, i64, i128,
@@ -222,7 +292,7 @@ cast!(
 cast!(from_nonzero NonZeroU16 => NonZeroU8, NonZeroI8, NonZeroI16; u8, i8, i16);
 
 cast!(
-    lossless NonZeroU32 =>
+    portable lossless NonZeroU32 =>
     NonZeroU32, NonZeroU64, NonZeroU128, NonZeroI64, NonZeroI128;
     u32, u64, u128, i64, i128, f64
 );
@@ -233,7 +303,7 @@ cast!(
     u8, u16, i8, i16, i32, f32
 );
 
-cast!(lossless NonZeroU64 => NonZeroU64, NonZeroU128, NonZeroI128; u64, u128, i128);
+cast!(portable lossless NonZeroU64 => NonZeroU64, NonZeroU128, NonZeroI128; u64, u128, i128);
 
 cast!(
     from_nonzero NonZeroU64 =>
@@ -241,7 +311,7 @@ cast!(
     u8, u16, u32, i8, i16, i32, i64, f32, f64
 );
 
-cast!(lossless NonZeroU128 => NonZeroU128; u128);
+cast!(portable lossless NonZeroU128 => NonZeroU128; u128);
 
 cast!(
     from_nonzero NonZeroU128 =>
@@ -251,7 +321,7 @@ cast!(
 );
 
 cast!(
-    lossless NonZeroI8 =>
+    portable lossless NonZeroI8 =>
     NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128;
     i8, i16, i32, i64, i128, f32, f64
 );
@@ -263,7 +333,7 @@ cast!(
 );
 
 cast!(
-    lossless NonZeroI16 =>
+    portable lossless NonZeroI16 =>
     NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128;
     i16, i32, i64, i128, f32, f64
 );
@@ -275,7 +345,7 @@ cast!(
     u8, u16, u32, u64, u128, i8
 );
 
-cast!(lossless NonZeroI32 => NonZeroI32, NonZeroI64, NonZeroI128; i32, i64, i128, f64);
+cast!(portable lossless NonZeroI32 => NonZeroI32, NonZeroI64, NonZeroI128; i32, i64, i128, f64);
 
 cast!(
     from_nonzero NonZeroI32 =>
@@ -284,7 +354,7 @@ cast!(
     u8, u16, u32, u64, u128, i8, i16, f32
 );
 
-cast!(lossless NonZeroI64 => NonZeroI64, NonZeroI128; i64, i128);
+cast!(portable lossless NonZeroI64 => NonZeroI64, NonZeroI128; i64, i128);
 
 cast!(
     from_nonzero NonZeroI64 =>
@@ -293,7 +363,7 @@ cast!(
     u8, u16, u32, u64, u128, i8, i16, i32, f32, f64
 );
 
-cast!(lossless NonZeroI128 => NonZeroI128; i128);
+cast!(portable lossless NonZeroI128 => NonZeroI128; i128);
 
 cast!(
     from_nonzero NonZeroI128 =>
@@ -309,7 +379,7 @@ cast!(
     NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
 ));
 
-cast!(from_primitive f32, f64 => (
+cast!(from_float_to_unsigned f32, f64 => (
     NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize
 ));
 
@@ -317,12 +387,21 @@ cast!(from_float_to_signed f32, f64 => (
     NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
 ));
 
-cast!(lossless NonZeroUsize => NonZeroUsize; usize);
+cast!(portable lossless NonZeroUsize => NonZeroUsize; usize);
 cast!(from_nonzero NonZeroUsize => NonZeroIsize; isize);
 
-cast!(lossless NonZeroIsize => NonZeroIsize; isize);
+cast!(portable lossless NonZeroIsize => NonZeroIsize; isize);
 cast!(from_nonzero NonZeroIsize => NonZeroUsize; usize);
 
+// Casts involving NonZeroUsize/NonZeroIsize which are lossless under every `platform_dependent`
+// table below
+cast!(portable NonZeroUsize => NonZeroU128; u128);
+cast!(portable NonZeroIsize => NonZeroI128; i128);
+cast!(portable NonZeroU8 => NonZeroUsize, NonZeroIsize; usize, isize);
+cast!(portable NonZeroU16 => NonZeroUsize; usize);
+cast!(portable NonZeroI8 => NonZeroIsize; isize);
+cast!(portable NonZeroI16 => NonZeroIsize; isize);
+
 // -- Macro-Generated Bulk Implementations: Non-Portable -- //
 #[cfg(target_pointer_width = "16")]
 mod platform_dependent {
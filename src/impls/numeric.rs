@@ -0,0 +1,674 @@
+//! This module provides a helper trait describing cove's numerical types, which is used to measure
+//! and classify the loss of casts between them
+
+use super::duration;
+use super::quad;
+use super::ratio;
+use super::fixed::{absolute, power_of_two};
+use super::wrapping::f64_modulo;
+use super::wide;
+use crate::casts::{Cast, Lossless};
+use crate::errors::{Loss, LossKind};
+use crate::types::{
+    bf16, f16, f128, f8e4m3, f8e5m2, i256, u256, Bits, Decimal, Finite, Fixed, Int, Micros, Millis,
+    Nanos, NonNan, NonNegative, Ranged, Ratio, Secs, UInt, UnitInterval, Wide, Width
+};
+
+use core::num::{
+    self, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+};
+
+use core::time::Duration;
+
+/// The number of parts into which [`Numeric::parts`] splits a value, which suffices for integers of
+/// up to 256 bits since each part after the first accounts for at least 53 bits
+pub(crate) const PARTS: usize = 5;
+
+// -- Numeric -- //
+/// Helper trait describing a numerical type for the purposes of measuring and classifying loss
+///
+/// This is public only so that it may appear in the bounds of public implementations; it is not
+/// reachable from outside of the crate.
+pub trait Numeric: Copy {
+    /// The smallest value of the type
+    const MIN: Self;
+
+    /// The largest finite value of the type
+    const MAX: Self;
+
+    /// Whether the type represents only integers
+    const INTEGRAL: bool;
+
+    /// Whether the type excludes zero from within its range, as for the `NonZero*` family
+    const NONZERO: bool;
+
+    /// Splits the value into non-overlapping [`f64`] parts which sum to exactly the value, in order
+    /// of decreasing magnitude; the leading part is the nearest [`f64`] to the value
+    fn parts(self) -> [f64; PARTS];
+
+    /// Returns the unit in the last place of this value within its own type
+    fn ulp(self) -> f64;
+
+    /// Returns whether the value with the given parts lies within the range of the type but is not
+    /// a value of it, as for the surrogate code points excluded from [`char`]
+    #[inline]
+    fn excludes(_parts: [f64; PARTS]) -> bool {
+        false
+    }
+}
+
+macro_rules! numeric {
+    // Integers are exactly the sum of their nearest f64 and the integer remainder, which is split
+    // in turn; the remainder is computed modulo 2^128 so that a nearest f64 just beyond the type's
+    // range is handled. At most 128 - 53 bits remain after the first split, and at most 22 bits
+    // after the second, so the final part is exact.
+    (integer $($int:ty),+) => {
+        $(
+            impl Numeric for $int {
+                const MIN: Self = <$int>::MIN;
+                const MAX: Self = <$int>::MAX;
+                const INTEGRAL: bool = true;
+                const NONZERO: bool = false;
+
+                #[inline]
+                #[allow(clippy::cast_precision_loss, clippy::cast_possible_wrap)]
+                #[allow(clippy::cast_sign_loss, clippy::cast_lossless)]
+                fn parts(self) -> [f64; PARTS] {
+                    let leading = self as f64;
+                    let remainder = (self as u128).wrapping_sub(f64_modulo(leading)) as i128;
+                    let middle = remainder as f64;
+                    let trailing = remainder - middle as i128;
+                    [leading, middle, trailing as f64, 0.0, 0.0]
+                }
+
+                #[inline]
+                fn ulp(self) -> f64 {
+                    1.0
+                }
+            }
+        )*
+    };
+
+    // NonZero* values are measured as their underlying primitive
+    (nonzero $($nonzero:ty: $int:ty),+) => {
+        $(
+            impl Numeric for $nonzero {
+                // These are safe because the primitive bounds are nonzero
+                const MIN: Self = unsafe {<$nonzero>::new_unchecked(match <$int>::MIN {
+                    0 => 1,
+                    min => min
+                })};
+
+                const MAX: Self = unsafe {<$nonzero>::new_unchecked(<$int>::MAX)};
+                const INTEGRAL: bool = true;
+                const NONZERO: bool = true;
+
+                #[inline]
+                fn parts(self) -> [f64; PARTS] {
+                    self.get().parts()
+                }
+
+                #[inline]
+                fn ulp(self) -> f64 {
+                    1.0
+                }
+            }
+        )*
+    };
+
+    // Software floats are measured as the f64 of the same value, which is always exact
+    (minifloat $($float:ty),+) => {
+        $(
+            impl Numeric for $float {
+                const MIN: Self = <$float>::MIN;
+                const MAX: Self = <$float>::MAX;
+                const INTEGRAL: bool = false;
+                const NONZERO: bool = false;
+
+                #[inline]
+                fn parts(self) -> [f64; PARTS] {
+                    [f64::from(self), 0.0, 0.0, 0.0, 0.0]
+                }
+
+                #[inline]
+                fn ulp(self) -> f64 {
+                    Self::FORMAT.ulp(self.to_bits().into())
+                }
+            }
+        )*
+    };
+
+    // Quadruple precision floats are split into the nearest f64 and the remainders; see
+    // quad::parts
+    (quad $($float:ty),+) => {
+        $(
+            impl Numeric for $float {
+                const MIN: Self = <$float>::MIN;
+                const MAX: Self = <$float>::MAX;
+                const INTEGRAL: bool = false;
+                const NONZERO: bool = false;
+
+                #[inline]
+                fn parts(self) -> [f64; PARTS] {
+                    quad::parts(self)
+                }
+
+                #[inline]
+                fn ulp(self) -> f64 {
+                    quad::ulp(self)
+                }
+            }
+        )*
+    };
+
+    // 256-bit integers are split as their two's complement words; see wide::parts
+    (wide $($int:ty: $negative:expr),+) => {
+        $(
+            impl Numeric for $int {
+                const MIN: Self = <$int>::MIN;
+                const MAX: Self = <$int>::MAX;
+                const INTEGRAL: bool = true;
+                const NONZERO: bool = false;
+
+                #[inline]
+                #[allow(clippy::redundant_closure_call)]
+                fn parts(self) -> [f64; PARTS] {
+                    wide::parts(self.to_words(), ($negative)(self))
+                }
+
+                #[inline]
+                fn ulp(self) -> f64 {
+                    1.0
+                }
+            }
+        )*
+    };
+
+    // Values of arbitrary bit width are measured as the 128-bit primitive of the same value
+    (bits $($bit:ident),+) => {
+        $(
+            impl<const BITS: u32> Numeric for $bit<BITS> where Bits<BITS>: Width {
+                const MIN: Self = <$bit<BITS>>::MIN;
+                const MAX: Self = <$bit<BITS>>::MAX;
+                const INTEGRAL: bool = true;
+                const NONZERO: bool = false;
+
+                #[inline]
+                fn parts(self) -> [f64; PARTS] {
+                    self.widen().parts()
+                }
+
+                #[inline]
+                fn ulp(self) -> f64 {
+                    1.0
+                }
+            }
+        )*
+    };
+
+    // Fixed-point values are measured as their underlying integer scaled by 2^-FRAC, which is exact
+    // for each part since the parts of an integer are integers
+    (fixed $($int:ty),+) => {
+        $(
+            impl<const FRAC: u32> Numeric for Fixed<$int, FRAC> {
+                const MIN: Self = Fixed::<$int, FRAC>::MIN;
+                const MAX: Self = Fixed::<$int, FRAC>::MAX;
+                const INTEGRAL: bool = FRAC == 0;
+                const NONZERO: bool = false;
+
+                #[inline]
+                #[allow(clippy::cast_possible_wrap)]
+                fn parts(self) -> [f64; PARTS] {
+                    let scale = power_of_two(-(FRAC as i32));
+                    self.to_bits().parts().map(|part| part * scale)
+                }
+
+                #[inline]
+                #[allow(clippy::cast_possible_wrap)]
+                fn ulp(self) -> f64 {
+                    power_of_two(-(FRAC as i32))
+                }
+            }
+        )*
+    };
+
+    // The core::num wrappers are measured as the value they wrap
+    (wrappers $($wrapper:ident),+) => {
+        $(
+            impl<T: Numeric> Numeric for num::$wrapper<T> {
+                const MIN: Self = num::$wrapper(T::MIN);
+                const MAX: Self = num::$wrapper(T::MAX);
+                const INTEGRAL: bool = T::INTEGRAL;
+                const NONZERO: bool = T::NONZERO;
+
+                #[inline]
+                fn parts(self) -> [f64; PARTS] {
+                    self.0.parts()
+                }
+
+                #[inline]
+                fn ulp(self) -> f64 {
+                    self.0.ulp()
+                }
+            }
+        )*
+    };
+
+    // Time units are measured in nanoseconds, scaling integers exactly in 256 bits and floats as
+    // the rounded product along with its error
+    (unit $unit:ident => integer $($int:ty),+) => {
+        $(
+            impl Numeric for $unit<$int> {
+                const MIN: Self = $unit(<$int>::MIN);
+                const MAX: Self = $unit(<$int>::MAX);
+                const INTEGRAL: bool = true;
+                const NONZERO: bool = false;
+
+                #[inline]
+                fn parts(self) -> [f64; PARTS] {
+                    duration::integer_parts(self.0.cast::<i256>().lossless(), Self::NANOS)
+                }
+
+                #[inline]
+                #[allow(clippy::cast_precision_loss)]
+                fn ulp(self) -> f64 {
+                    Self::NANOS as f64
+                }
+            }
+        )*
+    };
+
+    (unit $unit:ident => float $($float:ty),+) => {
+        $(
+            impl Numeric for $unit<$float> {
+                const MIN: Self = $unit(<$float>::MIN);
+                const MAX: Self = $unit(<$float>::MAX);
+                const INTEGRAL: bool = false;
+                const NONZERO: bool = false;
+
+                #[inline]
+                fn parts(self) -> [f64; PARTS] {
+                    duration::float_parts(self.0.into(), Self::NANOS)
+                }
+
+                #[inline]
+                #[allow(clippy::cast_precision_loss)]
+                fn ulp(self) -> f64 {
+                    self.0.ulp() * Self::NANOS as f64
+                }
+            }
+        )*
+    };
+
+    // Units are measured as the time units of every primitive
+    (units $($unit:ident),+) => {
+        $(
+            numeric!(
+                unit $unit =>
+                integer u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+            );
+
+            numeric!(unit $unit => float f32, f64);
+        )*
+    };
+
+    // Ranged values are measured as their underlying primitive
+    (ranged $($int:ty),+) => {
+        $(
+            impl<const MIN: i128, const MAX: i128> Numeric for Ranged<$int, MIN, MAX> {
+                const MIN: Self = Ranged::<$int, MIN, MAX>::MIN;
+                const MAX: Self = Ranged::<$int, MIN, MAX>::MAX;
+                const INTEGRAL: bool = true;
+                const NONZERO: bool = false;
+
+                #[inline]
+                fn parts(self) -> [f64; PARTS] {
+                    self.get().parts()
+                }
+
+                #[inline]
+                fn ulp(self) -> f64 {
+                    1.0
+                }
+            }
+        )*
+    };
+
+    // Ratios are measured as their quotient, which is exact for dyadic ratios with at most 128
+    // fractional bits and truncated to those bits otherwise
+    (ratio $($int:ty),+) => {
+        $(
+            impl Numeric for Ratio<$int> {
+                const MIN: Self = Ratio::<$int>::MIN;
+                const MAX: Self = Ratio::<$int>::MAX;
+                const INTEGRAL: bool = false;
+                const NONZERO: bool = false;
+
+                #[inline]
+                fn parts(self) -> [f64; PARTS] {
+                    let (negative, numer, denom) = self.to_sign_magnitude();
+                    ratio::parts(negative, numer, denom)
+                }
+
+                #[inline]
+                #[allow(clippy::cast_precision_loss)]
+                fn ulp(self) -> f64 {
+                    // The spacing of ratios varies with their denominators, so this is the
+                    // smallest positive value
+                    1.0 / Ratio::<$int>::DENOM_BOUND as f64
+                }
+            }
+        )*
+    };
+
+    // Decimal values are measured as the ratio of their underlying integer to 10^SCALE
+    (decimal $($int:ty),+) => {
+        $(
+            impl<const SCALE: u32> Numeric for Decimal<$int, SCALE> {
+                const MIN: Self = Decimal::<$int, SCALE>::MIN;
+                const MAX: Self = Decimal::<$int, SCALE>::MAX;
+                const INTEGRAL: bool = SCALE == 0;
+                const NONZERO: bool = false;
+
+                #[inline]
+                fn parts(self) -> [f64; PARTS] {
+                    let (wide, power) = (self.widen(), Decimal::<$int, SCALE>::POWER);
+                    ratio::parts(wide.is_negative(), wide.magnitude(), power)
+                }
+
+                #[inline]
+                #[allow(clippy::cast_precision_loss)]
+                fn ulp(self) -> f64 {
+                    1.0 / Decimal::<$int, SCALE>::POWER as f64
+                }
+            }
+        )*
+    };
+
+    // Refined floats are measured as their underlying primitive, within the finite part of their
+    // range; NaN and the infinities are classified before the range is consulted
+    (refined $refined:ident => $($float:ty),+) => {
+        $(
+            impl Numeric for $refined<$float> {
+                const MIN: Self = match $refined::<$float>::MIN.get() < <$float>::MIN {
+                    // This is safe because the MIN of the primitive then lies within the range
+                    true => unsafe {$refined::<$float>::new_unchecked(<$float>::MIN)},
+                    false => $refined::<$float>::MIN
+                };
+
+                const MAX: Self = match $refined::<$float>::MAX.get() > <$float>::MAX {
+                    // This is safe because the MAX of the primitive then lies within the range
+                    true => unsafe {$refined::<$float>::new_unchecked(<$float>::MAX)},
+                    false => $refined::<$float>::MAX
+                };
+
+                const INTEGRAL: bool = false;
+                const NONZERO: bool = false;
+
+                #[inline]
+                fn parts(self) -> [f64; PARTS] {
+                    self.get().parts()
+                }
+
+                #[inline]
+                fn ulp(self) -> f64 {
+                    self.get().ulp()
+                }
+            }
+        )*
+    };
+
+    (refined $($refined:ident),+) => {
+        $(
+            numeric!(refined $refined => f32, f64);
+        )*
+    };
+
+    // Option<NonZero*> values are measured as their underlying primitive, with None as zero
+    (option $($nonzero:ty: $int:ty),+) => {
+        $(
+            impl Numeric for Option<$nonzero> {
+                const MIN: Self = <$nonzero>::new(<$int>::MIN);
+                const MAX: Self = <$nonzero>::new(<$int>::MAX);
+                const INTEGRAL: bool = true;
+                const NONZERO: bool = false;
+
+                #[inline]
+                fn parts(self) -> [f64; PARTS] {
+                    self.map_or(0, <$nonzero>::get).parts()
+                }
+
+                #[inline]
+                fn ulp(self) -> f64 {
+                    1.0
+                }
+            }
+        )*
+    }
+}
+
+numeric!(integer u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+numeric!(minifloat f16, bf16, f8e4m3, f8e5m2);
+numeric!(quad f128);
+numeric!(wide u256: |_value| false, i256: i256::is_negative);
+numeric!(bits UInt, Int);
+numeric!(fixed u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+numeric!(wrappers Wrapping, Saturating);
+numeric!(units Secs, Millis, Micros, Nanos);
+numeric!(ranged u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+numeric!(decimal u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+numeric!(ratio u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+numeric!(refined Finite, NonNan, NonNegative, UnitInterval);
+
+numeric!(
+    nonzero
+    NonZeroU8: u8, NonZeroU16: u16, NonZeroU32: u32, NonZeroU64: u64, NonZeroU128: u128,
+    NonZeroUsize: usize, NonZeroI8: i8, NonZeroI16: i16, NonZeroI32: i32, NonZeroI64: i64,
+    NonZeroI128: i128, NonZeroIsize: isize
+);
+
+numeric!(
+    option
+    NonZeroU8: u8, NonZeroU16: u16, NonZeroU32: u32, NonZeroU64: u64, NonZeroU128: u128,
+    NonZeroUsize: usize, NonZeroI8: i8, NonZeroI16: i16, NonZeroI32: i32, NonZeroI64: i64,
+    NonZeroI128: i128, NonZeroIsize: isize
+);
+
+impl Numeric for bool {
+    const MIN: Self = false;
+    const MAX: Self = true;
+    const INTEGRAL: bool = true;
+    const NONZERO: bool = false;
+
+    #[inline]
+    fn parts(self) -> [f64; PARTS] {
+        [f64::from(u8::from(self)), 0.0, 0.0, 0.0, 0.0]
+    }
+
+    #[inline]
+    fn ulp(self) -> f64 {
+        1.0
+    }
+}
+
+impl Numeric for char {
+    const MIN: Self = '\0';
+    const MAX: Self = char::MAX;
+    const INTEGRAL: bool = true;
+    const NONZERO: bool = false;
+
+    #[inline]
+    fn parts(self) -> [f64; PARTS] {
+        [f64::from(u32::from(self)), 0.0, 0.0, 0.0, 0.0]
+    }
+
+    #[inline]
+    fn ulp(self) -> f64 {
+        1.0
+    }
+
+    #[inline]
+    fn excludes(parts: [f64; PARTS]) -> bool {
+        // The surrogate code points are the only gap in the range of char
+        (f64::from(0xd800) ..= f64::from(0xdfff)).contains(&parts[0])
+    }
+}
+
+// Durations are measured in nanoseconds
+impl Numeric for Duration {
+    const MIN: Self = Duration::ZERO;
+    const MAX: Self = Duration::MAX;
+    const INTEGRAL: bool = true;
+    const NONZERO: bool = false;
+
+    #[inline]
+    fn parts(self) -> [f64; PARTS] {
+        self.as_nanos().parts()
+    }
+
+    #[inline]
+    fn ulp(self) -> f64 {
+        1.0
+    }
+}
+
+impl Numeric for f32 {
+    const MIN: Self = f32::MIN;
+    const MAX: Self = f32::MAX;
+    const INTEGRAL: bool = false;
+    const NONZERO: bool = false;
+
+    #[inline]
+    fn parts(self) -> [f64; PARTS] {
+        [f64::from(self), 0.0, 0.0, 0.0, 0.0]
+    }
+
+    #[inline]
+    fn ulp(self) -> f64 {
+        // The ulp is 2^(exponent - 150) for normal values, and the same as for the smallest normal
+        // exponent for subnormal values; this is always a normal f64
+        let exponent = u64::from((self.to_bits() >> 23) & 0xff).max(1);
+        f64::from_bits((exponent + 873) << 52)
+    }
+}
+
+impl Numeric for f64 {
+    const MIN: Self = f64::MIN;
+    const MAX: Self = f64::MAX;
+    const INTEGRAL: bool = false;
+    const NONZERO: bool = false;
+
+    #[inline]
+    fn parts(self) -> [f64; PARTS] {
+        [self, 0.0, 0.0, 0.0, 0.0]
+    }
+
+    #[inline]
+    fn ulp(self) -> f64 {
+        // The ulp is 2^(exponent - 1075) for normal values, and the same as for the smallest normal
+        // exponent for subnormal values; this is itself subnormal for small exponents
+        let exponent = ((self.to_bits() >> 52) & 0x7ff).max(1);
+        match exponent > 52 {
+            true => f64::from_bits((exponent - 52) << 52),
+            false => f64::from_bits(1 << (exponent - 1))
+        }
+    }
+}
+
+// -- Classification -- //
+/// Classifies why `from` could not be casted to `CastTo` without loss
+#[inline]
+pub(crate) fn classify<CastFrom: Numeric, CastTo: Numeric>(from: CastFrom) -> LossKind {
+    // Parts compare lexicographically, since each is the nearest f64 to the remaining value
+    let value = from.parts();
+
+    match value[0] {
+        leading if leading.is_nan() => LossKind::NaN,
+        leading if leading.is_infinite() => LossKind::Infinity,
+        leading if CastTo::NONZERO && leading == 0.0 => LossKind::Zero,
+        _ if value > CastTo::MAX.parts() => LossKind::Overflow,
+        _ if value < CastTo::MIN.parts() => LossKind::Underflow,
+        _ if CastTo::excludes(value) => LossKind::Invalid,
+        _ if CastTo::INTEGRAL => LossKind::Fraction,
+        _ => LossKind::Precision
+    }
+}
+
+// -- Difference -- //
+/// Computes the signed difference `from - to` exactly and without overflow
+///
+/// The parts of both values are accumulated into an expansion of non-overlapping components via
+/// error-free transformations, which sum to exactly the difference.
+#[inline]
+pub(crate) fn difference<CastFrom: Numeric, CastTo: Numeric>(from: CastFrom, to: CastTo) -> Loss {
+    let (from, to) = (from.parts(), to.parts());
+
+    // Infinities and NaN propagate through the leading parts, but would poison the error terms
+    let leading = from[0] - to[0];
+    if !leading.is_finite() {
+        return Loss::new(&[leading]);
+    }
+
+    let mut expansion = [0.0; 2 * PARTS];
+    for (component, term) in expansion.iter_mut().zip(from.iter().chain(&to.map(|part| -part))) {
+        *component = *term;
+    }
+
+    grow(&mut expansion);
+    Loss::new(&expansion)
+}
+
+/// Replaces finite `terms` in place with an expansion of non-overlapping components in order of
+/// increasing magnitude, interspersed with zeros, which sums to exactly the same value
+/// (Shewchuk's Grow-Expansion, applied one term at a time)
+#[inline]
+pub(crate) fn grow(terms: &mut [f64]) {
+    for index in 0 .. terms.len() {
+        let (expansion, rest) = terms.split_at_mut(index);
+        let mut sum = rest[0];
+
+        for component in expansion {
+            let (high, low) = two_sum(sum, *component);
+            *component = low;
+            sum = high;
+        }
+
+        rest[0] = sum;
+    }
+}
+
+/// Returns the rounded sum of `a` and `b` along with the exact rounding error (Knuth's TwoSum)
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    (sum, (a - a_virtual) + (b - b_virtual))
+}
+
+/// Returns the rounded product of `a` and `b` along with the exact rounding error (Dekker's
+/// TwoProduct), provided that the product neither overflows nor underflows
+#[inline]
+pub(super) fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+    let (a_high, a_low) = split(a);
+    let (b_high, b_low) = split(b);
+    let error = ((a_high * b_high - product) + a_high * b_low + a_low * b_high) + a_low * b_low;
+    (product, error)
+}
+
+/// Splits `value` into two halves of at most 26 significant bits each, whose products are exact
+/// (Veltkamp's splitting)
+#[inline]
+fn split(value: f64) -> (f64, f64) {
+    // Values this large would overflow when scaled, so they are split at a smaller exponent instead
+    if value.is_finite() && absolute(value) > power_of_two(996) {
+        let (high, low) = split(value * power_of_two(-28));
+        return (high * power_of_two(28), low * power_of_two(28));
+    }
+
+    let scaled = 134_217_729.0 * value;
+    let high = scaled - (scaled - value);
+    (high, value - high)
+}
@@ -0,0 +1,274 @@
+//! This module provides implementations of the casting traits for `Option<NonZero*>`, which casts
+//! as its underlying primitive with `None` as zero
+
+use super::wrappers::Rewrap;
+use crate::base::CastImpl;
+use crate::casts::{AssumedLossless, Bitwise, Cast, Closest, PortableLossless};
+use crate::errors::{FailedCastError, LosslessCastError, LossyCastError};
+
+use core::fmt::Debug;
+use core::num::{
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+};
+
+// -- Plain -- //
+/// Helper trait for the types which cast into `Option<NonZero*>` via their cast into the underlying
+/// primitive; the options themselves are excluded, as their casts are implemented separately
+///
+/// This is public only so that it may appear in the bounds of public implementations; it is not
+/// reachable from outside of the crate.
+pub trait Plain {}
+
+macro_rules! plain {
+    ($($plain:ty),+) => {
+        $(
+            impl Plain for $plain {}
+        )*
+    };
+}
+
+plain!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64,
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+);
+
+// -- Casts -- //
+macro_rules! option {
+    // Implements Cast, Closest and PortableLossless for T -> Option<$nonzero> in terms of T ->
+    // $int, where T is Plain. Zero yields None, so the cast is lossless whenever T -> $int is.
+    (into $nonzero:ty: $int:ty) => {
+        impl Cast for Option<$nonzero> {}
+
+        impl<T: Plain + Copy + CastImpl<$int>> CastImpl<Option<$nonzero>> for T
+        where T::Error: Rewrap<T, $int, Option<$nonzero>> {
+            type Error = <T::Error as Rewrap<T, $int, Option<$nonzero>>>::Error;
+
+            #[inline]
+            fn cast_impl(self) -> Result<Option<$nonzero>, Self::Error> {
+                match CastImpl::<$int>::cast_impl(self) {
+                    Ok(value) => Ok(<$nonzero>::new(value)),
+                    Err(error) => Err(error.rewrap(self, <$nonzero>::new))
+                }
+            }
+        }
+
+        impl<T: Plain> Closest<Option<$nonzero>> for LossyCastError<T, Option<$nonzero>>
+        where LossyCastError<T, $int>: Closest<$int> {
+            #[inline]
+            fn closest(self) -> Option<$nonzero> {
+                let to = self.to.map_or(0, <$nonzero>::get);
+                <$nonzero>::new(LossyCastError {from: self.from, to}.closest())
+            }
+        }
+
+        impl<T: Plain + Debug> PortableLossless<Option<$nonzero>>
+        for LosslessCastError<T, Option<$nonzero>>
+        where LosslessCastError<T, $int>: PortableLossless<$int> {
+            #[inline]
+            fn portable_lossless(self) -> Option<$nonzero> {
+                // This is safe because LosslessCastError cannot be instantiated
+                unsafe {core::hint::unreachable_unchecked()}
+            }
+        }
+    };
+
+    // Implements Cast for Option<$nonzero> -> $to in terms of $int -> $to, where $to is a
+    // primitive, a NonZero* or another Option<NonZero*>. Casts are implemented for each target
+    // individually, as a generic implementation would overlap with those into Option<NonZero*>.
+    (from $nonzero:ty: $int:ty => $($to:ty),+) => {
+        $(
+            impl CastImpl<$to> for Option<$nonzero> {
+                type Error = <<$int as CastImpl<$to>>::Error as Rewrap<Self, $to, $to>>::Error;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$to, Self::Error> {
+                    let primitive = self.map_or(0, <$nonzero>::get);
+                    CastImpl::<$to>::cast_impl(primitive).map_err(|error| error.rewrap(self, |to| to))
+                }
+            }
+        )*
+    };
+
+    // Implements Closest and PortableLossless for Option<$nonzero> -> U in terms of $int -> U
+    (from $nonzero:ty: $int:ty) => {
+        impl<U> Closest<U> for LossyCastError<Option<$nonzero>, U>
+        where LossyCastError<$int, U>: Closest<U> {
+            #[inline]
+            fn closest(self) -> U {
+                let from = self.from.map_or(0, <$nonzero>::get);
+                LossyCastError {from, to: self.to}.closest()
+            }
+        }
+
+        impl<U> Closest<U> for FailedCastError<Option<$nonzero>, U>
+        where FailedCastError<$int, U>: Closest<U> {
+            #[inline]
+            fn closest(self) -> U {
+                FailedCastError::<$int, U>::new(self.from.map_or(0, <$nonzero>::get)).closest()
+            }
+        }
+
+        impl<U: Debug> PortableLossless<U> for LosslessCastError<Option<$nonzero>, U>
+        where LosslessCastError<$int, U>: PortableLossless<U> {
+            #[inline]
+            fn portable_lossless(self) -> U {
+                // This is safe because LosslessCastError cannot be instantiated
+                unsafe {core::hint::unreachable_unchecked()}
+            }
+        }
+    };
+
+    // Implements Bitwise for each $from -> $to, where at least one is an Option<NonZero*> and the other
+    // is of the same size. Option<NonZero*> is guaranteed to have the layout of its underlying
+    // primitive, with None as zero, so the value is reinterpreted directly.
+    (bitwise {$($from:ty),+} => $to:tt) => {
+        $(
+            option!(bitwise $from => $to);
+        )*
+    };
+
+    (bitwise $from:ty => {$($to:ty),+}) => {
+        $(
+            impl Bitwise<$to> for Result<$to, LossyCastError<$from, $to>> {
+                #[inline]
+                #[allow(clippy::useless_transmute)]
+                fn bitwise(self) -> $to {
+                    // A successful cast is lossless, so it can be casted back to the original
+                    let original = match self {
+                        Ok(value) => value.cast::<$from>().assumed_lossless(),
+                        Err(error) => error.from
+                    };
+
+                    // This is safe because both types have the layout of the same primitive, of
+                    // which every value is a valid Option<NonZero*>
+                    unsafe {core::mem::transmute::<$from, $to>(original)}
+                }
+            }
+
+            impl Bitwise<$to> for Result<$to, LosslessCastError<$from, $to>> {
+                #[inline]
+                #[allow(clippy::useless_transmute)]
+                fn bitwise(self) -> $to {
+                    // This is safe because LosslessCastError cannot be instantiated
+                    let original = unsafe {self.unwrap_unchecked()}
+                        .cast::<$from>()
+                        .assumed_lossless();
+
+                    // This is safe because both types have the layout of the same primitive, of
+                    // which every value is a valid Option<NonZero*>
+                    unsafe {core::mem::transmute::<$from, $to>(original)}
+                }
+            }
+        )*
+    };
+
+    // Implements Bitwise in both directions between each Option<$nonzero> and each $same of the
+    // same size
+    (bitwise {$($nonzero:ty),+} <=> {$($same:ty),+}) => {
+        option!(bitwise {$(Option<$nonzero>),+} => {$($same),+});
+        option!(bitwise {$($same),+} => {$(Option<$nonzero>),+});
+    };
+
+    // Implements Bitwise in n-squared fashion between each Option<$nonzero> of the same size
+    (bitwise $($nonzero:ty),+) => {
+        option!(bitwise {$(Option<$nonzero>),+} => {$(Option<$nonzero>),+});
+    };
+
+    // Generates all casts for each `$nonzero`
+    ($($nonzero:ty: $int:ty),+) => {
+        $(
+            option!(into $nonzero: $int);
+            option!(from $nonzero: $int);
+
+            option!(
+                from $nonzero: $int =>
+                u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64,
+                NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+                NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+                Option<NonZeroU8>, Option<NonZeroU16>, Option<NonZeroU32>, Option<NonZeroU64>,
+                Option<NonZeroU128>, Option<NonZeroUsize>, Option<NonZeroI8>, Option<NonZeroI16>,
+                Option<NonZeroI32>, Option<NonZeroI64>, Option<NonZeroI128>, Option<NonZeroIsize>
+            );
+        )*
+    };
+}
+
+option!(
+    NonZeroU8: u8, NonZeroU16: u16, NonZeroU32: u32, NonZeroU64: u64, NonZeroU128: u128,
+    NonZeroUsize: usize, NonZeroI8: i8, NonZeroI16: i16, NonZeroI32: i32, NonZeroI64: i64,
+    NonZeroI128: i128, NonZeroIsize: isize
+);
+
+option!(bitwise {NonZeroU8, NonZeroI8} <=> {u8, i8});
+option!(bitwise {NonZeroU16, NonZeroI16} <=> {u16, i16});
+option!(bitwise {NonZeroU32, NonZeroI32} <=> {u32, i32, f32});
+option!(bitwise {NonZeroU64, NonZeroI64} <=> {u64, i64, f64});
+option!(bitwise {NonZeroU128, NonZeroI128} <=> {u128, i128});
+option!(bitwise {NonZeroUsize, NonZeroIsize} <=> {usize, isize});
+
+option!(bitwise NonZeroU8, NonZeroI8);
+option!(bitwise NonZeroU16, NonZeroI16);
+option!(bitwise NonZeroU32, NonZeroI32);
+option!(bitwise NonZeroU64, NonZeroI64);
+option!(bitwise NonZeroU128, NonZeroI128);
+option!(bitwise NonZeroUsize, NonZeroIsize);
+
+// -- Platform-dependent -- //
+#[cfg(target_pointer_width = "16")]
+#[allow(clippy::wildcard_imports)]
+mod platform_dependent {
+    use super::*;
+
+    option!(bitwise {NonZeroU16, NonZeroI16} <=> {usize, isize});
+    option!(bitwise {NonZeroUsize, NonZeroIsize} <=> {u16, i16});
+
+    option!(
+        bitwise {Option<NonZeroU16>, Option<NonZeroI16>} =>
+        {Option<NonZeroUsize>, Option<NonZeroIsize>}
+    );
+
+    option!(
+        bitwise {Option<NonZeroUsize>, Option<NonZeroIsize>} =>
+        {Option<NonZeroU16>, Option<NonZeroI16>}
+    );
+}
+
+#[cfg(target_pointer_width = "32")]
+#[allow(clippy::wildcard_imports)]
+mod platform_dependent {
+    use super::*;
+
+    option!(bitwise {NonZeroU32, NonZeroI32} <=> {usize, isize});
+    option!(bitwise {NonZeroUsize, NonZeroIsize} <=> {u32, i32, f32});
+
+    option!(
+        bitwise {Option<NonZeroU32>, Option<NonZeroI32>} =>
+        {Option<NonZeroUsize>, Option<NonZeroIsize>}
+    );
+
+    option!(
+        bitwise {Option<NonZeroUsize>, Option<NonZeroIsize>} =>
+        {Option<NonZeroU32>, Option<NonZeroI32>}
+    );
+}
+
+#[cfg(target_pointer_width = "64")]
+#[allow(clippy::wildcard_imports)]
+mod platform_dependent {
+    use super::*;
+
+    option!(bitwise {NonZeroU64, NonZeroI64} <=> {usize, isize});
+    option!(bitwise {NonZeroUsize, NonZeroIsize} <=> {u64, i64, f64});
+
+    option!(
+        bitwise {Option<NonZeroU64>, Option<NonZeroI64>} =>
+        {Option<NonZeroUsize>, Option<NonZeroIsize>}
+    );
+
+    option!(
+        bitwise {Option<NonZeroUsize>, Option<NonZeroIsize>} =>
+        {Option<NonZeroU64>, Option<NonZeroI64>}
+    );
+}
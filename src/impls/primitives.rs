@@ -2,9 +2,12 @@
 
 #![allow(clippy::wildcard_imports)]
 
-use crate::casts::{Cast, Closest};
+use crate::casts::{
+    Cast, CastSplit, Closest, PortableLossless, Rounded, RoundingMode, Stochastic, StochasticRng
+};
 use crate::errors::{LosslessCastError, LossyCastError};
-use crate::base::CastImpl;
+use crate::base::{CastImpl, CastSplitImpl};
+use crate::konst::ConstCast;
 
 macro_rules! cast {
     ($($num:ty),+) => {
@@ -13,6 +16,35 @@ macro_rules! cast {
         )*
     };
 
+    // Implements the ConstCast functions which are common to all casts in terms of its `cast`
+    (const $from:ty => $to:ty) => {
+        impl ConstCast<$from, $to> {
+            /// Casts `value` lossily, yielding the same value as the `as` keyword
+            #[inline]
+            #[must_use]
+            #[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+            #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+            #[allow(clippy::cast_precision_loss)]
+            pub const fn lossy(value: $from) -> $to {
+                value as $to
+            }
+
+            /// Casts `value`, panicking if the cast is lossy; in a const context, this fails
+            /// compilation instead
+            ///
+            /// # Panics
+            /// Panics if the cast is lossy
+            #[inline]
+            #[must_use]
+            pub const fn unwrap(value: $from) -> $to {
+                match Self::cast(value) {
+                    Ok(value) => value,
+                    Err(_) => panic!("Numerical cast was lossy")
+                }
+            }
+        }
+    };
+
     (integer $from:ty => $($to:ty),+) => {
         $(
             impl CastImpl<$to> for $from {
@@ -27,6 +59,25 @@ macro_rules! cast {
                 }
             }
 
+            impl ConstCast<$from, $to> {
+                /// Casts `value`, yielding the same [`Result`] as [`Cast::cast`]
+                ///
+                /// # Errors
+                /// Returns a [`LossyCastError`] if the cast is lossy
+                #[inline]
+                #[allow(unused_comparisons)]
+                pub const fn cast(value: $from) -> Result<$to, LossyCastError<$from, $to>> {
+                    // The cast is lossless if and only if it round trips and preserves the sign
+                    let to = value as $to;
+                    match to as $from == value && (to < 0) == (value < 0) {
+                        true => Ok(to),
+                        false => Err(LossyCastError {from: value, to})
+                    }
+                }
+            }
+
+            cast!(const $from => $to);
+
             impl Closest<$to> for LossyCastError<$from, $to> {
                 #[inline]
                 fn closest(self) -> $to {
@@ -71,6 +122,32 @@ macro_rules! cast {
                 }
             }
 
+            impl ConstCast<$from, $to> {
+                /// Casts `value`, yielding the same [`Result`] as [`Cast::cast`]
+                ///
+                /// # Errors
+                /// Returns a [`LossyCastError`] if the cast is lossy
+                #[inline]
+                #[allow(unused_comparisons, clippy::cast_sign_loss, clippy::cast_lossless)]
+                pub const fn cast(value: $from) -> Result<$to, LossyCastError<$from, $to>> {
+                    // Integers are exactly representable if their significant bits fit within
+                    // the mantissa; no such integer is large enough to overflow
+                    let magnitude = match value < 0 {
+                        true => (value as i128).unsigned_abs(),
+                        false => value as u128
+                    };
+
+                    let zeros = magnitude.leading_zeros() + magnitude.trailing_zeros();
+                    let to = value as $to;
+                    match zeros + <$to>::MANTISSA_DIGITS >= 128 {
+                        true => Ok(to),
+                        false => Err(LossyCastError {from: value, to})
+                    }
+                }
+            }
+
+            cast!(const $from => $to);
+
             impl Closest<$to> for LossyCastError<$from, $to> {
                 #[inline]
                 fn closest(self) -> $to {
@@ -143,6 +220,33 @@ macro_rules! cast {
                 }
             }
 
+            impl CastSplitImpl<$to> for $from {
+                type Error = LossyCastError<Self, $to>;
+
+                #[inline]
+                #[allow(clippy::float_cmp)]
+                fn cast_split_impl(self) -> Result<($to, Self), Self::Error> {
+                    // The integral part is representable if the value is finite and lies strictly
+                    // between MIN - 1 and MAX + 1. These bounds may round to MIN and to the max
+                    // castable, in which case there are no floats between them and the true bounds;
+                    // the bounds themselves must then be accepted explicitly. The max castable may
+                    // be infinite, so finiteness is checked separately.
+                    let min = <$to>::MIN as $from;
+                    let above_min = self >= min || self > min - 1.0;
+                    let below_max = self <= $max || self < $max + 1.0;
+
+                    match self.is_finite() && above_min && below_max {
+                        // Within the range the raw cast truncates towards zero, and the integral
+                        // part is exactly representable as a float, so the remainder is exact
+                        true => Ok((self as $to, self - (self as $to) as $from)),
+                        false => Err(LossyCastError {
+                            from: self,
+                            to: self as $to
+                        })
+                    }
+                }
+            }
+
             impl Closest<$to> for LossyCastError<$from, $to> {
                 #[inline]
                 fn closest(self) -> $to {
@@ -163,6 +267,61 @@ macro_rules! cast {
                     }
                 }
             }
+
+            impl Rounded<$to> for LossyCastError<$from, $to> {
+                #[inline]
+                #[allow(clippy::float_cmp)]
+                fn rounded(self, mode: RoundingMode) -> $to {
+                    // The raw cast truncates towards zero and saturates. Within the target's range
+                    // the truncated value is exactly representable as a float, so the fraction can
+                    // be computed exactly; outside the range the truncated value is already MAX or
+                    // MIN and the saturating adjustments below leave it there. NaN yields a NaN
+                    // fraction, which fails every comparison and so leaves the 0 from the raw cast.
+                    let truncated = self.from as $to;
+                    let fraction = self.from - truncated as $from;
+
+                    let up = match mode {
+                        RoundingMode::Floor | RoundingMode::TowardZero => false,
+                        RoundingMode::Ceil => fraction > 0.0,
+                        RoundingMode::HalfAwayFromZero => fraction >= 0.5,
+                        RoundingMode::HalfEven => fraction > 0.5
+                            || (fraction == 0.5 && truncated % 2 != 0)
+                    };
+
+                    let down = match mode {
+                        RoundingMode::Ceil | RoundingMode::TowardZero => false,
+                        RoundingMode::Floor => fraction < 0.0,
+                        RoundingMode::HalfAwayFromZero => fraction <= -0.5,
+                        RoundingMode::HalfEven => fraction < -0.5
+                            || (fraction == -0.5 && truncated % 2 != 0)
+                    };
+
+                    match (up, down) {
+                        (true, _) => truncated.saturating_add(1),
+                        (_, true) => truncated.saturating_sub(1),
+                        _ => truncated
+                    }
+                }
+            }
+
+            impl Stochastic<$to> for LossyCastError<$from, $to> {
+                #[inline]
+                fn stochastic<R: StochasticRng + ?Sized>(self, rng: &mut R) -> $to {
+                    // As for Rounded, the fraction is exact within the target's range; rounding
+                    // away from the truncated value with probability equal to the magnitude of the
+                    // fraction makes the expected result equal to the origin value. Outside of the
+                    // range the saturating adjustments have no effect, and a NaN fraction fails
+                    // both comparisons.
+                    let truncated = self.from as $to;
+                    let fraction = f64::from(self.from - truncated as $from);
+
+                    match unit_interval(rng) {
+                        random if random < fraction => truncated.saturating_add(1),
+                        random if random < -fraction => truncated.saturating_sub(1),
+                        _ => truncated
+                    }
+                }
+            }
         )*
     };
 
@@ -176,6 +335,26 @@ macro_rules! cast {
         $(cast!(float_to_int $from => $to));*;
     };
 
+    // Implements Cast and PortableLossless for casts which are lossless on every platform
+    (portable lossless $from:ty => $($to:ty),+) => {
+        cast!(lossless $from => $($to),+);
+        cast!(portable $from => $($to),+);
+    };
+
+    // Implements PortableLossless for casts whose Cast is implemented elsewhere; these must be
+    // lossless under every `platform_dependent` table
+    (portable $from:ty => $($to:ty),+) => {
+        $(
+            impl PortableLossless<$to> for LosslessCastError<$from, $to> {
+                #[inline]
+                fn portable_lossless(self) -> $to {
+                    // This is safe because LosslessCastError cannot be instantiated
+                    unsafe {core::hint::unreachable_unchecked()}
+                }
+            }
+        )*
+    };
+
     (lossless $from:ty => $($to:ty),+) => {
         $(
             impl CastImpl<$to> for $from {
@@ -186,6 +365,28 @@ macro_rules! cast {
                     Ok(self as $to)
                 }
             }
+
+            impl ConstCast<$from, $to> {
+                /// Casts `value`, yielding the same [`Result`] as [`Cast::cast`]
+                ///
+                /// # Errors
+                /// Never returns an error, since the cast is lossless
+                #[inline]
+                #[allow(clippy::cast_lossless)]
+                pub const fn cast(value: $from) -> Result<$to, LosslessCastError<$from, $to>> {
+                    Ok(value as $to)
+                }
+
+                /// Casts `value` losslessly, as with [`Lossless`](crate::casts::Lossless)
+                #[inline]
+                #[must_use]
+                #[allow(clippy::cast_lossless)]
+                pub const fn lossless(value: $from) -> $to {
+                    value as $to
+                }
+            }
+
+            cast!(const $from => $to);
         )*
     };
 
@@ -198,34 +399,37 @@ macro_rules! cast {
 // -- Macro-Generated Bulk Implementations: Portable -- //
 cast!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
 
-cast!(lossless u8 => u8, u16, u32, u64, u128, i16, i32, i64, i128, f32, f64);
+impl CastSplit for f32 {}
+impl CastSplit for f64 {}
+
+cast!(portable lossless u8 => u8, u16, u32, u64, u128, i16, i32, i64, i128, f32, f64);
 cast!(integer u8 => i8);
 
-cast!(lossless u16 => u16, u32, u64, u128, i32, i64, i128, f32, f64);
+cast!(portable lossless u16 => u16, u32, u64, u128, i32, i64, i128, f32, f64);
 cast!(integer u16 => u8, i8, i16);
 
-cast!(lossless u32 => u32, u64, u128, i64, i128, f64);
+cast!(portable lossless u32 => u32, u64, u128, i64, i128, f64);
 cast!(integer u32 => u8, u16, i8, i16, i32);
 
-cast!(lossless u64 => u64, u128, i128);
+cast!(portable lossless u64 => u64, u128, i128);
 cast!(integer u64 => u8, u16, u32, i8, i16, i32, i64);
 
-cast!(lossless u128 => u128);
+cast!(portable lossless u128 => u128);
 cast!(integer u128 => u8, u16, u32, u64, i8, i16, i32, i64, i128);
 
-cast!(lossless i8 => i8, i16, i32, i64, i128, f32, f64);
+cast!(portable lossless i8 => i8, i16, i32, i64, i128, f32, f64);
 cast!(integer i8 => u8, u16, u32, u64, u128);
 
-cast!(lossless i16 => i16, i32, i64, i128, f32, f64);
+cast!(portable lossless i16 => i16, i32, i64, i128, f32, f64);
 cast!(integer i16 => u8, u16, u32, u64, u128, i8);
 
-cast!(lossless i32 => i32, i64, i128, f64);
+cast!(portable lossless i32 => i32, i64, i128, f64);
 cast!(integer i32 => u8, u16, u32, u64, u128, i8, i16);
 
-cast!(lossless i64 => i64, i128);
+cast!(portable lossless i64 => i64, i128);
 cast!(integer i64 => u8, u16, u32, u64, u128, i8, i16, i32);
 
-cast!(lossless i128 => i128);
+cast!(portable lossless i128 => i128);
 cast!(integer i128 => u8, u16, u32, u64, u128, i8, i16, i32, i64);
 
 cast!(int_to_float u32, u64, u128, i32, i64, i128 => f32);
@@ -261,16 +465,24 @@ cast!(
     (i128, 170_141_183_460_469_212_842_221_372_237_303_250_944_f64)
 );
 
-cast!(lossless f32 => f32, f64);
-cast!(lossless f64 => f64);
+cast!(portable lossless f32 => f32, f64);
+cast!(portable lossless f64 => f64);
 // Note: f64 -> f32 is implemented manually later in this module
 
-cast!(lossless usize => usize);
+cast!(portable lossless usize => usize);
 cast!(integer usize => isize);
 
-cast!(lossless isize => isize);
+cast!(portable lossless isize => isize);
 cast!(integer isize => usize);
 
+// Casts involving usize/isize which are lossless under every `platform_dependent` table below
+cast!(portable usize => u128);
+cast!(portable isize => i128);
+cast!(portable u8 => usize, isize);
+cast!(portable u16 => usize);
+cast!(portable i8 => isize);
+cast!(portable i16 => isize);
+
 // -- Macro-Generated Bulk Implementations: Non-Portable -- //
 #[cfg(target_pointer_width = "16")]
 mod platform_dependent {
@@ -353,7 +565,8 @@ mod platform_dependent {
     cast!(lossless usize => u128);
     cast!(integer usize => u8, u16, u32, u64, i8, i16, i32, i64, i128);
     
-    cast!(integer isize => u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+    cast!(lossless isize => i128);
+    cast!(integer isize => u8, u16, u32, u64, u128, i8, i16, i32, i64);
 
     cast!(lossless u8, u16, u32, u64, u128 => usize);
     cast!(integer i8, i16, i32, i64, i128 => usize);
@@ -382,6 +595,34 @@ mod platform_dependent {
     );
 }
 
+// -- Helpers -- //
+/// Draws a uniformly distributed value in `[0, 1)` with 53 bits of precision from `rng`
+#[inline]
+#[allow(clippy::cast_precision_loss)]
+pub(super) fn unit_interval<R: StochasticRng + ?Sized>(rng: &mut R) -> f64 {
+    // The top 53 bits are exactly representable in the mantissa of an f64
+    (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Returns the adjacent [`f32`] towards positive infinity if `up` and towards negative infinity
+/// otherwise; `value` must be finite
+#[inline]
+fn adjacent_f32(value: f32, up: bool) -> f32 {
+    match value == 0.0 {
+        // Both signed zeroes are adjacent to the smallest subnormals
+        true => match up {
+            true => f32::from_bits(1),
+            false => -f32::from_bits(1)
+        },
+
+        // Otherwise moving away from zero increments the magnitude bits and vice versa
+        false => match value.is_sign_positive() == up {
+            true => f32::from_bits(value.to_bits() + 1),
+            false => f32::from_bits(value.to_bits() - 1)
+        }
+    }
+}
+
 // -- Manual Implementations -- //
 impl CastImpl<f32> for f64 {
     type Error = LossyCastError<Self, f32>;
@@ -418,4 +659,33 @@ impl Closest<f32> for LossyCastError<f64, f32> {
             _ => self.to
         }
     }
-}
\ No newline at end of file
+}
+
+impl Stochastic<f32> for LossyCastError<f64, f32> {
+    #[inline]
+    fn stochastic<R: StochasticRng + ?Sized>(self, rng: &mut R) -> f32 {
+        // Overflow to infinity saturates just as for Closest
+        if self.to.is_infinite() {
+            return self.closest();
+        }
+
+        // The raw cast yields the nearest neighbor; find the other neighbor on the far side of the
+        // origin value, which saturates if it would overflow
+        let nearest = self.to;
+        let other = adjacent_f32(nearest, f64::from(nearest) < self.from);
+
+        if other.is_infinite() {
+            return nearest;
+        }
+
+        // Both differences are exact in f64 since the values are adjacent f32s, so this is the
+        // exact probability of rounding to the other neighbor (at most one half)
+        let (nearest_wide, other_wide) = (f64::from(nearest), f64::from(other));
+        let probability = (self.from - nearest_wide) / (other_wide - nearest_wide);
+
+        match unit_interval(rng) < probability {
+            true => other,
+            false => nearest
+        }
+    }
+}
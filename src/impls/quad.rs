@@ -0,0 +1,479 @@
+//! This module provides implementations of the casting traits for the software quadruple precision
+//! floating point type f128, along with the rounding between binary floating point formats on
+//! which they rely
+
+use super::fixed::power_of_two;
+use super::numeric::{Numeric, PARTS};
+use crate::base::CastImpl;
+use crate::casts::{Cast, Closest, Lossless, PortableLossless};
+use crate::errors::{LosslessCastError, LossyCastError};
+use crate::types::f128;
+
+use core::cmp::Ordering;
+use core::num::{
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+};
+
+impl Cast for f128 {}
+
+// -- Rounding -- //
+/// Describes the layout of the bits of a binary floating point format in the IEEE 754 style
+#[derive(Copy, Clone)]
+struct Layout {
+    /// The number of bits in the exponent
+    exponent: u32,
+
+    /// The number of bits in the mantissa, excluding the implicit leading bit
+    mantissa: u32,
+
+    /// The bias of the exponent
+    bias: i32
+}
+
+const F32: Layout = Layout {exponent: 8, mantissa: 23, bias: 127};
+const F64: Layout = Layout {exponent: 11, mantissa: 52, bias: 1023};
+const F128: Layout = Layout {exponent: 15, mantissa: f128::MANTISSA, bias: f128::BIAS};
+
+/// Returns the bits in `layout` of the nearest value to `significand * 2^exponent` with the given
+/// sign, rounding ties to even, along with whether the result is exact; values beyond the largest
+/// finite value round to infinity
+#[inline]
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+fn round(layout: Layout, negative: bool, significand: u128, exponent: i32) -> (u128, bool) {
+    let sign = u128::from(negative) << (layout.exponent + layout.mantissa);
+    let infinity = ((1 << layout.exponent) - 1) << layout.mantissa;
+    if significand == 0 {
+        return (sign, true);
+    }
+
+    // The exponent of the last place of the result, which is fixed throughout the subnormal range
+    let leading = exponent + 127 - significand.leading_zeros() as i32;
+    let minimum = 1 - layout.bias - layout.mantissa as i32;
+    let last = (leading - layout.mantissa as i32).max(minimum);
+
+    // The exponent field less one, which overflows at this point even without rounding up
+    let field = last - minimum;
+    if field >= (1 << layout.exponent) - 2 {
+        return (sign | infinity, false);
+    }
+
+    let (kept, exact) = match last - exponent {
+        shift @ ..=0 => (significand << -shift, true),
+        shift @ 1 ..= 127 => {
+            let kept = significand >> shift;
+            let remainder = significand & ((1 << shift) - 1);
+            let half = 1 << (shift - 1);
+            let up = remainder > half || (remainder == half && kept & 1 == 1);
+            (kept + u128::from(up), remainder == 0)
+        },
+
+        // The whole significand is the remainder, which is below one half unless it exceeds 2^127
+        128 => (u128::from(significand > 1 << 127), false),
+        _ => (0, false)
+    };
+
+    // Adding the kept significand to the exponent field less one accounts for its leading bit, and
+    // also carries into the exponent if rounding overflowed the mantissa. Subnormals have an
+    // exponent field of zero and no leading bit.
+    match ((field as u128) << layout.mantissa) + kept {
+        magnitude if magnitude >= infinity => (sign | infinity, false),
+        magnitude => (sign | magnitude, exact)
+    }
+}
+
+/// Converts the bits of a primitive float in `layout` to the [`f128`] of the same value, keeping
+/// the payload of NaN in the leading bits of the mantissa
+#[inline]
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+fn widen(layout: Layout, bits: u128) -> f128 {
+    let negative = bits >> (layout.exponent + layout.mantissa) != 0;
+    let field = (bits >> layout.mantissa) as i32 & ((1 << layout.exponent) - 1);
+    let fraction = bits & ((1 << layout.mantissa) - 1);
+    let minimum = 1 - layout.bias - layout.mantissa as i32;
+
+    let (bits, _) = match field {
+        field if field == (1 << layout.exponent) - 1 => {
+            let payload = fraction << (f128::MANTISSA - layout.mantissa);
+            (f128::INFINITY.to_bits() | u128::from(negative) << 127 | payload, true)
+        },
+        0 => round(F128, negative, fraction, minimum),
+        field => round(F128, negative, fraction | 1 << layout.mantissa, minimum + field - 1)
+    };
+
+    f128::from_bits(bits)
+}
+
+/// Returns the bits in `layout` of the nearest value to `value`, as described by [`round`]; NaN
+/// becomes a quiet NaN which keeps the sign and the leading bits of the payload
+#[inline]
+fn narrow(layout: Layout, value: f128) -> (u128, bool) {
+    let (negative, significand, exponent) = value.decompose();
+    let sign = u128::from(negative) << (layout.exponent + layout.mantissa);
+    let infinity = ((1 << layout.exponent) - 1) << layout.mantissa;
+
+    match value {
+        value if value.is_nan() => {
+            let payload = significand >> (f128::MANTISSA - layout.mantissa);
+            let payload = payload & ((1 << layout.mantissa) - 1);
+            (sign | infinity | 1 << (layout.mantissa - 1) | payload, true)
+        },
+        value if value.is_infinite() => (sign | infinity, true),
+        _ => round(layout, negative, significand, exponent)
+    }
+}
+
+/// Converts `value` to the integer `T`, truncating or rounding to nearest with ties away from zero
+/// and saturating at the bounds of `T` as `as` does for the primitive floats; NaN becomes zero
+///
+/// The result is `Ok` only if the conversion is exact.
+#[inline]
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+fn integer<T: Numeric + Default>(value: f128, nearest: bool) -> Result<T, T>
+where u128: CastImpl<T>, i128: CastImpl<T> {
+    if value.is_nan() {
+        return Err(T::default());
+    }
+
+    // Split the magnitude into its integral part, if it does not exceed u128, and the comparison
+    // of its fractional part with one half, if it is not zero
+    let (negative, significand, exponent) = value.decompose();
+    let (integral, fraction) = match exponent {
+        exponent @ 0 .. => match significand.leading_zeros() >= exponent as u32 {
+            true => (Some(significand << exponent), None),
+            false => (None, None)
+        },
+        exponent @ -127 ..= -1 => {
+            let remainder = significand & ((1 << -exponent) - 1);
+            let half = 1 << (-exponent - 1);
+            (Some(significand >> -exponent), (remainder != 0).then(|| remainder.cmp(&half)))
+        },
+        -128 => (Some(0), (significand != 0).then(|| significand.cmp(&(1 << 127)))),
+        _ => (Some(0), (significand != 0).then_some(Ordering::Less))
+    };
+
+    let integral = match nearest && fraction >= Some(Ordering::Equal) {
+        true => integral.and_then(|integral| integral.checked_add(1)),
+        false => integral
+    };
+
+    let result = match (negative, integral) {
+        (false, Some(integral)) => CastImpl::<T>::cast_impl(integral).ok().ok_or(T::MAX),
+        (true, Some(integral)) if integral <= 1 << 127 => {
+            CastImpl::<T>::cast_impl((integral as i128).wrapping_neg()).ok().ok_or(T::MIN)
+        },
+        (false, None) => Err(T::MAX),
+        (true, _) => Err(T::MIN)
+    };
+
+    match fraction {
+        None => result,
+        Some(_) => Err(result.unwrap_or_else(|to| to))
+    }
+}
+
+// -- Numeric -- //
+/// Splits `value` into parts as described by [`Numeric::parts`], by repeatedly subtracting the
+/// nearest [`f64`] to the remainder
+///
+/// Values beyond the range of [`f64`] exceed the range of every other numerical type, so they are
+/// represented by parts of [`f64::MAX`] or [`f64::MIN`], which preserve their order.
+#[inline]
+#[allow(clippy::cast_possible_truncation)]
+pub(super) fn parts(value: f128) -> [f64; PARTS] {
+    let mut parts = [0.0; PARTS];
+    let (mut negative, mut significand, exponent) = value.decompose();
+
+    match value {
+        value if value.is_nan() => parts[0] = f64::NAN,
+        value if value.is_infinite() && negative => parts[0] = f64::NEG_INFINITY,
+        value if value.is_infinite() => parts[0] = f64::INFINITY,
+        _ => for part in &mut parts {
+            let (bits, _) = round(F64, negative, significand, exponent);
+            *part = f64::from_bits(bits as u64);
+
+            if part.is_infinite() {
+                return [part.clamp(f64::MIN, f64::MAX); PARTS];
+            } else if *part == 0.0 {
+                break;
+            }
+
+            // The nearest f64 is a multiple of 2^exponent, since it either equals the remainder or
+            // rounds it at a coarser precision
+            let (_, nearest, nearest_exponent) = widen(F64, bits).decompose();
+            let nearest = match nearest_exponent - exponent {
+                shift @ 0 .. => nearest << shift,
+                shift => nearest >> -shift
+            };
+
+            (negative, significand) = match nearest > significand {
+                true => (!negative, nearest - significand),
+                false => (negative, significand - nearest)
+            };
+        }
+    }
+
+    parts
+}
+
+/// Returns the unit in the last place of `value`, as described by [`Numeric::ulp`], rounded to
+/// [`f64`]
+#[inline]
+#[allow(clippy::cast_sign_loss)]
+pub(super) fn ulp(value: f128) -> f64 {
+    match value.decompose().2 {
+        1024 .. => f64::INFINITY,
+        exponent @ -1022 .. => power_of_two(exponent),
+        exponent @ -1074 .. => f64::from_bits(1 << (exponent + 1074)),
+        _ => 0.0
+    }
+}
+
+// -- Casts -- //
+macro_rules! quad {
+    // Implements Cast and PortableLossless for $from -> f128 via LosslessCastError, where $from is
+    // an integer of up to 64 bits
+    (from portable integer $($from:ty),+) => {
+        quad!(from integer $($from),+);
+
+        $(
+            impl PortableLossless<f128> for LosslessCastError<$from, f128> {
+                #[inline]
+                fn portable_lossless(self) -> f128 {
+                    // This is safe because LosslessCastError cannot be instantiated
+                    unsafe {core::hint::unreachable_unchecked()}
+                }
+            }
+        )*
+    };
+
+    // Implements Cast for $from -> f128 via LosslessCastError, where $from is an integer of up to
+    // 64 bits on the current platform
+    (from integer $($from:ty),+) => {
+        $(
+            impl CastImpl<f128> for $from {
+                type Error = LosslessCastError<Self, f128>;
+
+                #[inline]
+                #[allow(clippy::cast_lossless, clippy::cast_possible_wrap)]
+                fn cast_impl(self) -> Result<f128, Self::Error> {
+                    let value = self as i128;
+                    Ok(f128::from_bits(round(F128, value < 0, value.unsigned_abs(), 0).0))
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and Closest for $from -> f128 via LossyCastError, where $from is an integer
+    // of 128 bits which is split into its sign and magnitude by $split
+    (from wide $($from:ty: $split:expr),+) => {
+        $(
+            impl CastImpl<f128> for $from {
+                type Error = LossyCastError<Self, f128>;
+
+                #[inline]
+                #[allow(clippy::redundant_closure_call)]
+                fn cast_impl(self) -> Result<f128, Self::Error> {
+                    let (negative, magnitude) = ($split)(self);
+                    match round(F128, negative, magnitude, 0) {
+                        (bits, true) => Ok(f128::from_bits(bits)),
+                        (bits, false) => Err(LossyCastError {from: self, to: f128::from_bits(bits)})
+                    }
+                }
+            }
+
+            impl Closest<f128> for LossyCastError<$from, f128> {
+                #[inline]
+                fn closest(self) -> f128 {
+                    // Integers never overflow f128, so the lossy value is always the nearest
+                    self.to
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and PortableLossless for $from -> f128 via LosslessCastError, where $from is
+    // a NonZero* of up to 64 bits
+    (from portable nonzero $($from:ty),+) => {
+        quad!(from nonzero $($from),+);
+
+        $(
+            impl PortableLossless<f128> for LosslessCastError<$from, f128> {
+                #[inline]
+                fn portable_lossless(self) -> f128 {
+                    // This is safe because LosslessCastError cannot be instantiated
+                    unsafe {core::hint::unreachable_unchecked()}
+                }
+            }
+        )*
+    };
+
+    // Implements Cast for $from -> f128 via LosslessCastError, where $from is a NonZero* of up to
+    // 64 bits on the current platform, in terms of the underlying primitive implementations
+    (from nonzero $($from:ty),+) => {
+        $(
+            impl CastImpl<f128> for $from {
+                type Error = LosslessCastError<Self, f128>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<f128, Self::Error> {
+                    Ok(self.get().cast::<f128>().lossless())
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and Closest for $from -> f128 via LossyCastError, where $from is a NonZero*
+    // of 128 bits, in terms of the underlying primitive implementations
+    (from wide nonzero $($from:ty),+) => {
+        $(
+            impl CastImpl<f128> for $from {
+                type Error = LossyCastError<Self, f128>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<f128, Self::Error> {
+                    self.get().cast::<f128>().map_err(|error| LossyCastError {
+                        from: self,
+                        to: error.to
+                    })
+                }
+            }
+
+            impl Closest<f128> for LossyCastError<$from, f128> {
+                #[inline]
+                fn closest(self) -> f128 {
+                    // Integers never overflow f128, so the lossy value is always the nearest
+                    self.to
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and PortableLossless for $float -> f128 via LosslessCastError, where $float
+    // is a primitive float with the bits of $layout
+    (from float $($float:ty: $layout:expr),+) => {
+        $(
+            impl CastImpl<f128> for $float {
+                type Error = LosslessCastError<Self, f128>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<f128, Self::Error> {
+                    Ok(widen($layout, self.to_bits().into()))
+                }
+            }
+
+            impl PortableLossless<f128> for LosslessCastError<$float, f128> {
+                #[inline]
+                fn portable_lossless(self) -> f128 {
+                    // This is safe because LosslessCastError cannot be instantiated
+                    unsafe {core::hint::unreachable_unchecked()}
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and Closest for f128 -> $float via LossyCastError, where $float is a
+    // primitive float with the bits of $layout
+    (into float $($float:ty: $layout:expr),+) => {
+        $(
+            impl CastImpl<$float> for f128 {
+                type Error = LossyCastError<Self, $float>;
+
+                #[inline]
+                #[allow(clippy::cast_possible_truncation)]
+                fn cast_impl(self) -> Result<$float, Self::Error> {
+                    match narrow($layout, self) {
+                        (bits, true) => Ok(<$float>::from_bits(bits as _)),
+                        (bits, false) => Err(LossyCastError {
+                            from: self,
+                            to: <$float>::from_bits(bits as _)
+                        })
+                    }
+                }
+            }
+
+            impl Closest<$float> for LossyCastError<f128, $float> {
+                #[inline]
+                fn closest(self) -> $float {
+                    // Overflow yields infinity, which saturates to the finite extremes; NaN and
+                    // infinity are never lossy
+                    self.to.clamp(<$float>::MIN, <$float>::MAX)
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and Closest for f128 -> $int via LossyCastError; see integer
+    (into integer $($int:ty),+) => {
+        $(
+            impl CastImpl<$int> for f128 {
+                type Error = LossyCastError<Self, $int>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$int, Self::Error> {
+                    integer(self, false).map_err(|to| LossyCastError {from: self, to})
+                }
+            }
+
+            impl Closest<$int> for LossyCastError<f128, $int> {
+                #[inline]
+                fn closest(self) -> $int {
+                    integer(self.from, true).unwrap_or_else(|to| to)
+                }
+            }
+        )*
+    }
+}
+
+impl CastImpl<f128> for f128 {
+    type Error = LosslessCastError<Self, f128>;
+
+    #[inline]
+    fn cast_impl(self) -> Result<f128, Self::Error> {
+        Ok(self)
+    }
+}
+
+quad!(from portable integer u8, u16, u32, u64, i8, i16, i32, i64);
+quad!(
+    from wide
+    u128: |value| (false, value),
+    i128: |value: i128| (value < 0, value.unsigned_abs())
+);
+quad!(
+    from portable nonzero
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64
+);
+quad!(from wide nonzero NonZeroU128, NonZeroI128);
+quad!(from float f32: F32, f64: F64);
+quad!(into float f32: F32, f64: F64);
+quad!(into integer u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+// -- Platform-dependent -- //
+#[cfg(any(
+    target_pointer_width = "16",
+    target_pointer_width = "32",
+    target_pointer_width = "64"
+))]
+quad!(from integer usize, isize);
+
+#[cfg(any(
+    target_pointer_width = "16",
+    target_pointer_width = "32",
+    target_pointer_width = "64"
+))]
+quad!(from nonzero NonZeroUsize, NonZeroIsize);
+
+#[cfg(target_pointer_width = "128")]
+#[allow(clippy::cast_sign_loss)]
+mod platform_dependent {
+    use super::*;
+
+    quad!(
+        from wide
+        usize: |value| (false, value as u128),
+        isize: |value: isize| (value < 0, value.unsigned_abs() as u128)
+    );
+
+    quad!(from wide nonzero NonZeroUsize, NonZeroIsize);
+}
@@ -0,0 +1,186 @@
+//! This module provides implementations of the casting traits for the Ranged type
+
+use crate::base::CastImpl;
+use crate::casts::{Cast, Closest, Lossless, Lossy};
+use crate::errors::{FailedCastError, LossyCastError};
+use crate::types::{IntegerRange, Ranged};
+
+use core::marker::PhantomData;
+use core::num::{
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+};
+
+impl<T, const MIN: i128, const MAX: i128> Cast for Ranged<T, MIN, MAX> {}
+
+// -- Proof -- //
+/// Helper type for proving at compile time that casts involving [`Ranged`] are lossless; the
+/// proofs are associated constants which fail to evaluate if the cast could be lossy
+struct Proof<T, const MIN: i128, const MAX: i128>(PhantomData<T>);
+
+impl<T: IntegerRange, const MIN: i128, const MAX: i128> Proof<T, MIN, MAX> {
+    /// Proves that every integer within `MIN..=MAX` is exactly representable by `T`
+    const INTO: () = assert!(
+        T::LOWER <= MIN && MAX <= T::UPPER && (T::HAS_ZERO || MIN > 0 || MAX < 0),
+        "Cast from Ranged is not guaranteed lossless by its range"
+    );
+
+    /// Proves that every value of `T` is an integer within `MIN..=MAX`
+    const FROM: () = assert!(
+        T::EXHAUSTIVE && MIN <= T::LOWER && T::UPPER <= MAX,
+        "Cast to Ranged is not guaranteed lossless by its range"
+    );
+}
+
+// -- Casts -- //
+macro_rules! ranged {
+    // Implements Cast, Closest and Lossless for Ranged<$int> -> $primitive via LossyCastError and
+    // Ranged<$int> -> $nonzero via FailedCastError. Casts are performed via the underlying
+    // primitive; Lossless relies on a proof which the blanket implementation for Result evaluates.
+    (into $int:ty => {$($primitive:ty),+}; {$($nonzero:ty),+}) => {
+        $(
+            impl<const MIN: i128, const MAX: i128> CastImpl<$primitive> for Ranged<$int, MIN, MAX> {
+                type Error = LossyCastError<Self, $primitive>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$primitive, Self::Error> {
+                    self.get().cast::<$primitive>().map_err(|error| LossyCastError {
+                        from: self,
+                        to: error.lossy()
+                    })
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> Closest<$primitive>
+            for LossyCastError<Ranged<$int, MIN, MAX>, $primitive> {
+                #[inline]
+                fn closest(self) -> $primitive {
+                    self.from.get().cast::<$primitive>().closest()
+                }
+            }
+
+            unsafe impl<const MIN: i128, const MAX: i128> Lossless<$primitive>
+            for LossyCastError<Ranged<$int, MIN, MAX>, $primitive> {
+                // Fail compilation unless the range proves the cast to be lossless
+                const _PROOF: () = Proof::<$primitive, MIN, MAX>::INTO;
+
+                #[inline]
+                fn lossless(self) -> $primitive {
+                    // The proof guarantees that cove never produces this error, but it could 
+                    // still be constructed by hand; the closest value is exact for valid values
+                    #[allow(clippy::let_unit_value)]
+                    let () = Self::_PROOF;
+                    self.closest()
+                }
+            }
+        )*
+
+        $(
+            impl<const MIN: i128, const MAX: i128> CastImpl<$nonzero> for Ranged<$int, MIN, MAX> {
+                type Error = FailedCastError<Self, $nonzero>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$nonzero, Self::Error> {
+                    self.get().cast::<$nonzero>().map_err(|_error| FailedCastError::new(self))
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> Closest<$nonzero>
+            for FailedCastError<Ranged<$int, MIN, MAX>, $nonzero> {
+                #[inline]
+                fn closest(self) -> $nonzero {
+                    self.from.get().cast::<$nonzero>().closest()
+                }
+            }
+
+            unsafe impl<const MIN: i128, const MAX: i128> Lossless<$nonzero>
+            for FailedCastError<Ranged<$int, MIN, MAX>, $nonzero> {
+                // Fail compilation unless the range proves the cast to be lossless
+                const _PROOF: () = Proof::<$nonzero, MIN, MAX>::INTO;
+
+                #[inline]
+                fn lossless(self) -> $nonzero {
+                    // The proof guarantees that cove never produces this error, but it could 
+                    // still be constructed by hand; the closest value is exact for valid values
+                    #[allow(clippy::let_unit_value)]
+                    let () = Self::_PROOF;
+                    self.closest()
+                }
+            }
+        )*
+    };
+
+    // Implements Cast, Closest and Lossless for $from -> Ranged<$int> via FailedCastError, where
+    // $from is a primitive or NonZero*. Closest clamps the closest value of the underlying
+    // primitive to the bounds of the range.
+    (from $int:ty => {$($from:ty),+}) => {
+        $(
+            impl<const MIN: i128, const MAX: i128> CastImpl<Ranged<$int, MIN, MAX>> for $from {
+                type Error = FailedCastError<Self, Ranged<$int, MIN, MAX>>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<Ranged<$int, MIN, MAX>, Self::Error> {
+                    self.cast::<$int>()
+                        .ok()
+                        .and_then(Ranged::<$int, MIN, MAX>::new)
+                        .ok_or_else(|| FailedCastError::new(self))
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> Closest<Ranged<$int, MIN, MAX>>
+            for FailedCastError<$from, Ranged<$int, MIN, MAX>> {
+                #[inline]
+                fn closest(self) -> Ranged<$int, MIN, MAX> {
+                    let value = self.from.cast::<$int>().closest();
+                    let min = Ranged::<$int, MIN, MAX>::MIN.get();
+                    let max = Ranged::<$int, MIN, MAX>::MAX.get();
+
+                    // This is safe because the value has been clamped to the range
+                    unsafe {Ranged::<$int, MIN, MAX>::new_unchecked(value.clamp(min, max))}
+                }
+            }
+
+            unsafe impl<const MIN: i128, const MAX: i128> Lossless<Ranged<$int, MIN, MAX>>
+            for FailedCastError<$from, Ranged<$int, MIN, MAX>> {
+                // Fail compilation unless the range proves the cast to be lossless
+                const _PROOF: () = Proof::<$from, MIN, MAX>::FROM;
+
+                #[inline]
+                fn lossless(self) -> Ranged<$int, MIN, MAX> {
+                    // The proof guarantees that cove never produces this error, but it could 
+                    // still be constructed by hand; the closest value is exact for valid values
+                    #[allow(clippy::let_unit_value)]
+                    let () = Self::_PROOF;
+                    self.closest()
+                }
+            }
+        )*
+    };
+
+    // Generates all implementations for each `$int`
+    ($($int:ty),+) => {
+        $(
+            ranged!(
+                into $int =>
+                {
+                    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+                };
+                {
+                    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+                    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+                }
+            );
+
+            ranged!(
+                from $int =>
+                {
+                    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64,
+                    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+                    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+                }
+            );
+        )*
+    }
+}
+
+ranged!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,424 @@
+//! This module provides implementations of the casting traits for the Ratio type
+
+use super::fixed::{absolute, power_of_two};
+use super::numeric::PARTS;
+use super::option::Plain;
+use super::wide;
+use super::wrappers::Rewrap;
+use crate::base::CastImpl;
+use crate::casts::{Cast, Closest, Lossy, PortableLossless};
+use crate::errors::{LosslessCastError, LossyCastError};
+use crate::types::Ratio;
+
+use core::cmp::Ordering;
+use core::fmt::Debug;
+
+impl<I> Cast for Ratio<I> {}
+
+// -- Arithmetic -- //
+/// Returns the full product of `a` and `b` as its high and low words, which compare as the product
+#[inline]
+pub(super) fn product(a: u128, b: u128) -> (u128, u128) {
+    let mask = u128::from(u64::MAX);
+    let (a_low, a_high, b_low, b_high) = (a & mask, a >> 64, b & mask, b >> 64);
+
+    let (middle, middle_carry) = (a_low * b_high).overflowing_add(a_high * b_low);
+    let (low, low_carry) = (a_low * b_low).overflowing_add(middle << 64);
+    let high = a_high * b_high + (middle >> 64) + (u128::from(middle_carry) << 64);
+    (high + u128::from(low_carry), low)
+}
+
+/// Divides the number with the given high and low words by `divisor`, returning the words of the
+/// quotient and the remainder
+#[inline]
+fn divide((high, low): (u128, u128), divisor: u64) -> ((u128, u128), u128) {
+    let (divisor, mask) = (u128::from(divisor), u128::from(u64::MAX));
+    let mut quotient = [0; 4];
+    let mut remainder = 0;
+
+    // Each step divides less than 2^64 times the divisor, so the digits of the quotient fit in 64
+    // bits and can be reassembled into words
+    for (digit, limb) in quotient.iter_mut().zip([high >> 64, high & mask, low >> 64, low & mask]) {
+        let current = (remainder << 64) | limb;
+        (*digit, remainder) = (current / divisor, current % divisor);
+    }
+
+    (((quotient[0] << 64) | quotient[1], (quotient[2] << 64) | quotient[3]), remainder)
+}
+
+/// Performs a step of binary long division by `denom` on `remainder`, which must be less than
+/// `denom`, returning the next bit of the quotient and the next remainder
+#[inline]
+fn next_bit(remainder: u128, denom: u128) -> (bool, u128) {
+    // The doubled remainder may exceed 128 bits, in which case it certainly exceeds the divisor
+    let (doubled, carry) = (remainder << 1, remainder >> 127 == 1);
+
+    match carry || doubled >= denom {
+        true => (true, doubled.wrapping_sub(denom)),
+        false => (false, doubled)
+    }
+}
+
+// -- Approximation -- //
+/// Finds the closest ratio to the magnitude of `value` whose numerator is at most `numer_bound`
+/// and whose denominator is at most `denom_bound`, with ties away from zero, returning the
+/// numerator and denominator in lowest terms along with whether they are exact; NaN yields zero
+/// and values out of range saturate
+///
+/// The float is exactly `mantissa / 2^shift`, whose continued fraction is expanded until the next
+/// convergent exceeds a bound. The closest ratio is then either the last convergent or the largest
+/// semiconvergent beyond it within bounds, since the two are adjacent in the Stern-Brocot tree and
+/// so every ratio between them exceeds a bound.
+fn approximate(value: f64, numer_bound: u128, denom_bound: u128) -> (u128, u128, bool) {
+    let bits = absolute(value).to_bits();
+    let (field, fraction) = (bits >> 52, bits & ((1 << 52) - 1));
+
+    let (mantissa, exponent) = match field {
+        _ if value == 0.0 => return (0, 1, true),
+        _ if value.is_nan() || numer_bound == 0 => return (0, 1, false),
+        0x7ff => return (numer_bound, 1, false),
+        0 => (fraction, -1074),
+        _ => (fraction | (1 << 52), i32::from(field as u16) - 1075)
+    };
+
+    // Reduce the mantissa to odd, so that the dyadic ratio is in lowest terms
+    let zeros = mantissa.trailing_zeros();
+    let (mantissa, exponent) = (u128::from(mantissa >> zeros), exponent + zeros as i32);
+    let length = 128 - mantissa.leading_zeros();
+
+    let shift = match u32::try_from(-exponent) {
+        // Integers fit only if their magnitude is within the numerator's bound
+        Err(_) => return match mantissa.checked_shl(exponent as u32) {
+            Some(integer) if exponent < mantissa.leading_zeros() as i32 => match integer {
+                integer if integer <= numer_bound => (integer, 1, true),
+                _ => (numer_bound, 1, false)
+            },
+            _ => (numer_bound, 1, false)
+        },
+
+        // Values below 2^-129 are closer to zero than to the smallest positive ratio
+        Ok(shift) if shift >= length + 129 => return (0, 1, false),
+        Ok(shift) => shift
+    };
+
+    let integral = mantissa.checked_shr(shift).unwrap_or(0);
+    if integral > numer_bound {
+        return (numer_bound, 1, false);
+    }
+
+    // The convergents h / k start from the integral part, and the complete quotient of the first
+    // step, 2^shift / remainder, may exceed 128 bits; every later one is below 2^53
+    let (mut h_previous, mut k_previous, mut h, mut k) = (1, 0, integral, 1);
+    let mut below = true;
+
+    let remainder = mantissa - (integral << shift.min(127));
+    if remainder == 0 {
+        return (integral, 1, true);
+    }
+
+    let power = match shift {
+        0 ..= 127 => (0, 1 << shift),
+        _ => (1 << (shift - 128), 0)
+    };
+
+    // The complete quotient is quotient + rest / divisor
+    #[allow(clippy::cast_possible_truncation)]
+    let (mut quotient, mut rest) = divide(power, remainder as u64);
+    let mut divisor = remainder;
+
+    loop {
+        let numer_limit = match h {
+            0 => u128::MAX,
+            h => (numer_bound - h_previous) / h
+        };
+
+        let limit = numer_limit.min((denom_bound - k_previous) / k);
+
+        if quotient.0 == 0 && quotient.1 <= limit {
+            let term = quotient.1;
+            (h_previous, k_previous, h, k) = (h, k, term * h + h_previous, term * k + k_previous);
+            below = !below;
+
+            if rest == 0 {
+                return (h, k, true);
+            }
+
+            (quotient, rest, divisor) = ((0, divisor / rest), divisor % rest, rest);
+            continue;
+        }
+
+        // The semiconvergent with the largest term is closer than the convergent exactly when the
+        // complete quotient is below twice the term plus the ratio of the previous denominator
+        let twice = (limit >> 127, limit << 1);
+        let order = match quotient.cmp(&twice) {
+            Ordering::Less => Ordering::Less,
+            Ordering::Equal => product(rest, k).cmp(&product(divisor, k_previous)),
+            Ordering::Greater if quotient == (twice.0, twice.1 | 1) => {
+                match rest == 0 && k == k_previous {
+                    true => Ordering::Equal,
+                    false => Ordering::Greater
+                }
+            },
+            Ordering::Greater => Ordering::Greater
+        };
+
+        // Ties favour the larger of the two, which is the semiconvergent if the convergent is below
+        let semiconvergent = (h_previous + limit * h, k_previous + limit * k);
+        return match order {
+            Ordering::Less => (semiconvergent.0, semiconvergent.1, false),
+            Ordering::Equal if below => (semiconvergent.0, semiconvergent.1, false),
+            _ => (h, k, false)
+        };
+    }
+}
+
+/// Rounds `numer / denom` to the nearest float with `precision` significant bits whose least
+/// significant bit is at least `2^min_exponent`, with ties to even, returning the result as an
+/// [`f64`] along with whether it is exact
+///
+/// Both integers must be nonzero; the quotient then lies within 2^±128, so every intermediate is a
+/// normal [`f64`].
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+#[allow(clippy::cast_precision_loss, clippy::cast_sign_loss)]
+pub(super) fn quotient(
+    numer: u128,
+    denom: u128,
+    precision: u32,
+    min_exponent: i32
+) -> (f64, bool) {
+    let (integral, mut remainder) = (numer / denom, numer % denom);
+
+    // Gather 64 significant bits of the quotient, collecting any others into a sticky bit
+    let (mut bits, mut exponent, mut sticky) = match 128 - integral.leading_zeros() {
+        length if length > 64 => {
+            let drop = length - 64;
+            (integral >> drop, drop as i32, integral & ((1 << drop) - 1) != 0)
+        },
+        _ => (integral, 0, false)
+    };
+
+    while bits < 1 << 63 {
+        let (bit, next) = next_bit(remainder, denom);
+        (bits, remainder, exponent) = ((bits << 1) | u128::from(bit), next, exponent - 1);
+    }
+
+    sticky |= remainder != 0;
+
+    // Round at the precision, or at the smallest exponent for subnormals
+    let lsb = (exponent + 64 - precision as i32).max(min_exponent);
+    let shift = (lsb - exponent) as u32;
+    let (kept, dropped, half) = (bits >> shift, bits & ((1 << shift) - 1), 1 << (shift - 1));
+    let up = dropped > half || (dropped == half && (sticky || kept & 1 == 1));
+
+    ((kept + u128::from(up)) as f64 * power_of_two(lsb), dropped == 0 && !sticky)
+}
+
+/// Splits the ratio with the given sign and magnitudes into parts as for `Numeric::parts`, which is
+/// exact for dyadic ratios with at most 128 fractional bits and truncated to those bits otherwise
+#[inline]
+pub(super) fn parts(negative: bool, numer: u128, denom: u128) -> [f64; PARTS] {
+    let (integral, mut remainder) = (numer / denom, numer % denom);
+    let mut fraction = 0;
+
+    for _ in 0 .. 128 {
+        let (bit, next) = next_bit(remainder, denom);
+        (fraction, remainder) = ((fraction << 1) | u128::from(bit), next);
+    }
+
+    // The value scaled by 2^128 is a 256-bit integer, whose parts are scaled back exactly
+    let words = match negative {
+        true => {
+            let (low, carry) = (!fraction).overflowing_add(1);
+            [low, (!integral).wrapping_add(u128::from(carry))]
+        },
+        false => [fraction, integral]
+    };
+
+    wide::parts(words, negative).map(|part| part * power_of_two(-128))
+}
+
+// -- Casts -- //
+macro_rules! ratio {
+    // Implements Cast and Closest for Ratio<$int> -> $to via LossyCastError, where $to is a
+    // primitive integer. Casts are performed via the integral part of the value.
+    (into $int:ty => integer $($to:ty),+) => {
+        $(
+            impl CastImpl<$to> for Ratio<$int> {
+                type Error = LossyCastError<Self, $to>;
+
+                #[inline]
+                #[allow(clippy::cast_possible_wrap)]
+                fn cast_impl(self) -> Result<$to, Self::Error> {
+                    let (negative, numer, denom) = self.to_sign_magnitude();
+                    let integral = numer / denom;
+
+                    // Negative magnitudes are at most 2^127, which wraps to i128::MIN
+                    let (fits, lossy) = match negative {
+                        true => {
+                            let result = (integral as i128).wrapping_neg().cast::<$to>();
+                            (result.is_ok(), result.lossy())
+                        },
+                        false => {
+                            let result = integral.cast::<$to>();
+                            (result.is_ok(), result.lossy())
+                        }
+                    };
+
+                    match fits && denom == 1 {
+                        true => Ok(lossy),
+                        false => Err(LossyCastError {from: self, to: lossy})
+                    }
+                }
+            }
+
+            impl Closest<$to> for LossyCastError<Ratio<$int>, $to> {
+                #[inline]
+                #[allow(clippy::cast_possible_wrap)]
+                fn closest(self) -> $to {
+                    let (negative, numer, denom) = self.from.to_sign_magnitude();
+                    let (integral, remainder) = (numer / denom, numer % denom);
+                    let nearest = integral + u128::from(remainder >= denom - remainder);
+
+                    match negative {
+                        true => (nearest as i128).wrapping_neg().cast::<$to>().closest(),
+                        false => nearest.cast::<$to>().closest()
+                    }
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and Closest for Ratio<$int> -> $to via LossyCastError, where $to is a
+    // primitive float. The quotient is rounded to nearest, which may overflow f32.
+    (into $int:ty => float $($to:ty),+) => {
+        $(
+            impl CastImpl<$to> for Ratio<$int> {
+                type Error = LossyCastError<Self, $to>;
+
+                #[inline]
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                fn cast_impl(self) -> Result<$to, Self::Error> {
+                    let (negative, numer, denom) = self.to_sign_magnitude();
+                    let (value, exact) = match numer {
+                        0 => (0.0, true),
+                        _ => quotient(
+                            numer,
+                            denom,
+                            <$to>::MANTISSA_DIGITS,
+                            <$to>::MIN_EXP - <$to>::MANTISSA_DIGITS as i32
+                        )
+                    };
+
+                    let value = match negative {
+                        true => -value as $to,
+                        false => value as $to
+                    };
+
+                    match exact {
+                        true => Ok(value),
+                        false => Err(LossyCastError {from: self, to: value})
+                    }
+                }
+            }
+
+            impl Closest<$to> for LossyCastError<Ratio<$int>, $to> {
+                #[inline]
+                fn closest(self) -> $to {
+                    self.to.clamp(<$to>::MIN, <$to>::MAX)
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and Closest for $from -> Ratio<$int> in terms of $from -> $int, where $from
+    // is a primitive integer, so that the cast is lossless exactly when that one is
+    (from $int:ty => integer $($from:ty),+) => {
+        $(
+            impl CastImpl<Ratio<$int>> for $from {
+                type Error = <
+                    <$from as CastImpl<$int>>::Error as Rewrap<$from, $int, Ratio<$int>>
+                >::Error;
+
+                #[inline]
+                fn cast_impl(self) -> Result<Ratio<$int>, Self::Error> {
+                    match CastImpl::<$int>::cast_impl(self) {
+                        Ok(value) => Ok(Ratio::<$int>::from_integer(value)),
+                        Err(error) => Err(error.rewrap(self, Ratio::<$int>::from_integer))
+                    }
+                }
+            }
+
+            impl Closest<Ratio<$int>> for LossyCastError<$from, Ratio<$int>> {
+                #[inline]
+                fn closest(self) -> Ratio<$int> {
+                    Ratio::<$int>::from_integer(self.from.cast::<$int>().closest())
+                }
+            }
+        )*
+
+        impl<T: Plain + Debug> PortableLossless<Ratio<$int>> for LosslessCastError<T, Ratio<$int>>
+        where LosslessCastError<T, $int>: PortableLossless<$int> {
+            #[inline]
+            fn portable_lossless(self) -> Ratio<$int> {
+                // This is safe because LosslessCastError cannot be instantiated
+                unsafe {core::hint::unreachable_unchecked()}
+            }
+        }
+    };
+
+    // Implements Cast and Closest for $from -> Ratio<$int> via LossyCastError, where $from is a
+    // primitive float. The lossy value is the closest ratio, so Closest yields it as is.
+    (from $int:ty => float $($from:ty),+) => {
+        $(
+            impl CastImpl<Ratio<$int>> for $from {
+                type Error = LossyCastError<Self, Ratio<$int>>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<Ratio<$int>, Self::Error> {
+                    let value = f64::from(self);
+                    let negative = value.is_sign_negative();
+                    let (numer, denom, exact) = approximate(
+                        value,
+                        Ratio::<$int>::numer_bound(negative),
+                        Ratio::<$int>::DENOM_BOUND
+                    );
+
+                    let ratio = Ratio::<$int>::from_sign_magnitude(negative, numer, denom);
+
+                    match exact {
+                        true => Ok(ratio),
+                        false => Err(LossyCastError {from: self, to: ratio})
+                    }
+                }
+            }
+
+            impl Closest<Ratio<$int>> for LossyCastError<$from, Ratio<$int>> {
+                #[inline]
+                fn closest(self) -> Ratio<$int> {
+                    self.to
+                }
+            }
+        )*
+    };
+
+    // Generates all implementations for each `$int`
+    ($($int:ty),+) => {
+        $(
+            ratio!(
+                into $int =>
+                integer u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+            );
+
+            ratio!(into $int => float f32, f64);
+
+            ratio!(
+                from $int =>
+                integer u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+            );
+
+            ratio!(from $int => float f32, f64);
+        )*
+    }
+}
+
+ratio!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,233 @@
+//! This module provides implementations of the casting traits for the refined float types
+//! Finite, NonNan, NonNegative and UnitInterval
+
+use super::fixed::power_of_two;
+use super::option::Plain;
+use super::wrappers::Rewrap;
+use crate::base::CastImpl;
+use crate::casts::{Cast, Closest, Lossy, PortableLossless};
+use crate::errors::{LosslessCastError, LossyCastError};
+use crate::types::{Finite, NonNan, NonNegative, UnitInterval};
+
+use core::fmt::Debug;
+
+// -- Casts -- //
+macro_rules! refined {
+    // Implements Cast and Closest for $refined<$float>, the latter for casts from it in terms of
+    // the casts from $float
+    (cast $refined:ident<$float:ty>) => {
+        impl Cast for $refined<$float> {}
+
+        impl<U> Closest<U> for LossyCastError<$refined<$float>, U>
+        where LossyCastError<$float, U>: Closest<U> {
+            #[inline]
+            fn closest(self) -> U {
+                LossyCastError {from: self.from.get(), to: self.to}.closest()
+            }
+        }
+    };
+
+    // Implements Cast for NonNegative<$float> -> $to via LossyCastError, where $to is a primitive
+    // unsigned integer. The value is neither NaN nor negative, so only the range and fraction are
+    // checked; the lossy value is that of the `as` keyword, as for the underlying primitive.
+    (from NonNegative<$float:ty> => unsigned $($to:ty),+) => {
+        $(
+            impl CastImpl<$to> for NonNegative<$float> {
+                type Error = LossyCastError<Self, $to>;
+
+                #[inline]
+                #[allow(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_possible_wrap,
+                    clippy::cast_precision_loss,
+                    clippy::cast_sign_loss,
+                    clippy::float_cmp
+                )]
+                fn cast_impl(self) -> Result<$to, Self::Error> {
+                    // Below 2^BITS the truncated value is exact as a float, and differs from the
+                    // value only if it has a fraction; infinity is out of range
+                    let truncated = self.get() as $to;
+                    let fits = f64::from(self.get()) < power_of_two(<$to>::BITS as i32);
+
+                    match fits && truncated as $float == self.get() {
+                        true => Ok(truncated),
+                        false => Err(LossyCastError {from: self, to: truncated})
+                    }
+                }
+            }
+        )*
+    };
+
+    // Implements Cast for $refined<$float> -> $to in terms of $float -> $to, so that the cast
+    // behaves exactly as for the underlying primitive
+    (from $refined:ident<$float:ty> => $($to:ty),+) => {
+        $(
+            impl CastImpl<$to> for $refined<$float> {
+                type Error = <<$float as CastImpl<$to>>::Error as Rewrap<Self, $to, $to>>::Error;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$to, Self::Error> {
+                    CastImpl::<$to>::cast_impl(self.get())
+                        .map_err(|error| error.rewrap(self, |to| to))
+                }
+            }
+        )*
+    };
+
+    // Implements PortableLossless for $refined<$float> -> $to, where the cast from $float is
+    // portably lossless
+    (portable $refined:ident<$float:ty> => $($to:ty),+) => {
+        $(
+            impl PortableLossless<$to> for LosslessCastError<$refined<$float>, $to> {
+                #[inline]
+                fn portable_lossless(self) -> $to {
+                    // This is safe because LosslessCastError cannot be instantiated
+                    unsafe {core::hint::unreachable_unchecked()}
+                }
+            }
+        )*
+    };
+
+    // Implements Cast, Closest and PortableLossless for $from -> $refined<$float> in terms of
+    // $from -> $float, where every value of $from which casts exactly is valid, so that the cast
+    // is lossless exactly when that one is. The lossy value is clamped to the nearest valid value.
+    (into $refined:ident<$float:ty> => exact $($from:ty),+) => {
+        $(
+            impl CastImpl<$refined<$float>> for $from {
+                type Error = <
+                    <$from as CastImpl<$float>>::Error as Rewrap<$from, $float, $refined<$float>>
+                >::Error;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$refined<$float>, Self::Error> {
+                    match CastImpl::<$float>::cast_impl(self) {
+                        // This is safe because the exact value of $from is valid
+                        Ok(value) => Ok(unsafe {$refined::<$float>::new_unchecked(value)}),
+                        Err(error) => Err(error.rewrap(self, $refined::<$float>::clamped))
+                    }
+                }
+            }
+
+            refined!(closest $refined<$float> => $from);
+        )*
+
+        impl<T: Plain + Debug> PortableLossless<$refined<$float>>
+        for LosslessCastError<T, $refined<$float>>
+        where LosslessCastError<T, $float>: PortableLossless<$float> {
+            #[inline]
+            fn portable_lossless(self) -> $refined<$float> {
+                // This is safe because LosslessCastError cannot be instantiated
+                unsafe {core::hint::unreachable_unchecked()}
+            }
+        }
+    };
+
+    // Implements Cast and Closest for $from -> $refined<$float> via LossyCastError, where $from has
+    // values which cast exactly to $float but are invalid. The lossy value is clamped to the
+    // nearest valid value, with NaN taken as zero.
+    (into $refined:ident<$float:ty> => checked $($from:ty),+) => {
+        $(
+            impl CastImpl<$refined<$float>> for $from {
+                type Error = LossyCastError<Self, $refined<$float>>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$refined<$float>, Self::Error> {
+                    let result = self.cast::<$float>();
+                    let (exact, lossy) = (result.is_ok(), result.lossy());
+
+                    match $refined::<$float>::new(lossy) {
+                        Some(value) if exact => Ok(value),
+                        _ => {
+                            let to = $refined::<$float>::clamped(lossy);
+                            Err(LossyCastError {from: self, to})
+                        }
+                    }
+                }
+            }
+
+            refined!(closest $refined<$float> => $from);
+        )*
+    };
+
+    // Implements Closest for $from -> $refined<$float> via LossyCastError, clamping the closest
+    // value of $from -> $float
+    (closest $refined:ident<$float:ty> => $from:ty) => {
+        impl Closest<$refined<$float>> for LossyCastError<$from, $refined<$float>> {
+            #[inline]
+            fn closest(self) -> $refined<$float> {
+                $refined::<$float>::clamped(self.from.cast::<$float>().closest())
+            }
+        }
+    };
+
+    // Implements Cast for $refined<$from> -> $refined<$to> in terms of $from -> $to, which is exact
+    // only for valid values; the lossy value is clamped to the nearest valid value. Closest is
+    // provided by the implementation for casts from $refined<$from>.
+    (between $refined:ident<$from:ty> => $to:ty) => {
+        impl CastImpl<$refined<$to>> for $refined<$from> {
+            type Error = <
+                <$from as CastImpl<$to>>::Error as Rewrap<Self, $to, $refined<$to>>
+            >::Error;
+
+            #[inline]
+            fn cast_impl(self) -> Result<$refined<$to>, Self::Error> {
+                match CastImpl::<$to>::cast_impl(self.get()) {
+                    // This is safe because the exact value of a valid value is valid
+                    Ok(value) => Ok(unsafe {$refined::<$to>::new_unchecked(value)}),
+                    Err(error) => Err(error.rewrap(self, $refined::<$to>::clamped))
+                }
+            }
+        }
+    };
+
+    // Generates the implementations common to every `$refined`, excluding those into it from
+    // integers, and those from NonNegative into unsigned integers
+    (common $refined:ident) => {
+        refined!(cast $refined<f32>);
+        refined!(cast $refined<f64>);
+        refined!(into $refined<f32> => checked f32, f64);
+        refined!(into $refined<f64> => checked f32, f64);
+        refined!(between $refined<f32> => f64);
+        refined!(between $refined<f64> => f32);
+        refined!(portable $refined<f32> => f32, f64, $refined<f64>);
+        refined!(portable $refined<f64> => f64);
+    };
+
+    // Generates the implementations from `$refined` into each primitive
+    (from $refined:ident => $($to:ty),+) => {
+        refined!(from $refined<f32> => $($to),+);
+        refined!(from $refined<f64> => $($to),+);
+    };
+
+    // Generates the implementations into `$refined` from each primitive integer
+    (into $refined:ident => $kind:ident $($from:ty),+) => {
+        refined!(into $refined<f32> => $kind $($from),+);
+        refined!(into $refined<f64> => $kind $($from),+);
+    };
+}
+
+refined!(common Finite);
+refined!(from Finite => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+refined!(into Finite => exact u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+refined!(common NonNan);
+refined!(from NonNan => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+refined!(into NonNan => exact u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+refined!(common NonNegative);
+refined!(from NonNegative => i8, i16, i32, i64, i128, isize, f32, f64);
+refined!(from NonNegative<f32> => unsigned u8, u16, u32, u64, u128, usize);
+refined!(from NonNegative<f64> => unsigned u8, u16, u32, u64, u128, usize);
+refined!(into NonNegative => exact u8, u16, u32, u64, u128, usize);
+refined!(into NonNegative => checked i8, i16, i32, i64, i128, isize);
+
+refined!(common UnitInterval);
+refined!(
+    from UnitInterval =>
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+refined!(
+    into UnitInterval =>
+    checked u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
@@ -0,0 +1,612 @@
+//! This module provides implementations of the casting traits for cove's 256-bit integer types
+
+use super::numeric::PARTS;
+use super::primitives::unit_interval;
+use crate::base::{CastImpl, CastSplitImpl};
+use crate::casts::{
+    Cast, Closest, PortableLossless, Rounded, RoundingMode, Stochastic, StochasticRng, Wrapping
+};
+use crate::errors::{FailedCastError, LosslessCastError, LossyCastError};
+use crate::types::{i256, u256};
+
+use core::num::{
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+};
+
+impl Cast for u256 {}
+impl Cast for i256 {}
+
+// -- Words -- //
+/// Helper trait for converting integers to 256-bit two's complement words, least significant
+/// first; the words together with the sign identify the value uniquely
+trait ToWords: Copy {
+    /// Returns the value as 256-bit two's complement words, least significant first
+    fn words(self) -> [u128; 2];
+
+    /// Returns whether the value is negative
+    fn is_negative(self) -> bool;
+}
+
+/// Helper trait for creating integers from 256-bit two's complement words by truncation, which is
+/// the same as the `as` keyword for the primitive integers
+trait FromWords: ToWords + PartialEq {
+    /// The smallest value of the type
+    const MIN: Self;
+
+    /// The largest value of the type
+    const MAX: Self;
+
+    /// Creates a value from the least significant bits of `words`, reinterpreting the sign
+    fn truncated(words: [u128; 2]) -> Self;
+}
+
+macro_rules! words {
+    (unsigned $($int:ty),+) => {
+        $(
+            impl ToWords for $int {
+                #[inline]
+                #[allow(clippy::cast_lossless)]
+                fn words(self) -> [u128; 2] {
+                    [self as u128, 0]
+                }
+
+                #[inline]
+                fn is_negative(self) -> bool {
+                    false
+                }
+            }
+
+            words!(truncated $int);
+        )*
+    };
+
+    (signed $($int:ty),+) => {
+        $(
+            impl ToWords for $int {
+                #[inline]
+                #[allow(clippy::cast_lossless, clippy::cast_sign_loss)]
+                fn words(self) -> [u128; 2] {
+                    // Sign extend into the most significant word
+                    let value = self as i128;
+                    [value as u128, (value >> 127) as u128]
+                }
+
+                #[inline]
+                fn is_negative(self) -> bool {
+                    self < 0
+                }
+            }
+
+            words!(truncated $int);
+        )*
+    };
+
+    (truncated $int:ty) => {
+        impl FromWords for $int {
+            const MIN: Self = <$int>::MIN;
+            const MAX: Self = <$int>::MAX;
+
+            #[inline]
+            #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+            fn truncated(words: [u128; 2]) -> Self {
+                words[0] as $int
+            }
+        }
+    };
+
+    // NonZero* values convert as their underlying primitive
+    (nonzero $($nonzero:ty),+) => {
+        $(
+            impl ToWords for $nonzero {
+                #[inline]
+                fn words(self) -> [u128; 2] {
+                    self.get().words()
+                }
+
+                #[inline]
+                fn is_negative(self) -> bool {
+                    self.get().is_negative()
+                }
+            }
+        )*
+    };
+
+    // The words of u256 and i256 are their own bits; only the sign differs
+    (wide $($wide:ty: $negative:expr),+) => {
+        $(
+            impl ToWords for $wide {
+                #[inline]
+                fn words(self) -> [u128; 2] {
+                    self.to_words()
+                }
+
+                #[inline]
+                fn is_negative(self) -> bool {
+                    #[allow(clippy::redundant_closure_call)]
+                    ($negative)(self)
+                }
+            }
+
+            impl FromWords for $wide {
+                const MIN: Self = <$wide>::MIN;
+                const MAX: Self = <$wide>::MAX;
+
+                #[inline]
+                fn truncated(words: [u128; 2]) -> Self {
+                    <$wide>::from_words(words)
+                }
+            }
+        )*
+    }
+}
+
+words!(unsigned u8, u16, u32, u64, u128, usize);
+words!(signed i8, i16, i32, i64, i128, isize);
+words!(wide u256: |_value| false, i256: i256::is_negative);
+
+words!(
+    nonzero
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+);
+
+/// Returns `a + b` modulo 2^256
+#[inline]
+fn add(a: [u128; 2], b: [u128; 2]) -> [u128; 2] {
+    let (low, carry) = a[0].overflowing_add(b[0]);
+    [low, a[1].wrapping_add(b[1]).wrapping_add(u128::from(carry))]
+}
+
+/// Returns `-words` modulo 2^256
+#[inline]
+fn negate(words: [u128; 2]) -> [u128; 2] {
+    add([!words[0], !words[1]], [1, 0])
+}
+
+/// Returns `value + 1`, or `value` if it is the largest value of its type
+#[inline]
+fn saturating_increment<T: FromWords>(value: T) -> T {
+    match value == T::MAX {
+        true => value,
+        false => T::truncated(add(value.words(), [1, 0]))
+    }
+}
+
+/// Returns `value - 1`, or `value` if it is the smallest value of its type
+#[inline]
+fn saturating_decrement<T: FromWords>(value: T) -> T {
+    match value == T::MIN {
+        true => value,
+        false => T::truncated(add(value.words(), [u128::MAX, u128::MAX]))
+    }
+}
+
+// -- Float Conversions -- //
+/// Truncates `value` towards zero and reduces the result modulo 2^256 in two's complement; NaN and
+/// infinities yield 0
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+fn float_modulo(value: f64) -> [u128; 2] {
+    let bits = value.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i32;
+    let mantissa = u128::from((bits & ((1 << 52) - 1)) | (1 << 52));
+
+    let magnitude = match exponent {
+        // A zero exponent implies a subnormal, which always truncates to 0, while a max exponent
+        // indicates infinity or NaN, neither of which has a remainder
+        0 | 0x7ff => [0, 0],
+
+        // The value is the mantissa (with its implicit leading 1) scaled by a power of two; shift
+        // it into place, discarding any bits beyond either end
+        exponent => match exponent - 1075 {
+            shift if shift < 0 => [mantissa.checked_shr(shift.unsigned_abs()).unwrap_or(0), 0],
+            shift if shift < 128 => [
+                mantissa << shift,
+                mantissa.checked_shr(128 - shift as u32).unwrap_or(0)
+            ],
+            shift if shift < 256 => [0, mantissa << (shift - 128)],
+            _ => [0, 0]
+        }
+    };
+
+    match value.is_sign_negative() {
+        true => negate(magnitude),
+        false => magnitude
+    }
+}
+
+/// Reduces the magnitude of the integer with the given words and sign to at most 128 bits by
+/// shifting it right, returning the shifted magnitude and the shift
+///
+/// Any nonzero bits shifted out are collected into the least significant bit, which rounds the
+/// magnitude to odd. Since 128 bits exceeds the precision of any primitive float by more than two
+/// bits, converting the result to a float and scaling it back by the shift yields the same value
+/// as rounding the original magnitude directly.
+#[inline]
+fn reduce(words: [u128; 2], negative: bool) -> (u128, u32) {
+    let [low, high] = match negative {
+        true => negate(words),
+        false => words
+    };
+
+    match high.leading_zeros() {
+        128 => (low, 0),
+        zeros => {
+            let shift = 128 - zeros;
+            let top = (high << zeros) | low.checked_shr(shift).unwrap_or(0);
+            (top | u128::from(low << zeros != 0), shift)
+        }
+    }
+}
+
+macro_rules! nearest {
+    ($($name:ident: $float:ty),+) => {
+        $(
+            /// Returns the nearest float to the integer with the given words and sign, with ties to
+            /// even; values beyond the range of the float yield infinity
+            #[inline]
+            #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+            #[allow(clippy::unnecessary_cast, clippy::cast_lossless)]
+            fn $name(words: [u128; 2], negative: bool) -> $float {
+                // Scaling by a power of two is exact in f64, and the result is exactly a float of
+                // the target precision, so only overflow remains to be rounded on the way back
+                let (top, shift) = reduce(words, negative);
+                let scale = f64::from_bits(u64::from(1023 + shift) << 52);
+                let magnitude = ((top as $float) as f64 * scale) as $float;
+
+                match negative {
+                    true => -magnitude,
+                    false => magnitude
+                }
+            }
+        )*
+    }
+}
+
+nearest!(nearest_f32: f32, nearest_f64: f64);
+
+/// Splits the integer with the given words and sign into non-overlapping [`f64`] parts which sum
+/// to exactly the value, in order of decreasing magnitude, as for
+/// [`Numeric::parts`](super::numeric::Numeric::parts)
+///
+/// Each remainder is computed modulo 2^256, so that a nearest f64 just beyond the range is handled;
+/// remainders are always small enough to be interpreted as signed.
+#[inline]
+#[allow(clippy::cast_possible_wrap)]
+pub(super) fn parts(words: [u128; 2], negative: bool) -> [f64; PARTS] {
+    let (mut remainder, mut negative) = (words, negative);
+    let mut parts = [0.0; PARTS];
+
+    for part in &mut parts {
+        *part = nearest_f64(remainder, negative);
+        remainder = add(remainder, negate(float_modulo(*part)));
+        negative = (remainder[1] as i128) < 0;
+    }
+
+    parts
+}
+
+/// Truncates `value` towards zero, saturating to the bounds of `T` as for the `as` keyword; `lower`
+/// and `upper` are the smallest value of `T` and the power of two above its largest value
+#[inline]
+fn saturated<T: FromWords>(value: f64, lower: f64, upper: f64) -> T {
+    match value {
+        value if value >= upper => T::MAX,
+        value if value < lower => T::MIN,
+        value => T::truncated(float_modulo(value))
+    }
+}
+
+// -- Casts -- //
+macro_rules! wide {
+    // Implements Cast, Closest and Wrapping for $from -> $to via LossyCastError, where at least
+    // one of $from and $to is a 256-bit integer. The lossy value is the same as for the `as`
+    // keyword, and the cast is lossless if and only if it preserves both the words and the sign.
+    (integer $from:ty => $($to:ty),+) => {
+        $(
+            impl CastImpl<$to> for $from {
+                type Error = LossyCastError<Self, $to>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$to, Self::Error> {
+                    let to = <$to as FromWords>::truncated(self.words());
+                    match to.words() == self.words() && to.is_negative() == self.is_negative() {
+                        true => Ok(to),
+                        false => Err(LossyCastError {from: self, to})
+                    }
+                }
+            }
+
+            impl Closest<$to> for LossyCastError<$from, $to> {
+                #[inline]
+                fn closest(self) -> $to {
+                    match self.from.is_negative() {
+                        true => <$to>::MIN,
+                        false => <$to>::MAX
+                    }
+                }
+            }
+
+            impl Wrapping<$to> for LossyCastError<$from, $to> {
+                #[inline]
+                fn wrapping(self) -> $to {
+                    <$to as FromWords>::truncated(self.from.words())
+                }
+            }
+        )*
+    };
+
+    // Implements Cast, PortableLossless and Wrapping for $from -> $to via LosslessCastError,
+    // where $to is a 256-bit integer whose range includes that of $from
+    (lossless $from:ty => $($to:ty),+) => {
+        $(
+            impl CastImpl<$to> for $from {
+                type Error = LosslessCastError<Self, $to>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$to, Self::Error> {
+                    Ok(<$to>::from_words(self.words()))
+                }
+            }
+
+            impl PortableLossless<$to> for LosslessCastError<$from, $to> {
+                #[inline]
+                fn portable_lossless(self) -> $to {
+                    // This is safe because LosslessCastError cannot be instantiated
+                    unsafe {core::hint::unreachable_unchecked()}
+                }
+            }
+
+            impl Wrapping<$to> for LosslessCastError<$from, $to> {
+                #[inline]
+                fn wrapping(self) -> $to {
+                    // This is safe because LosslessCastError cannot be instantiated
+                    unsafe {core::hint::unreachable_unchecked()}
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and Closest for $wide -> $nonzero via FailedCastError, in terms of the
+    // casts to the underlying primitive
+    (nonzero $wide:ty => $($nonzero:ty),+) => {
+        $(
+            impl CastImpl<$nonzero> for $wide {
+                type Error = FailedCastError<Self, $nonzero>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$nonzero, Self::Error> {
+                    // Cast to the root primitive of the nonzero before creating the nonzero
+                    let primitive = self.cast().map_err(|_error| FailedCastError::new(self))?;
+                    <$nonzero>::new(primitive).ok_or_else(|| FailedCastError::new(self))
+                }
+            }
+
+            impl Closest<$nonzero> for FailedCastError<$wide, $nonzero> {
+                #[inline]
+                fn closest(self) -> $nonzero {
+                    // Create the NonZero from the closest primitive, using a value of 1 if 0
+                    <$nonzero>::new(self.from.cast().closest())
+                        .unwrap_or_else(|| unsafe {<$nonzero>::new_unchecked(1)})
+                }
+            }
+        )*
+    };
+
+    // Implements Cast and Closest for $wide -> $float via LossyCastError; the cast is lossless if
+    // and only if the nearest float truncates back to the same words
+    (to_float $wide:ty => $($float:ty as $nearest:ident),+) => {
+        $(
+            impl CastImpl<$float> for $wide {
+                type Error = LossyCastError<Self, $float>;
+
+                #[inline]
+                fn cast_impl(self) -> Result<$float, Self::Error> {
+                    let to = $nearest(self.words(), self.is_negative());
+                    match float_modulo(f64::from(to)) == self.words() {
+                        true => Ok(to),
+                        false => Err(LossyCastError {from: self, to})
+                    }
+                }
+            }
+
+            impl Closest<$float> for LossyCastError<$wide, $float> {
+                #[inline]
+                fn closest(self) -> $float {
+                    // For int-to-float the raw cast is the closest except in the case of overflow
+                    match self.to {
+                        <$float>::INFINITY => <$float>::MAX,
+                        <$float>::NEG_INFINITY => <$float>::MIN,
+                        _ => self.to
+                    }
+                }
+            }
+        )*
+    };
+
+    // Implements Cast, CastSplit, Closest, Rounded, Stochastic and Wrapping for $float -> $wide
+    // via LossyCastError, where `$lower` is the smallest value of $wide and `$upper` is the power
+    // of two above its largest value. Values are handled as f64, which is exact for f32.
+    (from_float $float:ty => $($wide:ty: $lower:expr, $upper:expr),+) => {
+        $(
+            impl CastImpl<$wide> for $float {
+                type Error = LossyCastError<Self, $wide>;
+
+                #[inline]
+                #[allow(clippy::float_cmp)]
+                fn cast_impl(self) -> Result<$wide, Self::Error> {
+                    // Within range the truncated value is exactly representable as a float, so it
+                    // is equal to the origin value if and only if that is an integer
+                    let value = f64::from(self);
+                    let to = saturated::<$wide>(value, $lower, $upper);
+                    let integral = nearest_f64(to.words(), to.is_negative()) == value;
+
+                    match integral && value >= $lower && value < $upper {
+                        true => Ok(to),
+                        false => Err(LossyCastError {from: self, to})
+                    }
+                }
+            }
+
+            impl CastSplitImpl<$wide> for $float {
+                type Error = LossyCastError<Self, $wide>;
+
+                #[inline]
+                #[allow(clippy::cast_possible_truncation, clippy::unnecessary_cast)]
+                fn cast_split_impl(self) -> Result<($wide, Self), Self::Error> {
+                    // The integral part is representable if the value is finite and lies strictly
+                    // between MIN - 1 and MAX + 1; MIN - 1 may round to MIN, in which case the
+                    // bound itself must be accepted explicitly
+                    let value = f64::from(self);
+                    let above_min = value >= $lower || value > $lower - 1.0;
+                    let integral = saturated::<$wide>(value, $lower, $upper);
+
+                    match value.is_finite() && above_min && value < $upper {
+                        // Within the range the integral part is exactly representable as a float,
+                        // so the remainder is exact and representable by $float
+                        true => {
+                            let truncated = nearest_f64(integral.words(), integral.is_negative());
+                            Ok((integral, (value - truncated) as $float))
+                        },
+
+                        false => Err(LossyCastError {from: self, to: integral})
+                    }
+                }
+            }
+
+            impl Closest<$wide> for LossyCastError<$float, $wide> {
+                #[inline]
+                fn closest(self) -> $wide {
+                    self.rounded(RoundingMode::HalfAwayFromZero)
+                }
+            }
+
+            impl Rounded<$wide> for LossyCastError<$float, $wide> {
+                #[inline]
+                #[allow(clippy::float_cmp)]
+                fn rounded(self, mode: RoundingMode) -> $wide {
+                    // The truncation saturates. Within the range the truncated value is exactly
+                    // representable as a float, so the fraction is exact; outside the range the
+                    // truncated value is already MAX or MIN and the saturating adjustments below
+                    // leave it there. NaN yields a NaN fraction, which fails every comparison and
+                    // so leaves the 0 from the truncation.
+                    let value = f64::from(self.from);
+                    let truncated = saturated::<$wide>(value, $lower, $upper);
+                    let fraction = value - nearest_f64(truncated.words(), truncated.is_negative());
+                    let odd = truncated.words()[0] & 1 == 1;
+
+                    let up = match mode {
+                        RoundingMode::Floor | RoundingMode::TowardZero => false,
+                        RoundingMode::Ceil => fraction > 0.0,
+                        RoundingMode::HalfAwayFromZero => fraction >= 0.5,
+                        RoundingMode::HalfEven => fraction > 0.5 || (fraction == 0.5 && odd)
+                    };
+
+                    let down = match mode {
+                        RoundingMode::Ceil | RoundingMode::TowardZero => false,
+                        RoundingMode::Floor => fraction < 0.0,
+                        RoundingMode::HalfAwayFromZero => fraction <= -0.5,
+                        RoundingMode::HalfEven => fraction < -0.5 || (fraction == -0.5 && odd)
+                    };
+
+                    match (up, down) {
+                        (true, _) => saturating_increment(truncated),
+                        (_, true) => saturating_decrement(truncated),
+                        _ => truncated
+                    }
+                }
+            }
+
+            impl Stochastic<$wide> for LossyCastError<$float, $wide> {
+                #[inline]
+                fn stochastic<R: StochasticRng + ?Sized>(self, rng: &mut R) -> $wide {
+                    // As for Rounded, the fraction is exact within the range; rounding away from
+                    // the truncated value with probability equal to the magnitude of the fraction
+                    // makes the expected result equal to the origin value
+                    let value = f64::from(self.from);
+                    let truncated = saturated::<$wide>(value, $lower, $upper);
+                    let fraction = value - nearest_f64(truncated.words(), truncated.is_negative());
+
+                    match unit_interval(rng) {
+                        random if random < fraction => saturating_increment(truncated),
+                        random if random < -fraction => saturating_decrement(truncated),
+                        _ => truncated
+                    }
+                }
+            }
+
+            impl Wrapping<$wide> for LossyCastError<$float, $wide> {
+                #[inline]
+                fn wrapping(self) -> $wide {
+                    <$wide>::from_words(float_modulo(f64::from(self.from)))
+                }
+            }
+        )*
+    };
+
+    // Iteratively generate implementations for many sources
+    (integer $first:ty, $($from:ty),+ => $to:tt) => {
+        wide!(integer $first => $to);
+        $(wide!(integer $from => $to);)*
+    };
+
+    (lossless $first:ty, $($from:ty),+ => $to:tt) => {
+        wide!(lossless $first => $to);
+        $(wide!(lossless $from => $to);)*
+    };
+}
+
+// Into u256
+wide!(
+    lossless
+    u8, u16, u32, u64, u128, usize, u256,
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize
+    => u256
+);
+
+wide!(
+    integer
+    i8, i16, i32, i64, i128, isize, i256,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+    => u256
+);
+
+// Into i256
+wide!(
+    lossless
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, i256,
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+    => i256
+);
+
+wide!(integer u256 => i256);
+
+// Out of u256 and i256
+wide!(integer u256 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+wide!(integer i256 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+wide!(
+    nonzero u256 =>
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+);
+
+wide!(
+    nonzero i256 =>
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+);
+
+// Floats
+wide!(to_float u256 => f32 as nearest_f32, f64 as nearest_f64);
+wide!(to_float i256 => f32 as nearest_f32, f64 as nearest_f64);
+
+wide!(from_float f32 => u256: 0.0, TWO_256, i256: -TWO_255, TWO_255);
+wide!(from_float f64 => u256: 0.0, TWO_256, i256: -TWO_255, TWO_255);
+
+/// 2^255 as an [`f64`]
+const TWO_255: f64 = 5.789_604_461_865_81e76;
+
+/// 2^256 as an [`f64`]
+const TWO_256: f64 = 2.0 * TWO_255;
@@ -0,0 +1,30 @@
+//! This module provides implementations of the Within trait
+
+use super::fixed::absolute;
+use super::numeric::Numeric;
+use crate::casts::{Tolerance, Within};
+use crate::errors::LossyCastError;
+
+// -- Within -- //
+impl<CastFrom: Numeric, CastTo: Numeric> Within<CastTo> for LossyCastError<CastFrom, CastTo> {
+    type Error = Self;
+
+    #[inline]
+    #[allow(clippy::cast_precision_loss)]
+    fn within(self, tolerance: Tolerance) -> Result<CastTo, Self::Error> {
+        let difference = self.loss().abs();
+
+        // Infinite and NaN differences are never accepted; comparisons involving NaN are false, so
+        // NaN tolerances are rejected too
+        let accepted = difference.to_f64().is_finite() && match tolerance {
+            Tolerance::Absolute(bound) => difference <= bound,
+            Tolerance::Relative(bound) => difference <= bound * absolute(self.from.parts()[0]),
+            Tolerance::Ulps(bound) => difference <= bound as f64 * self.to.ulp()
+        };
+
+        match accepted {
+            true => Ok(self.to),
+            false => Err(self)
+        }
+    }
+}
@@ -0,0 +1,211 @@
+//! This module provides implementations of the casting traits for the Wrapping and Saturating
+//! wrappers defined in core::num, which cast exactly as the types they wrap
+
+use crate::base::CastImpl;
+use crate::casts::{Bitwise, Cast, Closest, Lossless};
+use crate::errors::{FailedCastError, LosslessCastError, LossyCastError};
+use core::num;
+
+// -- Rewrap -- //
+/// Helper trait for adapting the error of a cast between the wrapped types to the corresponding
+/// cast involving the wrappers, preserving the kind of error
+///
+/// This is public only so that it may appear in the bounds of public implementations; it is not
+/// reachable from outside of the crate.
+pub trait Rewrap<CastFrom, Inner, CastTo> {
+    /// The adapted error type
+    type Error;
+
+    /// Adapts the error to originate from `from`, wrapping its lossy value (if any) with `wrap`
+    fn rewrap(self, from: CastFrom, wrap: fn(Inner) -> CastTo) -> Self::Error;
+}
+
+impl<T, U, CastFrom, CastTo> Rewrap<CastFrom, U, CastTo> for LosslessCastError<T, U> {
+    type Error = LosslessCastError<CastFrom, CastTo>;
+
+    #[inline]
+    fn rewrap(self, _from: CastFrom, _wrap: fn(U) -> CastTo) -> Self::Error {
+        // This is safe because LosslessCastError cannot be instantiated
+        unsafe {core::hint::unreachable_unchecked()}
+    }
+}
+
+impl<T, U, CastFrom, CastTo> Rewrap<CastFrom, U, CastTo> for LossyCastError<T, U> {
+    type Error = LossyCastError<CastFrom, CastTo>;
+
+    #[inline]
+    fn rewrap(self, from: CastFrom, wrap: fn(U) -> CastTo) -> Self::Error {
+        LossyCastError {from, to: wrap(self.to)}
+    }
+}
+
+impl<T, U, CastFrom, CastTo> Rewrap<CastFrom, U, CastTo> for FailedCastError<T, U> {
+    type Error = FailedCastError<CastFrom, CastTo>;
+
+    #[inline]
+    fn rewrap(self, from: CastFrom, _wrap: fn(U) -> CastTo) -> Self::Error {
+        FailedCastError::new(from)
+    }
+}
+
+// -- Casts -- //
+macro_rules! wrappers {
+    // Implements Cast, Closest, Lossless and Bitwise for $wrapper<T> -> U in terms of T -> U. This
+    // covers casts between wrappers too, since T -> $other<U> is implemented below.
+    (from $wrapper:ident) => {
+        impl<T> Cast for num::$wrapper<T> {}
+
+        impl<T: Copy + CastImpl<U>, U> CastImpl<U> for num::$wrapper<T>
+        where T::Error: Rewrap<Self, U, U> {
+            type Error = <T::Error as Rewrap<Self, U, U>>::Error;
+
+            #[inline]
+            fn cast_impl(self) -> Result<U, Self::Error> {
+                self.0.cast_impl().map_err(|error| error.rewrap(self, |to| to))
+            }
+        }
+
+        impl<T, U> Closest<U> for LossyCastError<num::$wrapper<T>, U>
+        where LossyCastError<T, U>: Closest<U> {
+            #[inline]
+            fn closest(self) -> U {
+                LossyCastError {from: self.from.0, to: self.to}.closest()
+            }
+        }
+
+        impl<T, U> Closest<U> for FailedCastError<num::$wrapper<T>, U>
+        where FailedCastError<T, U>: Closest<U> {
+            #[inline]
+            fn closest(self) -> U {
+                FailedCastError::<T, U>::new(self.from.0).closest()
+            }
+        }
+
+        unsafe impl<T, U> Lossless<U> for LossyCastError<num::$wrapper<T>, U>
+        where LossyCastError<T, U>: Lossless<U> {
+            // Defer to the proof of the wrapped types, if any
+            const _PROOF: () = <LossyCastError<T, U> as Lossless<U>>::_PROOF;
+
+            #[inline]
+            fn lossless(self) -> U {
+                LossyCastError {from: self.from.0, to: self.to}.lossless()
+            }
+        }
+
+        impl<T, U> Bitwise<U> for Result<U, LossyCastError<num::$wrapper<T>, U>>
+        where Result<U, LossyCastError<T, U>>: Bitwise<U> {
+            #[inline]
+            fn bitwise(self) -> U {
+                self.map_err(|error| LossyCastError {from: error.from.0, to: error.to}).bitwise()
+            }
+        }
+
+        impl<T, U> Bitwise<U> for Result<U, LosslessCastError<num::$wrapper<T>, U>>
+        where Result<U, LosslessCastError<T, U>>: Bitwise<U> {
+            #[inline]
+            fn bitwise(self) -> U {
+                // This is safe because LosslessCastError cannot be instantiated
+                let unwrapped: Result<U, LosslessCastError<T, U>> =
+                    self.map_err(|_error| unsafe {core::hint::unreachable_unchecked()});
+
+                unwrapped.bitwise()
+            }
+        }
+    };
+
+    // Implements Cast, Closest, Lossless and Bitwise for $from -> $wrapper<U> in terms of
+    // $from -> U for each primitive U. Cast is implemented for each target individually, as a
+    // generic implementation would recurse endlessly while inferring the target of a cast.
+    (into $wrapper:ident, $from:ty => $($to:ty),+) => {
+        $(
+            impl CastImpl<num::$wrapper<$to>> for $from {
+                type Error = <
+                    <$from as CastImpl<$to>>::Error as Rewrap<$from, $to, num::$wrapper<$to>>
+                >::Error;
+
+                #[inline]
+                fn cast_impl(self) -> Result<num::$wrapper<$to>, Self::Error> {
+                    match CastImpl::<$to>::cast_impl(self) {
+                        Ok(value) => Ok(num::$wrapper(value)),
+                        Err(error) => Err(error.rewrap(self, num::$wrapper))
+                    }
+                }
+            }
+        )*
+
+        impl<U> Closest<num::$wrapper<U>> for LossyCastError<$from, num::$wrapper<U>>
+        where LossyCastError<$from, U>: Closest<U> {
+            #[inline]
+            fn closest(self) -> num::$wrapper<U> {
+                num::$wrapper(LossyCastError {from: self.from, to: self.to.0}.closest())
+            }
+        }
+
+        impl<U> Closest<num::$wrapper<U>> for FailedCastError<$from, num::$wrapper<U>>
+        where FailedCastError<$from, U>: Closest<U> {
+            #[inline]
+            fn closest(self) -> num::$wrapper<U> {
+                num::$wrapper(FailedCastError::<$from, U>::new(self.from).closest())
+            }
+        }
+
+        unsafe impl<U> Lossless<num::$wrapper<U>> for LossyCastError<$from, num::$wrapper<U>>
+        where LossyCastError<$from, U>: Lossless<U> {
+            // Defer to the proof of the wrapped types, if any
+            const _PROOF: () = <LossyCastError<$from, U> as Lossless<U>>::_PROOF;
+
+            #[inline]
+            fn lossless(self) -> num::$wrapper<U> {
+                num::$wrapper(LossyCastError {from: self.from, to: self.to.0}.lossless())
+            }
+        }
+
+        impl<U> Bitwise<num::$wrapper<U>>
+        for Result<num::$wrapper<U>, LossyCastError<$from, num::$wrapper<U>>>
+        where Result<U, LossyCastError<$from, U>>: Bitwise<U> {
+            #[inline]
+            fn bitwise(self) -> num::$wrapper<U> {
+                let unwrapped = self
+                    .map(|value| value.0)
+                    .map_err(|error| LossyCastError {from: error.from, to: error.to.0});
+
+                num::$wrapper(unwrapped.bitwise())
+            }
+        }
+
+        impl<U> Bitwise<num::$wrapper<U>>
+        for Result<num::$wrapper<U>, LosslessCastError<$from, num::$wrapper<U>>>
+        where Result<U, LosslessCastError<$from, U>>: Bitwise<U> {
+            #[inline]
+            fn bitwise(self) -> num::$wrapper<U> {
+                // This is safe because LosslessCastError cannot be instantiated
+                let unwrapped: Result<U, LosslessCastError<$from, U>> = self
+                    .map(|value| value.0)
+                    .map_err(|_error| unsafe {core::hint::unreachable_unchecked()});
+
+                num::$wrapper(unwrapped.bitwise())
+            }
+        }
+    };
+
+    // Generates all implementations for each `$wrapper`, from each primitive
+    ($($wrapper:ident),+) => {
+        $(
+            wrappers!(from $wrapper);
+            wrappers!(into $wrapper => u8, u16, u32, u64, u128, usize);
+            wrappers!(into $wrapper => i8, i16, i32, i64, i128, isize, f32, f64);
+        )*
+    };
+
+    // Generates the implementations into `$wrapper` of each primitive from each `$from`
+    (into $wrapper:ident => $($from:ty),+) => {
+        $(
+            wrappers!(
+                into $wrapper, $from =>
+                u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+            );
+        )*
+    }
+}
+
+wrappers!(Wrapping, Saturating);
@@ -0,0 +1,160 @@
+//! This module provides implementations of the Wrapping trait
+
+use crate::casts::Wrapping;
+use crate::errors::{LosslessCastError, LossyCastError};
+
+use core::num::{
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+};
+
+// -- Float Decomposition -- //
+macro_rules! float_modulo {
+    ($($name:ident: $float:ty as $int:ty),+) => {
+        $(
+            /// Truncates `value` towards zero and reduces the result modulo 2^128 in two's
+            /// complement; NaN and infinities yield 0. Since every primitive integer is at most 128
+            /// bits, a further `as` cast to the target reduces this modulo 2^N as required.
+            #[inline]
+            pub(super) fn $name(value: $float) -> u128 {
+                // Compute bit count constants for this floating point type
+                const TOTAL_BITS: u32 = core::mem::size_of::<$float>() as u32 * 8;
+                const SIGN_BITS: u32 = 1;
+                const MANTISSA_BITS: u32 = <$float>::MANTISSA_DIGITS - 1;
+                const EXPONENT_BITS: u32 = TOTAL_BITS - MANTISSA_BITS - SIGN_BITS;
+
+                // Compute mask constants for this floating point type
+                const MANTISSA_MASK: $int = <$int>::MAX >> (TOTAL_BITS - MANTISSA_BITS);
+                const EXPONENT_MASK: $int = <$int>::MAX >> (TOTAL_BITS - EXPONENT_BITS);
+                const EXPONENT_BIAS: $int = EXPONENT_MASK >> 1;
+
+                // Extract the exponent from the raw bits
+                let bits = value.to_bits();
+                let exponent = (bits >> MANTISSA_BITS) & EXPONENT_MASK;
+
+                let magnitude = match exponent {
+                    // A zero exponent implies a subnormal, which always truncates to 0
+                    0 => return 0,
+
+                    // A max exponent indicates infinity or NaN, neither of which has a remainder
+                    EXPONENT_MASK => return 0,
+
+                    // The value is the mantissa (with its implicit leading 1) scaled by a power of
+                    // two; shift it into place, discarding any bits beyond either end
+                    exponent => {
+                        let mantissa = u128::from((bits & MANTISSA_MASK) | (MANTISSA_MASK + 1));
+                        match exponent.checked_sub(EXPONENT_BIAS + MANTISSA_BITS as $int) {
+                            Some(shift) => mantissa.checked_shl(shift as u32).unwrap_or(0),
+                            None => {
+                                let shift = EXPONENT_BIAS + MANTISSA_BITS as $int - exponent;
+                                mantissa.checked_shr(shift as u32).unwrap_or(0)
+                            }
+                        }
+                    }
+                };
+
+                // Apply the sign via two's complement
+                match value.is_sign_negative() {
+                    true => magnitude.wrapping_neg(),
+                    false => magnitude
+                }
+            }
+        )*
+    }
+}
+
+float_modulo!(f32_modulo: f32 as u32, f64_modulo: f64 as u64);
+
+// -- Wrapping -- //
+macro_rules! wrapping {
+    // The actual implementations for primitive integer -> primitive integer
+    (integer $from:ty => {$($to:ty),+}) => {
+        $(
+            impl Wrapping<$to> for LossyCastError<$from, $to> {
+                #[inline]
+                fn wrapping(self) -> $to {
+                    // The `as` keyword is defined to reduce modulo 2^N between integers
+                    self.from as $to
+                }
+            }
+
+            impl Wrapping<$to> for LosslessCastError<$from, $to> {
+                #[inline]
+                fn wrapping(self) -> $to {
+                    // This is safe because LosslessCastError cannot be instantiated
+                    unsafe {core::hint::unreachable_unchecked()}
+                }
+            }
+        )*
+    };
+
+    // The actual implementations for nonzero -> primitive integer
+    (nonzero $from:ty => {$($to:ty),+}) => {
+        $(
+            impl Wrapping<$to> for LossyCastError<$from, $to> {
+                #[inline]
+                fn wrapping(self) -> $to {
+                    // The `as` keyword is defined to reduce modulo 2^N between integers
+                    self.from.get() as $to
+                }
+            }
+
+            impl Wrapping<$to> for LosslessCastError<$from, $to> {
+                #[inline]
+                fn wrapping(self) -> $to {
+                    // This is safe because LosslessCastError cannot be instantiated
+                    unsafe {core::hint::unreachable_unchecked()}
+                }
+            }
+        )*
+    };
+
+    // The actual implementations for float -> primitive integer; these casts are never lossless
+    // so there is no need to implement for LosslessCastError
+    (float $from:ty as $modulo:ident => {$($to:ty),+}) => {
+        $(
+            impl Wrapping<$to> for LossyCastError<$from, $to> {
+                #[inline]
+                fn wrapping(self) -> $to {
+                    $modulo(self.from) as $to
+                }
+            }
+        )*
+    };
+
+    // Iteratively generate implementations for primitive integer -> primitive integer
+    (integer $first:ty, $($from:ty),+ => $to:tt) => {
+        wrapping!(integer $first => $to);
+        $(wrapping!(integer $from => $to);)*
+    };
+
+    // Iteratively generate implementations for nonzero -> primitive integer
+    (nonzero $first:ty, $($from:ty),+ => $to:tt) => {
+        wrapping!(nonzero $first => $to);
+        $(wrapping!(nonzero $from => $to);)*
+    };
+
+    // Generate implementations in n-squared fashion for primitive integer -> primitive integer
+    (integer $($ty:ty),*) => {
+        wrapping!(integer $($ty),* => {$($ty),*});
+    }
+}
+
+wrapping!(integer u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+wrapping!(
+    nonzero
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+    => {u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize}
+);
+
+wrapping!(
+    float f32 as f32_modulo => 
+    {u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize}
+);
+
+wrapping!(
+    float f64 as f64_modulo => 
+    {u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize}
+);
@@ -0,0 +1,67 @@
+//! Provides casts which may be evaluated in const contexts
+//!
+//! Cove's casting traits cannot be used in const contexts, since Rust does not yet support calling
+//! trait methods from `const fn`. This module fills the gap for the primitive casts most commonly
+//! needed when building lookup tables and array lengths from values of mixed widths, via the
+//! associated functions of [`ConstCast`]:
+//! ```
+//! use cove::konst::ConstCast;
+//!
+//! const WIDTH: u32 = 48;
+//! const TABLE: [u8; ConstCast::<u32, usize>::unwrap(WIDTH)] = [0; 48];
+//!
+//! const LOSSY: u8 = ConstCast::<u16, u8>::lossy(300);
+//! const FITS: bool = ConstCast::<u16, u8>::cast(300).is_ok();
+//! # assert_eq!(TABLE.len(), 48);
+//! # assert_eq!(LOSSY, 44);
+//! # assert!(!FITS);
+//! ```
+//!
+//! # Support
+//! [`ConstCast`] supports all casts between primitive integers, from primitive integers to
+//! primitive floats, and from primitive floats to wider or equal floats. For each supported cast,
+//! [`ConstCast::cast`] reaches the same verdict as [`Cast::cast`](crate::casts::Cast::cast) and
+//! returns the same [`Result`] type, so casts involving [`usize`] / [`isize`] are lossless or lossy
+//! according to the target platform. The available associated functions mirror the follow-on
+//! extension traits:
+//!
+//! | Function                  | Supported Casts   | Behavior                                      |
+//! | ---                       | ---               | ---                                           |
+//! | [`ConstCast::cast`]       | all               | Returns the [`Result`] of the cast            |
+//! | [`ConstCast::lossy`]      | all               | Same as the `as` keyword                      |
+//! | [`ConstCast::unwrap`]     | all               | Panics if the cast is lossy                   |
+//! | [`ConstCast::lossless`]   | lossless by types | Only compiles if [`Lossless`](crate::casts::Lossless) does |
+//!
+//! When evaluated in a const context, a panic from [`ConstCast::unwrap`] is a compilation error:
+//! ```compile_fail
+//! use cove::konst::ConstCast;
+//!
+//! const VALUE: u8 = ConstCast::<u16, u8>::unwrap(300);
+//! ```
+
+use core::marker::PhantomData;
+
+/// Provides const-evaluable casts from `CastFrom` to `CastTo`
+///
+/// This type cannot be instantiated; it exists only to namespace its associated functions by the
+/// source and target types. See the [`module`](self) documentation for the supported casts.
+///
+/// # Examples
+/// ```
+/// use cove::konst::ConstCast;
+/// use cove::errors::LossyCastError;
+///
+/// const WORDS: [u16; 3] = [
+///     ConstCast::<u32, u16>::unwrap(0x10),
+///     ConstCast::<i64, u16>::lossy(-1),
+///     ConstCast::<u8, u16>::lossless(0xa0)
+/// ];
+///
+/// assert_eq!(WORDS, [0x10, 0xffff, 0xa0]);
+/// assert_eq!(
+///     ConstCast::<i32, u16>::cast(-1),
+///     Err(LossyCastError {from: -1i32, to: u16::MAX})
+/// );
+/// assert_eq!(ConstCast::<u64, f64>::cast(1 << 60), Ok(1_152_921_504_606_846_976f64));
+/// ```
+pub struct ConstCast<CastFrom, CastTo>(PhantomData<fn(CastFrom) -> CastTo>);
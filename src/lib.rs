@@ -85,7 +85,9 @@
 //! 
 //! * Read about how to use cove's [`casts`]
 //! * Read about generic [`bounds`] for cove's casts
+//! * Read about casting in const contexts with [`konst`]
 //! * Read about [`extending`](base) cove's casts to new types
+//! * Read about additional numerical [`types`] provided by cove
 //! * Read about the [`motivation`](docs::motivation) behind cove
 //! * Read about [`performance`](docs::performance) considerations when using cove
 //! * Read about [`testing`](docs::testing) considerations with cove
@@ -98,4 +100,8 @@ pub mod bounds;
 pub mod casts;
 pub mod docs;
 pub mod errors;
-pub mod prelude;
\ No newline at end of file
+pub mod konst;
+pub mod prelude;
+pub mod types;
+
+pub use types::{bf16, f128, f16};
\ No newline at end of file
@@ -7,4 +7,12 @@
 //!
 //! While it is possible to selectively import required objects, that can be needlessly verbose.
 
-pub use crate::casts::{AssumedLossless, Bitwise, Cast, Closest, Lossless, Lossy};
\ No newline at end of file
+pub use crate::casts::{
+    AssumedLossless, Bitwise, Cast, CastSplit, Closest, Lossless, Lossy, PortableLossless, Rounded,
+    Stochastic, Within, Wrapping
+};
+
+// The Wrapping trait shares its name with core::num::Wrapping, which is commonly imported alongside
+// the prelude; an explicit import of that type shadows the name above, so the trait is also
+// exported anonymously to keep its method in scope
+pub use crate::casts::Wrapping as _;
\ No newline at end of file
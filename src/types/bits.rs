@@ -0,0 +1,414 @@
+//! Provides the [`UInt`] and [`Int`] integer types of arbitrary bit width
+
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::Hash;
+use core::num::{
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+};
+
+/// An unsigned integer of `BITS` bits, for `BITS` from 1 through 128
+///
+/// [`UInt`] is intended for fields of protocols and hardware registers which are narrower than
+/// any primitive, such as a 3-bit opcode or a 24-bit length. It is stored in the smallest
+/// primitive with at least `BITS` bits, and its value is always within `0..=2^BITS - 1`.
+///
+/// # Casts
+/// [`UInt`] supports [`Cast`](crate::casts::Cast) to and from every primitive number, every member
+/// of the `NonZero*` family defined in [`core::num`], and every width of [`UInt`] and [`Int`].
+/// Casts between integers behave as between the primitive integers: they are lossy if the value
+/// is out of range, in which case the lossy value is truncated to the target width as for the
+/// `as` keyword, [`Closest`](crate::casts::Closest) saturates instead and
+/// [`Wrapping`](crate::casts::Wrapping) truncates. Casts from floats are lossy unless the value is
+/// an integer within range, and support [`Rounded`](crate::casts::Rounded) and
+/// [`Stochastic`](crate::casts::Stochastic) as for the primitive integers.
+///
+/// [`Lossless`](crate::casts::Lossless) is supported whenever the widths prove the cast lossless,
+/// such as `UInt<12>` → `UInt<16>`, [`u8`] → `Int<9>` or `UInt<24>` → [`f32`].
+/// [`Bitwise`](crate::casts::Bitwise) is supported between `UInt<BITS>` and `Int<BITS>`.
+///
+/// Use of [`Lossless`](crate::casts::Lossless) where the widths do not prove the cast lossless
+/// is a compilation error; note that this error only arises once the cast is instantiated, so it
+/// may be reported by `cargo build` but not by `cargo check`.
+///
+/// # Examples
+/// ```
+/// use cove::prelude::*;
+/// use cove::types::{Int, UInt};
+///
+/// // Casts into UInt check the range, and truncate or saturate as for the primitives
+/// assert_eq!(5u16.cast::<UInt<3>>()?.get(), 5u8);
+/// assert_eq!(12u16.cast::<UInt<3>>().unwrap_err().to.get(), 4u8);
+/// assert_eq!(12u16.cast::<UInt<3>>().closest(), UInt::<3>::MAX);
+/// assert_eq!(12u16.cast::<UInt<3>>().wrapping().get(), 4u8);
+///
+/// // Casts between widths are lossless where the widths allow
+/// let length = UInt::<24>::new(70_000).unwrap();
+/// assert_eq!(length.cast::<u32>().lossless(), 70_000u32);
+/// assert_eq!(length.cast::<Int<25>>().lossless().get(), 70_000i32);
+/// assert_eq!(length.cast::<UInt<12>>().closest(), UInt::<12>::MAX);
+///
+/// // Bitwise reinterprets the bits as signed
+/// assert_eq!(UInt::<3>::MAX.cast::<Int<3>>().bitwise().get(), -1i8);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// ```compile_fail
+/// use cove::prelude::*;
+/// use cove::types::UInt;
+///
+/// // UInt<9> could exceed u8, so this fails to compile
+/// let _ = UInt::<9>::new(5).unwrap().cast::<u8>().lossless();
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UInt<const BITS: u32>(<Bits<BITS> as Width>::Unsigned) where Bits<BITS>: Width;
+
+/// A signed integer of `BITS` bits in two's complement, for `BITS` from 1 through 128
+///
+/// [`Int`] is the signed counterpart to [`UInt`], for signed fields of protocols and hardware
+/// registers such as a 12-bit offset. It is stored in the smallest primitive with at least `BITS`
+/// bits, and its value is always within `-2^(BITS - 1)..=2^(BITS - 1) - 1`.
+///
+/// # Casts
+/// [`Int`] supports the same casts as [`UInt`], with the same behavior; in particular, casts which
+/// are lossy due to range truncate to the target width as for the `as` keyword, and
+/// [`Closest`](crate::casts::Closest) saturates instead. [`Lossless`](crate::casts::Lossless) is
+/// supported whenever the widths prove the cast lossless, such as `Int<12>` → [`i16`] or `Int<7>`
+/// → `Int<8>`. [`Bitwise`](crate::casts::Bitwise) is supported between `Int<BITS>` and
+/// `UInt<BITS>`.
+///
+/// # Examples
+/// ```
+/// use cove::prelude::*;
+/// use cove::types::{Int, UInt};
+///
+/// // Casts into Int check the range, and truncate or saturate as for the primitives
+/// assert_eq!((-2048i32).cast::<Int<12>>()?, Int::<12>::MIN);
+/// assert_eq!(2048i32.cast::<Int<12>>().unwrap_err().to, Int::<12>::MIN);
+/// assert_eq!(2048i32.cast::<Int<12>>().closest(), Int::<12>::MAX);
+/// assert_eq!(2.5f32.cast::<Int<12>>().closest().get(), 3i16);
+///
+/// // Casts out of Int are lossless where the widths allow
+/// let offset = Int::<12>::new(-5).unwrap();
+/// assert_eq!(offset.cast::<i16>().lossless(), -5i16);
+/// assert_eq!(offset.cast::<f32>().lossless(), -5f32);
+/// assert_eq!(offset.cast::<UInt<12>>().bitwise().get(), 4091u16);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Int<const BITS: u32>(<Bits<BITS> as Width>::Signed) where Bits<BITS>: Width;
+
+impl<const BITS: u32> UInt<BITS> where Bits<BITS>: Width {
+    /// The number of bits of this type
+    pub const BITS: u32 = BITS;
+
+    /// The smallest value of this type
+    pub const MIN: Self = Self(<Bits<BITS> as Width>::UNSIGNED_MIN);
+
+    /// The largest value of this type, `2^BITS - 1`
+    pub const MAX: Self = Self(<Bits<BITS> as Width>::UNSIGNED_MAX);
+
+    /// Creates a new value if `value` fits in `BITS` bits, or returns [`None`] otherwise
+    #[inline]
+    #[must_use]
+    pub fn new(value: <Bits<BITS> as Width>::Unsigned) -> Option<Self> {
+        match value <= Self::MAX.0 {
+            true => Some(Self(value)),
+            false => None
+        }
+    }
+
+    /// Creates a new value without checking that it fits in `BITS` bits
+    ///
+    /// # Safety
+    /// `value` must fit in `BITS` bits; cove's casts rely on this invariant, so violating it can
+    /// lead to undefined behavior.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn new_unchecked(value: <Bits<BITS> as Width>::Unsigned) -> Self {
+        Self(value)
+    }
+
+    /// Returns the value as its underlying primitive type
+    #[inline]
+    #[must_use]
+    pub fn get(self) -> <Bits<BITS> as Width>::Unsigned {
+        self.0
+    }
+
+    /// Returns the value as a [`u128`]
+    #[inline]
+    pub(crate) fn widen(self) -> u128 {
+        <Bits<BITS> as Width>::widen_unsigned(self.0)
+    }
+
+    /// Creates a value from the least significant `BITS` bits of `value`
+    #[inline]
+    pub(crate) fn truncate(value: u128) -> Self {
+        Self(<Bits<BITS> as Width>::narrow_unsigned(value & (u128::MAX >> (128 - BITS))))
+    }
+
+    /// Creates a value from `value`, saturating to [`UInt::MAX`] if it does not fit
+    #[inline]
+    pub(crate) fn saturate(value: u128) -> Self {
+        Self::truncate(value.min(Self::MAX.widen()))
+    }
+}
+
+impl<const BITS: u32> Int<BITS> where Bits<BITS>: Width {
+    /// The number of bits of this type
+    pub const BITS: u32 = BITS;
+
+    /// The smallest value of this type, `-2^(BITS - 1)`
+    pub const MIN: Self = Self(<Bits<BITS> as Width>::SIGNED_MIN);
+
+    /// The largest value of this type, `2^(BITS - 1) - 1`
+    pub const MAX: Self = Self(<Bits<BITS> as Width>::SIGNED_MAX);
+
+    /// Creates a new value if `value` fits in `BITS` bits, or returns [`None`] otherwise
+    #[inline]
+    #[must_use]
+    pub fn new(value: <Bits<BITS> as Width>::Signed) -> Option<Self> {
+        match value >= Self::MIN.0 && value <= Self::MAX.0 {
+            true => Some(Self(value)),
+            false => None
+        }
+    }
+
+    /// Creates a new value without checking that it fits in `BITS` bits
+    ///
+    /// # Safety
+    /// `value` must fit in `BITS` bits; cove's casts rely on this invariant, so violating it can
+    /// lead to undefined behavior.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn new_unchecked(value: <Bits<BITS> as Width>::Signed) -> Self {
+        Self(value)
+    }
+
+    /// Returns the value as its underlying primitive type
+    #[inline]
+    #[must_use]
+    pub fn get(self) -> <Bits<BITS> as Width>::Signed {
+        self.0
+    }
+
+    /// Returns the value as an [`i128`]
+    #[inline]
+    pub(crate) fn widen(self) -> i128 {
+        <Bits<BITS> as Width>::widen_signed(self.0)
+    }
+
+    /// Creates a value from the least significant `BITS` bits of `value`, reinterpreting the sign
+    #[inline]
+    pub(crate) fn truncate(value: i128) -> Self {
+        // Shift the sign bit of the width into place and back again to sign extend it
+        let shift = 128 - BITS;
+        Self(<Bits<BITS> as Width>::narrow_signed((value << shift) >> shift))
+    }
+
+    /// Creates a value from `value`, saturating to [`Int::MIN`] or [`Int::MAX`] if it does not fit
+    #[inline]
+    pub(crate) fn saturate(value: i128) -> Self {
+        Self::truncate(value.clamp(Self::MIN.widen(), Self::MAX.widen()))
+    }
+}
+
+impl<const BITS: u32> Display for UInt<BITS> where Bits<BITS>: Width {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, formatter)
+    }
+}
+
+impl<const BITS: u32> Display for Int<BITS> where Bits<BITS>: Width {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, formatter)
+    }
+}
+
+// -- Width -- //
+/// Helper type which identifies a bit width, so that [`Width`] can select the storage of [`UInt`]
+/// and [`Int`] for it
+///
+/// This is public only so that it may appear in the bounds of public items; it is not reachable
+/// from outside of the crate.
+#[derive(Copy, Clone, Debug)]
+pub struct Bits<const BITS: u32>;
+
+/// Helper trait selecting the primitive storage of [`UInt`] and [`Int`] for each supported width,
+/// and converting that storage to and from the 128-bit primitives
+///
+/// This is public only so that it may appear in the bounds of public items; it is not reachable
+/// from outside of the crate.
+pub trait Width {
+    /// The storage of [`UInt`] of this width
+    type Unsigned: Copy + Debug + Display + Eq + Ord + Hash;
+
+    /// The storage of [`Int`] of this width
+    type Signed: Copy + Debug + Display + Eq + Ord + Hash;
+
+    /// The smallest value of [`UInt`] of this width
+    const UNSIGNED_MIN: Self::Unsigned;
+
+    /// The largest value of [`UInt`] of this width
+    const UNSIGNED_MAX: Self::Unsigned;
+
+    /// The smallest value of [`Int`] of this width
+    const SIGNED_MIN: Self::Signed;
+
+    /// The largest value of [`Int`] of this width
+    const SIGNED_MAX: Self::Signed;
+
+    /// Converts `value` to the unsigned storage as for the `as` keyword
+    fn narrow_unsigned(value: u128) -> Self::Unsigned;
+
+    /// Converts `value` to the signed storage as for the `as` keyword
+    fn narrow_signed(value: i128) -> Self::Signed;
+
+    /// Converts `value` from the unsigned storage to [`u128`]
+    fn widen_unsigned(value: Self::Unsigned) -> u128;
+
+    /// Converts `value` from the signed storage to [`i128`]
+    fn widen_signed(value: Self::Signed) -> i128;
+}
+
+macro_rules! width {
+    ($unsigned:ty, $signed:ty: $($bits:literal),+) => {
+        $(
+            #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+            impl Width for Bits<$bits> {
+                type Unsigned = $unsigned;
+                type Signed = $signed;
+
+                const UNSIGNED_MIN: $unsigned = 0;
+                const UNSIGNED_MAX: $unsigned = (u128::MAX >> (128 - $bits)) as $unsigned;
+                const SIGNED_MIN: $signed = !Self::SIGNED_MAX;
+                const SIGNED_MAX: $signed = match u128::MAX.checked_shr(129 - $bits) {
+                    Some(max) => max as $signed,
+                    None => 0
+                };
+
+                #[inline]
+                fn narrow_unsigned(value: u128) -> $unsigned {
+                    value as $unsigned
+                }
+
+                #[inline]
+                fn narrow_signed(value: i128) -> $signed {
+                    value as $signed
+                }
+
+                #[inline]
+                fn widen_unsigned(value: $unsigned) -> u128 {
+                    value.into()
+                }
+
+                #[inline]
+                fn widen_signed(value: $signed) -> i128 {
+                    value.into()
+                }
+            }
+        )*
+    }
+}
+
+width!(u8, i8: 1, 2, 3, 4, 5, 6, 7, 8);
+width!(u16, i16: 9, 10, 11, 12, 13, 14, 15, 16);
+width!(
+    u32, i32:
+    17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32
+);
+
+width!(
+    u64, i64:
+    33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48,
+    49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64
+);
+
+width!(
+    u128, i128:
+    65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80,
+    81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96,
+    97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112,
+    113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125, 126, 127, 128
+);
+
+// -- BitRange -- //
+/// Helper trait describing the integers exactly representable by a type in terms of the widths of
+/// [`UInt`] and [`Int`], as used to prove casts involving them lossless
+///
+/// A cast is lossless if the source fits within some width which the target holds entirely.
+pub(crate) trait BitRange {
+    /// The smallest width of [`UInt`] which holds every value of the type, or [`u32::MAX`] if none
+    const UNSIGNED_FITS: u32;
+
+    /// The smallest width of [`Int`] which holds every value of the type, or [`u32::MAX`] if none
+    const SIGNED_FITS: u32;
+
+    /// The largest width of [`UInt`] whose every value is representable by the type, or 0 if none
+    const UNSIGNED_HOLDS: u32;
+
+    /// The largest width of [`Int`] whose every value is representable by the type, or 0 if none
+    const SIGNED_HOLDS: u32;
+}
+
+impl<const BITS: u32> BitRange for UInt<BITS> where Bits<BITS>: Width {
+    const UNSIGNED_FITS: u32 = BITS;
+    const SIGNED_FITS: u32 = BITS + 1;
+    const UNSIGNED_HOLDS: u32 = BITS;
+    const SIGNED_HOLDS: u32 = 0;
+}
+
+impl<const BITS: u32> BitRange for Int<BITS> where Bits<BITS>: Width {
+    const UNSIGNED_FITS: u32 = u32::MAX;
+    const SIGNED_FITS: u32 = BITS;
+    const UNSIGNED_HOLDS: u32 = BITS - 1;
+    const SIGNED_HOLDS: u32 = BITS;
+}
+
+macro_rules! bit_range {
+    ($($ty:ty: $unsigned_fits:expr, $signed_fits:expr, $unsigned_holds:expr, $signed_holds:expr);+)
+    => {
+        $(
+            impl BitRange for $ty {
+                const UNSIGNED_FITS: u32 = $unsigned_fits;
+                const SIGNED_FITS: u32 = $signed_fits;
+                const UNSIGNED_HOLDS: u32 = $unsigned_holds;
+                const SIGNED_HOLDS: u32 = $signed_holds;
+            }
+        )*
+    };
+}
+
+bit_range!(
+    u8: 8, 9, 8, 0;
+    u16: 16, 17, 16, 0;
+    u32: 32, 33, 32, 0;
+    u64: 64, 65, 64, 0;
+    u128: 128, 129, 128, 0;
+    usize: usize::BITS, usize::BITS + 1, usize::BITS, 0;
+    i8: u32::MAX, 8, 7, 8;
+    i16: u32::MAX, 16, 15, 16;
+    i32: u32::MAX, 32, 31, 32;
+    i64: u32::MAX, 64, 63, 64;
+    i128: u32::MAX, 128, 127, 128;
+    isize: u32::MAX, isize::BITS, isize::BITS - 1, isize::BITS;
+
+    // NonZero* values fit where their primitive does, but hold nothing since every width has zero
+    NonZeroU8: 8, 9, 0, 0;
+    NonZeroU16: 16, 17, 0, 0;
+    NonZeroU32: 32, 33, 0, 0;
+    NonZeroU64: 64, 65, 0, 0;
+    NonZeroU128: 128, 129, 0, 0;
+    NonZeroUsize: usize::BITS, usize::BITS + 1, 0, 0;
+    NonZeroI8: u32::MAX, 8, 0, 0;
+    NonZeroI16: u32::MAX, 16, 0, 0;
+    NonZeroI32: u32::MAX, 32, 0, 0;
+    NonZeroI64: u32::MAX, 64, 0, 0;
+    NonZeroI128: u32::MAX, 128, 0, 0;
+    NonZeroIsize: u32::MAX, isize::BITS, 0, 0;
+
+    // Floats hold every integer up to 2^MANTISSA_DIGITS in magnitude, but fit in no width
+    f32: u32::MAX, u32::MAX, f32::MANTISSA_DIGITS, f32::MANTISSA_DIGITS + 1;
+    f64: u32::MAX, u32::MAX, f64::MANTISSA_DIGITS, f64::MANTISSA_DIGITS + 1
+);
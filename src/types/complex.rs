@@ -0,0 +1,76 @@
+//! Provides the [`Complex`] complex number type
+
+use core::fmt::{Display, Formatter};
+
+/// A complex number with real and imaginary parts of type `T`, such as an IQ sample
+///
+/// [`Complex`] is a minimal pair of components for carrying complex values through cove's casts;
+/// it provides no arithmetic, and both components are accessible as public fields.
+///
+/// # Casts
+/// [`Complex<T>`] supports [`Cast`](crate::casts::Cast) to [`Complex<U>`] whenever `T` casts to
+/// `U`, casting each component separately. The cast is lossy if either component is, in which
+/// case the error keeps the lossy values of both components; the follow-on traits follow those of
+/// `T` → `U`, so that for example `Complex<i16>` → `Complex<f32>` supports
+/// [`Lossless`](crate::casts::Lossless) and [`Closest`](crate::casts::Closest) yields the closest
+/// value of each component.
+///
+/// The errors of these casts report the loss of each component through
+/// [`LossyCastError::kinds`](crate::errors::LossyCastError::kinds) and
+/// [`LossyCastError::losses`](crate::errors::LossyCastError::losses) when `T` and `U` are
+/// numerical types supported by cove, and
+/// [`LossyCastError::detailed`](crate::errors::LossyCastError::detailed) names the kind of loss
+/// of each component in its message.
+///
+/// # Examples
+/// ```
+/// use cove::prelude::*;
+/// use cove::errors::LossKind;
+/// use cove::types::Complex;
+///
+/// // IQ samples widen losslessly and narrow with detection of loss in either component
+/// let sample = Complex::new(-1200i16, 35);
+/// assert_eq!(sample.cast::<Complex<f32>>().lossless(), Complex::new(-1200.0, 35.0));
+/// assert_eq!(Complex::new(0.5f64, -0.25).cast::<Complex<f32>>()?, Complex::new(0.5, -0.25));
+///
+/// let error = Complex::new(40_000.0f64, 0.5).cast::<Complex<i16>>().unwrap_err();
+/// assert_eq!(error.to, Complex::new(i16::MAX, 0));
+/// assert_eq!(error.kinds(), Complex::new(Some(LossKind::Overflow), Some(LossKind::Fraction)));
+/// assert_eq!(error.closest(), Complex::new(i16::MAX, 1));
+///
+/// // Components which cast exactly are reported as such
+/// let error = Complex::new(0.1f64, 2.0).cast::<Complex<f32>>().unwrap_err();
+/// assert_eq!(error.kinds(), Complex::new(Some(LossKind::Precision), None));
+/// let message = error.detailed().to_string();
+/// assert!(message.starts_with("Numerical cast was lossy (re: precision, im: exact) [0.1+2i"));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Complex<T> {
+    /// The real part
+    pub re: T,
+
+    /// The imaginary part
+    pub im: T
+}
+
+impl<T> Complex<T> {
+    /// Creates a new value from its real and imaginary parts
+    #[inline]
+    #[must_use]
+    pub const fn new(re: T, im: T) -> Self {
+        Self {re, im}
+    }
+}
+
+impl<T: Display> Display for Complex<T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        // Both parts share the precision, if any, and the imaginary part always carries its sign
+        match formatter.precision() {
+            Some(precision) => {
+                write!(formatter, "{:.*}{:+.*}i", precision, self.re, precision, self.im)
+            },
+            None => write!(formatter, "{}{:+}i", self.re, self.im)
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! Provides the [`Decimal`] scaled decimal type
+
+use super::Wide;
+use core::fmt::{Display, Formatter};
+
+/// A decimal fixed-scale number stored in the primitive integer `I`, with `SCALE` decimal places
+///
+/// [`Decimal`] represents the value `bits / 10^SCALE` for the underlying integer `bits`, as for
+/// monetary amounts stored in their smallest unit: cents are `Decimal<i64, 2>`. `SCALE` may be
+/// anything from 0 through 38, so that `10^SCALE` fits in 128 bits; this is checked at compile
+/// time whenever a [`Decimal`] is constructed.
+///
+/// # Casts
+/// [`Decimal`] supports [`Cast`](crate::casts::Cast) to and from every primitive number and
+/// between any two scales, with detection of loss.
+///
+/// Casts to and from integers and between scales are exact unless the value has more decimal
+/// places than the target or is out of range, in which case the lossy value is truncated towards
+/// zero and wraps around as for the `as` keyword; [`Closest`](crate::casts::Closest) rounds to the
+/// nearest value with ties to even (banker's rounding) and saturates instead.
+///
+/// Floats cannot represent most decimals exactly, so a float and a [`Decimal`] are considered to be
+/// the same value whenever each is the nearest value of its type to the other; `12.34f64` is then
+/// exactly the [`Decimal`] `12.34`, as with the shortest representation of the float. Casts from
+/// floats are lossy if the float does not correspond to a [`Decimal`] in this way, in which case
+/// the lossy value is the exact value of the float truncated towards zero and saturated as for the
+/// `as` keyword, while [`Closest`](crate::casts::Closest) rounds it to nearest with ties to even.
+/// Casts to floats are lossy likewise, in which case the value is rounded to nearest.
+///
+/// [`Lossless`](crate::casts::Lossless) is supported between integers and decimals whenever their
+/// ranges and scales prove the cast lossless, such as [`i32`] → `Decimal<i64, 2>` or
+/// `Decimal<i32, 2>` → `Decimal<i64, 4>`. [`Bitwise`](crate::casts::Bitwise) is supported in both
+/// directions between `Decimal<I, SCALE>` and `I`, reinterpreting the underlying integer as the
+/// value or vice versa.
+///
+/// Use of [`Lossless`](crate::casts::Lossless) where the ranges and scales do not prove the cast
+/// lossless is a compilation error; note that this error only arises once the cast is
+/// instantiated, so it may be reported by `cargo build` but not by `cargo check`.
+///
+/// # Examples
+/// ```
+/// use cove::prelude::*;
+/// use cove::types::Decimal;
+///
+/// type Cents = Decimal<i64, 2>;
+///
+/// // Casts from floats detect values with too many decimal places
+/// assert_eq!(12.34f64.cast::<Cents>()?.to_bits(), 1234);
+/// assert!(12.345f64.cast::<Cents>().is_err());
+/// assert_eq!(0.125f64.cast::<Cents>().closest().to_bits(), 12);
+/// assert_eq!(Cents::from_bits(1234).cast::<f64>()?, 12.34);
+///
+/// // Casts between scales round with ties to even
+/// let value = Decimal::<i32, 3>::from_bits(2_345);
+/// assert_eq!(value.cast::<Cents>().unwrap_err().to.to_bits(), 234);
+/// assert_eq!(value.cast::<Cents>().closest().to_bits(), 234);
+/// assert_eq!(value.cast::<Decimal<i64, 4>>().lossless().to_bits(), 23_450);
+/// assert_eq!(value.to_string(), "2.345");
+///
+/// // Casts from integers detect overflow, and Bitwise reinterprets the underlying integer
+/// assert_eq!(25i32.cast::<Cents>().lossless().to_string(), "25.00");
+/// assert!(u64::MAX.cast::<Cents>().is_err());
+/// assert_eq!(1234i64.cast::<Cents>().bitwise().to_string(), "12.34");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// ```compile_fail
+/// use cove::prelude::*;
+/// use cove::types::Decimal;
+///
+/// // An i64 could exceed i64::MAX / 100, so this fails to compile
+/// let _ = 5i64.cast::<Decimal<i64, 2>>().lossless();
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal<I, const SCALE: u32>(I);
+
+macro_rules! decimal {
+    ($($int:ty as $wide:ty),+) => {
+        $(
+            impl<const SCALE: u32> Decimal<$int, SCALE> {
+                /// The number of decimal places of this type
+                pub const SCALE: u32 = SCALE;
+
+                /// The smallest value of this type
+                pub const MIN: Self = Self::valid(<$int>::MIN);
+
+                /// The largest value of this type
+                pub const MAX: Self = Self::valid(<$int>::MAX);
+
+                /// The smallest positive value of this type, `10^-SCALE`
+                pub const EPSILON: Self = Self::valid(1);
+
+                /// The denominator of this type, `10^SCALE`
+                pub(crate) const POWER: u128 = 10u128.pow(SCALE);
+
+                /// Creates a new value from its underlying integer, representing `bits / 10^SCALE`
+                #[inline]
+                #[must_use]
+                pub const fn from_bits(bits: $int) -> Self {
+                    // Reference the bounds to ensure that SCALE is checked at compile time
+                    let _ = Self::MIN;
+                    Self(bits)
+                }
+
+                /// Returns the underlying integer of the value, which is the value times
+                /// `10^SCALE`
+                #[inline]
+                #[must_use]
+                pub const fn to_bits(self) -> $int {
+                    self.0
+                }
+
+                /// Returns the underlying integer widened to 128 bits
+                #[inline]
+                #[allow(clippy::cast_lossless)]
+                pub(crate) const fn widen(self) -> $wide {
+                    self.0 as $wide
+                }
+
+                /// Wraps `bits` after checking at compile time that `SCALE` is valid
+                const fn valid(bits: $int) -> Self {
+                    assert!(SCALE <= 38, "Decimal must have a scale of at most 38 places");
+                    Self(bits)
+                }
+            }
+
+            impl<const SCALE: u32> Display for Decimal<$int, SCALE> {
+                fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+                    let wide = self.widen();
+                    decimal(!wide.is_negative(), wide.magnitude(), SCALE, formatter)
+                }
+            }
+        )*
+    };
+}
+
+decimal!(
+    u8 as u128, u16 as u128, u32 as u128, u64 as u128, u128 as u128, usize as u128,
+    i8 as i128, i16 as i128, i32 as i128, i64 as i128, i128 as i128, isize as i128
+);
+
+/// Writes `magnitude / 10^scale` in decimal with the given sign and exactly `scale` decimal places
+#[allow(clippy::cast_possible_truncation)]
+fn decimal(
+    nonnegative: bool,
+    mut magnitude: u128,
+    scale: u32,
+    formatter: &mut Formatter<'_>
+) -> core::fmt::Result {
+    // The longest output is 39 digits and the point
+    let mut buffer = [0u8; 40];
+    let (mut start, mut digits) = (buffer.len(), 0);
+
+    // Write the digits from least significant backwards, placing the point after `scale` of them
+    // and padding with zeros until there is an integral digit
+    loop {
+        if digits == scale && scale != 0 {
+            start -= 1;
+            buffer[start] = b'.';
+        }
+
+        start -= 1;
+        buffer[start] = b'0' + (magnitude % 10) as u8;
+        magnitude /= 10;
+        digits += 1;
+
+        if magnitude == 0 && digits > scale {
+            break;
+        }
+    }
+
+    // This is safe because the buffer holds only ASCII digits and the point
+    let digits = unsafe {core::str::from_utf8_unchecked(&buffer[start ..])};
+    formatter.pad_integral(nonnegative, "", digits)
+}
@@ -0,0 +1,79 @@
+//! Provides the time unit types [`Secs`], [`Millis`], [`Micros`] and [`Nanos`]
+
+use core::fmt::{Display, Formatter};
+
+macro_rules! unit {
+    ($($unit:ident: $name:literal, $symbol:literal, $nanos:literal),+) => {
+        $(
+            #[doc = concat!("A number of ", $name, " of type `T`, which casts to and from ")]
+            #[doc = "[`Duration`](core::time::Duration)"]
+            ///
+            /// # Casts
+            /// The time unit types support [`Cast`](crate::casts::Cast) to and from
+            /// [`Duration`](core::time::Duration) for every primitive number `T`, with exact
+            /// detection of loss; they are otherwise plain wrappers around the number, which is
+            /// accessible as the public field.
+            ///
+            /// Casts to [`Duration`](core::time::Duration) are lossy if the value is negative,
+            /// NaN, too large, or not a whole number of nanoseconds. The lossy value is truncated
+            /// towards zero and saturated as for the `as` keyword, with NaN yielding zero, while
+            /// [`Closest`](crate::casts::Closest) rounds to the nearest nanosecond (with ties away
+            /// from zero) and saturates instead. Unsigned integers of up to 64 bits are
+            /// [`Lossless`](crate::casts::Lossless) in every unit.
+            ///
+            /// Casts from [`Duration`](core::time::Duration) are lossy if the duration is not a
+            /// whole number of the unit or does not fit in `T`. For integers, the lossy value is
+            /// the number of whole units casted as for the primitive integers, while
+            /// [`Closest`](crate::casts::Closest) rounds to the nearest unit (with ties away from
+            /// zero) and saturates instead; for floats, the value is rounded to nearest. Only
+            /// [`Nanos<u128>`] and [`Nanos<i128>`] are [`Lossless`](crate::casts::Lossless).
+            ///
+            /// Loss is measured in nanoseconds by
+            /// [`LossyCastError::loss`](crate::errors::LossyCastError::loss). Since
+            /// [`Duration`](core::time::Duration) does not implement [`Display`], neither do the
+            /// errors of these casts.
+            ///
+            /// # Examples
+            /// ```
+            /// use cove::prelude::*;
+            /// use cove::types::{Micros, Millis, Nanos, Secs};
+            /// use core::time::Duration;
+            ///
+            /// // Durations cast into the unit exactly, or else round to the nearest unit
+            /// let duration = Duration::from_micros(1_500);
+            /// assert_eq!(duration.cast::<Micros<u16>>().unwrap(), Micros(1_500));
+            /// assert!(duration.cast::<Millis<u32>>().is_err());
+            /// assert_eq!(duration.cast::<Millis<u32>>().closest(), Millis(2));
+            /// assert_eq!(duration.cast::<Secs<f64>>().closest(), Secs(0.0015));
+            /// assert_eq!(Duration::MAX.cast::<Nanos<u64>>().closest(), Nanos(u64::MAX));
+            ///
+            /// // Numbers cast into durations, detecting negative and fractional values
+            /// assert_eq!(Secs(1.5f64).cast::<Duration>().unwrap(), Duration::from_millis(1_500));
+            /// assert_eq!(Secs(-1i8).cast::<Duration>().closest(), Duration::ZERO);
+            /// assert_eq!(Secs(1e-10f64).cast::<Duration>().closest(), Duration::ZERO);
+            /// assert_eq!(Millis(7u64).cast::<Duration>().lossless(), Duration::from_millis(7));
+            /// ```
+            #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub struct $unit<T>(pub T);
+
+            impl<T> $unit<T> {
+                /// The number of nanoseconds per unit
+                pub(crate) const NANOS: u128 = $nanos;
+            }
+
+            impl<T: Display> Display for $unit<T> {
+                fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+                    self.0.fmt(formatter)?;
+                    formatter.write_str(concat!(" ", $symbol))
+                }
+            }
+        )*
+    };
+}
+
+unit!(
+    Secs: "seconds", "s", 1_000_000_000,
+    Millis: "milliseconds", "ms", 1_000_000,
+    Micros: "microseconds", "µs", 1_000,
+    Nanos: "nanoseconds", "ns", 1
+);
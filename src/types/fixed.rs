@@ -0,0 +1,291 @@
+//! Provides the [`Fixed`] fixed-point type
+
+use super::BitRange;
+use core::fmt::{Display, Formatter};
+use core::ops::Add;
+
+/// A binary fixed-point number stored in the primitive integer `I`, with `FRAC` fractional bits
+///
+/// [`Fixed`] represents the value `bits / 2^FRAC` for the underlying integer `bits`, as for the
+/// Q formats common in signal processing and embedded firmware: Q15 is `Fixed<i16, 15>` and
+/// Q16.16 is `Fixed<i32, 16>`. `FRAC` may be anything from 0 through the number of bits of `I`;
+/// this is checked at compile time whenever a [`Fixed`] is constructed.
+///
+/// # Casts
+/// [`Fixed`] supports [`Cast`](crate::casts::Cast) to and from every primitive number, with exact
+/// detection of loss.
+///
+/// Casts to integers are lossy if the value has a fractional part or its integral part is out of
+/// range, in which case the lossy value is the integral part (truncated towards zero) casted as
+/// for the primitive integers; [`Closest`](crate::casts::Closest) rounds to the nearest integer
+/// (with ties away from zero) and saturates instead. Casts to floats are lossy if the value has
+/// more significant bits than the float, in which case the value is rounded to nearest as for the
+/// `as` keyword.
+///
+/// Casts from integers are lossy if the value is out of range, in which case the lossy value wraps
+/// around as for the `as` keyword and [`Closest`](crate::casts::Closest) saturates instead. Casts
+/// from floats are lossy unless the value is a multiple of `2^-FRAC` within range; the lossy value
+/// is truncated towards zero and saturated as for the `as` keyword, while
+/// [`Closest`](crate::casts::Closest) rounds to the nearest value (with ties away from zero).
+///
+/// [`Lossless`](crate::casts::Lossless) is supported whenever the bits prove the cast lossless,
+/// such as [`i16`] → `Fixed<i32, 16>`, `Fixed<i16, 15>` → [`f32`] or `Fixed<u8, 0>` → [`u8`].
+/// [`Bitwise`](crate::casts::Bitwise) is supported in both directions between `Fixed<I, FRAC>`
+/// and `I`, reinterpreting the underlying integer as the value or vice versa.
+///
+/// Use of [`Lossless`](crate::casts::Lossless) where the bits do not prove the cast lossless is
+/// a compilation error; note that this error only arises once the cast is instantiated, so it may
+/// be reported by `cargo build` but not by `cargo check`.
+///
+/// # Examples
+/// ```
+/// use cove::prelude::*;
+/// use cove::types::Fixed;
+///
+/// type Q15 = Fixed<i16, 15>;
+/// type Q16 = Fixed<i32, 16>;
+///
+/// // Casts from floats detect loss exactly, and Closest rounds to the nearest value
+/// assert_eq!(0.5f32.cast::<Q15>()?.to_bits(), 0x4000i16);
+/// assert!(0.1f32.cast::<Q15>().is_err());
+/// assert_eq!(0.1f32.cast::<Q15>().closest().to_bits(), 3277i16);
+/// assert_eq!(1.0f32.cast::<Q15>().closest(), Q15::MAX);
+///
+/// // Casts to integers detect fractional parts
+/// let value = 2.75f64.cast::<Q16>()?;
+/// assert_eq!(value.cast::<u8>().unwrap_err().to, 2u8);
+/// assert_eq!(value.cast::<u8>().closest(), 3u8);
+/// assert_eq!(value.to_string(), "2.75");
+///
+/// // Lossless casts are proven by the bits
+/// assert_eq!((-7i16).cast::<Q16>().lossless().to_bits(), -7i32 << 16);
+/// assert_eq!(Q15::MIN.cast::<f32>().lossless(), -1f32);
+///
+/// // Bitwise reinterprets the underlying integer
+/// assert_eq!(0x2000i16.cast::<Q15>().bitwise(), 0.25f32.cast::<Q15>()?);
+/// assert_eq!(value.cast::<i32>().bitwise(), 0x2_c000i32);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// ```compile_fail
+/// use cove::prelude::*;
+/// use cove::types::Fixed;
+///
+/// // An i16 could exceed the 15 integral bits of Fixed<i32, 17>, so this fails to compile
+/// let _ = 5i16.cast::<Fixed<i32, 17>>().lossless();
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<I, const FRAC: u32>(I);
+
+macro_rules! fixed {
+    ($($int:ty as $wide:ty),+) => {
+        $(
+            impl<const FRAC: u32> Fixed<$int, FRAC> {
+                /// The number of fractional bits of this type
+                pub const FRAC: u32 = FRAC;
+
+                /// The smallest value of this type
+                pub const MIN: Self = Self::valid(<$int>::MIN);
+
+                /// The largest value of this type
+                pub const MAX: Self = Self::valid(<$int>::MAX);
+
+                /// The smallest positive value of this type, `2^-FRAC`
+                pub const EPSILON: Self = Self::valid(1);
+
+                /// Creates a new value from its underlying integer, representing `bits / 2^FRAC`
+                #[inline]
+                #[must_use]
+                pub const fn from_bits(bits: $int) -> Self {
+                    // Reference the bounds to ensure that FRAC is checked at compile time
+                    let _ = Self::MIN;
+                    Self(bits)
+                }
+
+                /// Returns the underlying integer of the value, which is the value times `2^FRAC`
+                #[inline]
+                #[must_use]
+                pub const fn to_bits(self) -> $int {
+                    self.0
+                }
+
+                /// Returns the underlying integer widened to 128 bits
+                #[inline]
+                #[allow(clippy::cast_lossless)]
+                pub(crate) const fn widen(self) -> $wide {
+                    self.0 as $wide
+                }
+
+                /// Wraps `bits` after checking at compile time that `FRAC` is valid
+                const fn valid(bits: $int) -> Self {
+                    assert!(
+                        FRAC <= <$int>::BITS,
+                        "Fixed must have no more fractional bits than its underlying integer"
+                    );
+
+                    Self(bits)
+                }
+            }
+
+            impl<const FRAC: u32> Display for Fixed<$int, FRAC> {
+                fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+                    let wide = self.widen();
+                    decimal(!wide.is_negative(), wide.magnitude(), FRAC, formatter)
+                }
+            }
+        )*
+    };
+}
+
+fixed!(
+    u8 as u128, u16 as u128, u32 as u128, u64 as u128, u128 as u128, usize as u128,
+    i8 as i128, i16 as i128, i32 as i128, i64 as i128, i128 as i128, isize as i128
+);
+
+/// Writes `magnitude / 2^frac` in decimal with the given sign; since the denominator is a power of
+/// two, the expansion is finite and is written exactly
+#[allow(clippy::cast_possible_truncation)]
+fn decimal(
+    nonnegative: bool,
+    magnitude: u128,
+    frac: u32,
+    formatter: &mut Formatter<'_>
+) -> core::fmt::Result {
+    // The longest output is 39 integral digits, the point and 128 fractional digits
+    let mut buffer = [0u8; 168];
+    let mut integral = magnitude.checked_shr(frac).unwrap_or(0);
+    let mut fraction = magnitude & mask(frac);
+
+    // Write the integral digits from least significant backwards
+    let mut start = 39;
+    loop {
+        start -= 1;
+        buffer[start] = b'0' + (integral % 10) as u8;
+        integral /= 10;
+
+        if integral == 0 {
+            break;
+        }
+    }
+
+    // Write the fractional digits by repeatedly multiplying by ten; the product may exceed 128
+    // bits, so it is formed from the products of the halves of the fraction
+    let mut end = 39;
+    if fraction != 0 {
+        buffer[end] = b'.';
+        end += 1;
+    }
+
+    while fraction != 0 {
+        let low = fraction.wrapping_mul(10);
+        let high = ((fraction >> 64) * 10 + (((fraction & u128::from(u64::MAX)) * 10) >> 64)) >> 64;
+        let digit = match frac {
+            128 => high,
+            _ => (high << (128 - frac)) | (low >> frac)
+        };
+
+        buffer[end] = b'0' + digit as u8;
+        fraction = low & mask(frac);
+        end += 1;
+    }
+
+    // This is safe because the buffer holds only ASCII digits and the point
+    let digits = unsafe {core::str::from_utf8_unchecked(&buffer[start .. end])};
+    formatter.pad_integral(nonnegative, "", digits)
+}
+
+/// Returns a mask of the least significant `bits` bits
+#[inline]
+const fn mask(bits: u32) -> u128 {
+    match u128::MAX.checked_shr(128 - bits) {
+        Some(mask) => mask,
+        None => 0
+    }
+}
+
+// -- Wide -- //
+/// Helper trait for the 128-bit integers to which the underlying integers of [`Fixed`] widen, as
+/// used to split values into their integral and fractional parts
+pub(crate) trait Wide: Copy + Add<Output = Self> + From<bool> {
+    /// Returns whether the value is negative
+    fn is_negative(self) -> bool;
+
+    /// Returns the magnitude of the value
+    fn magnitude(self) -> u128;
+
+    /// Divides the value by `2^frac`, returning the floor of the quotient and the remainder
+    fn split(self, frac: u32) -> (Self, u128);
+
+    /// Divides the value by `2^frac`, returning the quotient truncated towards zero and whether it
+    /// is exact
+    #[inline]
+    fn truncated(self, frac: u32) -> (Self, bool) {
+        let (floor, remainder) = self.split(frac);
+        (floor + Self::from(self.is_negative() && remainder != 0), remainder == 0)
+    }
+
+    /// Divides the value by `2^frac`, returning the quotient rounded to nearest with ties away from
+    /// zero
+    #[inline]
+    fn nearest(self, frac: u32) -> Self {
+        let (floor, remainder) = self.split(frac);
+        let half = 1 << (frac.max(1) - 1);
+
+        // Ties round down for negative values, which is away from zero
+        let up = remainder > half || (remainder == half && !self.is_negative());
+        floor + Self::from(remainder != 0 && up)
+    }
+}
+
+impl Wide for u128 {
+    #[inline]
+    fn is_negative(self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn magnitude(self) -> u128 {
+        self
+    }
+
+    #[inline]
+    fn split(self, frac: u32) -> (Self, u128) {
+        (self.checked_shr(frac).unwrap_or(0), self & mask(frac))
+    }
+}
+
+impl Wide for i128 {
+    #[inline]
+    fn is_negative(self) -> bool {
+        self < 0
+    }
+
+    #[inline]
+    fn magnitude(self) -> u128 {
+        self.unsigned_abs()
+    }
+
+    #[inline]
+    #[allow(clippy::cast_sign_loss)]
+    fn split(self, frac: u32) -> (Self, u128) {
+        // Shifting the sign bit all the way down yields the floor for shifts of 128 bits
+        (self.checked_shr(frac).unwrap_or(self >> 127), self as u128 & mask(frac))
+    }
+}
+
+// The integers exactly representable by a Fixed are those of its underlying integer with FRAC
+// fewer bits, and it fits within an integer width only if it has no fractional bits
+impl<I: BitRange, const FRAC: u32> BitRange for Fixed<I, FRAC> {
+    const UNSIGNED_FITS: u32 = match FRAC {
+        0 => I::UNSIGNED_FITS,
+        _ => u32::MAX
+    };
+
+    const SIGNED_FITS: u32 = match FRAC {
+        0 => I::SIGNED_FITS,
+        _ => u32::MAX
+    };
+
+    const UNSIGNED_HOLDS: u32 = I::UNSIGNED_HOLDS.saturating_sub(FRAC);
+    const SIGNED_HOLDS: u32 = I::SIGNED_HOLDS.saturating_sub(FRAC);
+}
@@ -0,0 +1,109 @@
+//! Provides the [`f8e4m3`] and [`f8e5m2`] 8-bit floating point types of the OCP FP8 specification
+
+use super::minifloat::{minifloat, Encoding, Format};
+
+minifloat!(
+    /// An 8-bit floating point number in the OCP FP8 E4M3 format
+    ///
+    /// [`f8e4m3`] is a storage type for quantized machine learning data such as weights and
+    /// activations: it has 1 sign bit, 4 exponent bits and 3 mantissa bits, with finite values up
+    /// to 448 in magnitude. Unlike the IEEE 754 formats, it has no infinities: the largest exponent
+    /// encodes finite values, except that an all-ones exponent and mantissa encodes NaN, so the
+    /// only NaNs are [`f8e4m3::NAN`] and its negation. It provides no arithmetic; instead, cast it
+    /// to [`f32`] or another wider float (which is always lossless) to compute with it, and cast
+    /// the result back.
+    ///
+    /// # Casts
+    /// [`f8e4m3`] supports [`Cast`](crate::casts::Cast) to and from every primitive number, every
+    /// member of the `NonZero*` family defined in [`core::num`], and cove's other software floats.
+    /// Casting to [`f8e4m3`] rounds to the nearest value, with ties to even, and is lossy unless
+    /// the value is exactly representable:
+    ///
+    /// * Finite values beyond [`f8e4m3::MAX`] saturate to [`f8e4m3::MAX`] or [`f8e4m3::MIN`]
+    /// * Infinities have no counterpart, and also saturate to [`f8e4m3::MAX`] or [`f8e4m3::MIN`]
+    /// * NaN becomes [`f8e4m3::NAN`] with the same sign, which is lossless as between other floats
+    ///
+    /// The lossy value is available from the [`LossyCastError`](crate::errors::LossyCastError) and
+    /// via [`Closest`](crate::casts::Closest), which therefore agree for all values.
+    ///
+    /// Casting from [`f8e4m3`] behaves exactly as casting from the same value as an [`f32`], and
+    /// supports the same follow-on extension traits. [`Lossless`](crate::casts::Lossless) is
+    /// supported to [`f16`](crate::f16), [`bf16`](crate::bf16), [`f32`] and [`f64`]; casts
+    /// between [`f8e4m3`] and [`f8e5m2`] are lossy in both directions.
+    ///
+    /// [`Bitwise`](crate::casts::Bitwise) is supported to and from [`u8`] and [`i8`] as well as
+    /// from [`NonZeroU8`](core::num::NonZeroU8) and [`NonZeroI8`](core::num::NonZeroI8), but not
+    /// to or from [`f8e5m2`], whose casts would not preserve the payloads of NaNs.
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use cove::types::f8e4m3;
+    ///
+    /// // Casts into f8e4m3 round to nearest, and saturate instead of overflowing
+    /// assert_eq!(0.3f32.cast::<f8e4m3>().lossy(), f8e4m3::from_bits(0x2a));
+    /// assert_eq!(17u32.cast::<f8e4m3>().lossy(), 16u32.cast::<f8e4m3>()?);
+    /// assert_eq!(1000f32.cast::<f8e4m3>().lossy(), f8e4m3::MAX);
+    /// assert_eq!(f32::NEG_INFINITY.cast::<f8e4m3>().closest(), f8e4m3::MIN);
+    /// assert!(f32::NAN.cast::<f8e4m3>()?.is_nan());
+    ///
+    /// // Casts out of f8e4m3 behave as for f32
+    /// assert_eq!(f8e4m3::MAX.cast::<f32>().lossless(), 448f32);
+    /// assert_eq!(f8e4m3::NAN.cast::<u8>().closest(), 0u8);
+    /// assert_eq!(f8e4m3::NAN.cast::<u8>().bitwise(), 0x7f);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    f8e4m3(u8): Format {exponent: 4, mantissa: 3, encoding: Encoding::Finite}
+);
+
+minifloat!(
+    /// An 8-bit floating point number in the OCP FP8 E5M2 format
+    ///
+    /// [`f8e5m2`] is a storage type for quantized machine learning data such as gradients: it has 1
+    /// sign bit, 5 exponent bits and 2 mantissa bits, with finite values up to 57344 in magnitude.
+    /// It follows the IEEE 754 conventions, with infinities and multiple NaNs, and its bits are the
+    /// most significant half of the bits of the [`f16`](crate::f16) of the same value. It provides
+    /// no arithmetic; instead, cast it to [`f32`] or another wider float (which is always lossless)
+    /// to compute with it, and cast the result back.
+    ///
+    /// # Casts
+    /// [`f8e5m2`] supports [`Cast`](crate::casts::Cast) to and from every primitive number, every
+    /// member of the `NonZero*` family defined in [`core::num`], and cove's other software floats.
+    /// Casting to [`f8e5m2`] rounds to the nearest value, with ties to even, and is lossy unless
+    /// the value is exactly representable:
+    ///
+    /// * Finite values beyond [`f8e5m2::MAX`] become infinite, in which case
+    ///     [`Closest`](crate::casts::Closest) saturates to [`f8e5m2::MAX`] or [`f8e5m2::MIN`]
+    /// * Infinities are preserved, which is lossless as between other floats
+    /// * NaN becomes [`f8e5m2::NAN`] with the same sign, which is lossless as between other floats
+    ///
+    /// Casting from [`f8e5m2`] behaves exactly as casting from the same value as an [`f32`], and
+    /// supports the same follow-on extension traits. [`Lossless`](crate::casts::Lossless) is
+    /// supported to [`f16`](crate::f16), [`bf16`](crate::bf16), [`f32`] and [`f64`]; casts
+    /// between [`f8e5m2`] and [`f8e4m3`] are lossy in both directions.
+    ///
+    /// [`Bitwise`](crate::casts::Bitwise) is supported to and from [`u8`] and [`i8`] as well as
+    /// from [`NonZeroU8`](core::num::NonZeroU8) and [`NonZeroI8`](core::num::NonZeroI8), but not
+    /// to or from [`f8e4m3`], whose casts would not preserve the payloads of NaNs.
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use cove::types::f8e5m2;
+    ///
+    /// // Casts into f8e5m2 round to nearest, and overflow to infinity
+    /// assert_eq!(0.3f32.cast::<f8e5m2>().lossy(), f8e5m2::from_bits(0x35));
+    /// assert_eq!(1e5f32.cast::<f8e5m2>().lossy(), f8e5m2::INFINITY);
+    /// assert_eq!(1e5f32.cast::<f8e5m2>().closest(), f8e5m2::MAX);
+    /// assert_eq!(f32::NEG_INFINITY.cast::<f8e5m2>()?, f8e5m2::NEG_INFINITY);
+    ///
+    /// // Casts out of f8e5m2 behave as for f32
+    /// assert_eq!(f8e5m2::MAX.cast::<f32>().lossless(), 57344f32);
+    /// assert_eq!(f8e5m2::INFINITY.cast::<u8>().closest(), u8::MAX);
+    /// assert_eq!(f8e5m2::INFINITY.cast::<u8>().bitwise(), 0x7c);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    f8e5m2(u8): Format {exponent: 5, mantissa: 2, encoding: Encoding::Ieee}
+);
+
+minifloat!(infinity f8e5m2);
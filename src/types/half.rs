@@ -0,0 +1,118 @@
+//! Provides the [`f16`] and [`bf16`] 16-bit floating point types
+
+use super::minifloat::{minifloat, Encoding, Format};
+
+minifloat!(
+    /// A 16-bit floating point number in the IEEE 754 binary16 format
+    ///
+    /// [`f16`] is a storage type for half precision data such as that produced by sensors or
+    /// stored in GPU buffers: it has 1 sign bit, 5 exponent bits and 10 mantissa bits, so it
+    /// represents integers exactly up to 2048 in magnitude and finite values up to 65504. It
+    /// provides no arithmetic; instead, cast it to [`f32`] or [`f64`] (which is always lossless)
+    /// to compute with it, and cast the result back.
+    ///
+    /// # Casts
+    /// [`f16`] supports [`Cast`](crate::casts::Cast) to and from every primitive number and every
+    /// member of the `NonZero*` family defined in [`core::num`], following the same rules as the
+    /// primitive floats:
+    ///
+    /// Casting to [`f16`] rounds to the nearest value, with ties to even, and is lossy unless the
+    /// value is exactly representable. Casting NaN from another float is lossless, and values
+    /// beyond [`f16::MAX`] become infinite, in which case [`Closest`](crate::casts::Closest)
+    /// saturates to [`f16::MAX`] or [`f16::MIN`] instead. [`Lossless`](crate::casts::Lossless) is
+    /// supported from [`u8`], [`i8`], [`NonZeroU8`](core::num::NonZeroU8) and
+    /// [`NonZeroI8`](core::num::NonZeroI8).
+    ///
+    /// Casting from [`f16`] behaves exactly as casting from the same value as an [`f32`], and
+    /// supports the same follow-on extension traits. [`Lossless`](crate::casts::Lossless) is
+    /// supported to [`f32`] and [`f64`].
+    ///
+    /// Casts between [`f16`] and [`bf16`] are lossy in both directions, since each represents
+    /// values beyond the precision or range of the other.
+    ///
+    /// [`Bitwise`](crate::casts::Bitwise) is supported to and from [`u16`] and [`i16`] as well as
+    /// from [`NonZeroU16`](core::num::NonZeroU16) and [`NonZeroI16`](core::num::NonZeroI16), but
+    /// not to or from [`bf16`], whose casts would not preserve the payloads of NaNs.
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use cove::f16;
+    ///
+    /// // Casts into f16 round to nearest and report whether they were lossy
+    /// assert_eq!(0.5f32.cast::<f16>()?, f16::from_bits(0x3800));
+    /// assert_eq!(2049u32.cast::<f16>().lossy(), 2048u32.cast::<f16>()?);
+    /// assert_eq!(1e6f64.cast::<f16>().lossy(), f16::INFINITY);
+    /// assert_eq!(1e6f64.cast::<f16>().closest(), f16::MAX);
+    ///
+    /// // Casts out of f16 behave as for f32
+    /// let value = 2.5f32.cast::<f16>()?;
+    /// assert_eq!(value.cast::<f32>().lossless(), 2.5f32);
+    /// assert_eq!(value.cast::<u8>().closest(), 3u8);
+    /// assert_eq!(value.cast::<u16>().bitwise(), 0x4100);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    f16(u16): Format {exponent: 5, mantissa: 10, encoding: Encoding::Ieee}
+);
+
+minifloat!(infinity f16);
+
+minifloat!(
+    /// A 16-bit floating point number in the bfloat16 ("brain float") format
+    ///
+    /// [`bf16`] is a storage type for machine learning data such as model weights: it has 1 sign
+    /// bit, 8 exponent bits and 7 mantissa bits, so it has the same range as [`f32`] but far less
+    /// precision, representing integers exactly only up to 256 in magnitude. Its bits are the most
+    /// significant half of the bits of the [`f32`] of the same value. It provides no arithmetic;
+    /// instead, cast it to [`f32`] or [`f64`] (which is always lossless) to compute with it, and
+    /// cast the result back.
+    ///
+    /// # Casts
+    /// [`bf16`] supports [`Cast`](crate::casts::Cast) to and from every primitive number and every
+    /// member of the `NonZero*` family defined in [`core::num`], following the same rules as the
+    /// primitive floats:
+    ///
+    /// Casting to [`bf16`] rounds to the nearest value, with ties to even, and is lossy unless the
+    /// value is exactly representable; the lossy value is available from the
+    /// [`LossyCastError`](crate::errors::LossyCastError) and via
+    /// [`Closest`](crate::casts::Closest). Casting NaN from another float is lossless, and values
+    /// beyond [`bf16::MAX`] become infinite, in which case [`Closest`](crate::casts::Closest)
+    /// saturates to [`bf16::MAX`] or [`bf16::MIN`] instead. [`Lossless`](crate::casts::Lossless) is
+    /// supported from [`u8`], [`i8`], [`NonZeroU8`](core::num::NonZeroU8) and
+    /// [`NonZeroI8`](core::num::NonZeroI8).
+    ///
+    /// Casting from [`bf16`] behaves exactly as casting from the same value as an [`f32`], and
+    /// supports the same follow-on extension traits. [`Lossless`](crate::casts::Lossless) is
+    /// supported to [`f32`] and [`f64`].
+    ///
+    /// Casts between [`bf16`] and [`f16`] are lossy in both directions, since each represents
+    /// values beyond the precision or range of the other.
+    ///
+    /// [`Bitwise`](crate::casts::Bitwise) is supported to and from [`u16`] and [`i16`] as well as
+    /// from [`NonZeroU16`](core::num::NonZeroU16) and [`NonZeroI16`](core::num::NonZeroI16), but
+    /// not to or from [`f16`], whose casts would not preserve the payloads of NaNs.
+    ///
+    /// # Examples
+    /// ```
+    /// use cove::prelude::*;
+    /// use cove::bf16;
+    ///
+    /// // Casts into bf16 round to nearest, with ties to even, and report whether they were lossy
+    /// let weight = 0.1f32.cast::<bf16>().unwrap_err();
+    /// assert_eq!(weight.to, bf16::from_bits(0x3dcd));
+    /// assert_eq!(weight.closest(), bf16::from_bits(0x3dcd));
+    /// assert_eq!(257u32.cast::<bf16>().lossy(), 256u32.cast::<bf16>()?);
+    /// assert_eq!(f32::MAX.cast::<bf16>().lossy(), bf16::INFINITY);
+    /// assert_eq!(f32::MAX.cast::<bf16>().closest(), bf16::MAX);
+    ///
+    /// // Casts out of bf16 behave as for f32
+    /// let value = (-2.5f32).cast::<bf16>()?;
+    /// assert_eq!(value.cast::<f32>().lossless(), -2.5f32);
+    /// assert_eq!(value.cast::<i8>().closest(), -3i8);
+    /// assert_eq!(value.cast::<u16>().bitwise(), 0xc020);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    bf16(u16): Format {exponent: 8, mantissa: 7, encoding: Encoding::Ieee}
+);
+
+minifloat!(infinity bf16);
@@ -0,0 +1,403 @@
+//! Provides the shared implementation of cove's software floating point types, which store values
+//! in the IEEE 754 binary interchange layout with fewer bits than the primitive floats
+
+// -- Format -- //
+/// Describes the binary layout of a software floating point type: a sign bit, followed by the
+/// biased exponent field, followed by the mantissa field without its implicit leading bit
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Format {
+    /// The number of bits in the exponent field
+    pub exponent: u32,
+
+    /// The number of bits in the mantissa field
+    pub mantissa: u32,
+
+    /// How values with the largest exponent are encoded
+    pub encoding: Encoding
+}
+
+/// Describes how a software floating point type encodes values with the largest exponent
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum Encoding {
+    /// As for IEEE 754: a zero mantissa encodes infinity, and any other mantissa encodes NaN
+    Ieee,
+
+    /// There are no infinities: an all-ones mantissa encodes NaN, and any other mantissa encodes a
+    /// finite value as for the smaller exponents; values beyond the finite range saturate
+    Finite
+}
+
+impl Format {
+    /// Returns the mask of the exponent field, once shifted down past the mantissa
+    const fn exponent_mask(self) -> u32 {
+        (1 << self.exponent) - 1
+    }
+
+    /// Returns the mask of the mantissa field
+    const fn mantissa_mask(self) -> u32 {
+        (1 << self.mantissa) - 1
+    }
+
+    /// Returns the exponent bias
+    #[allow(clippy::cast_possible_wrap)]
+    const fn bias(self) -> i32 {
+        (1 << (self.exponent - 1)) - 1
+    }
+
+    /// Returns the bits of the smallest magnitude with the largest exponent
+    const fn special(self) -> u32 {
+        self.exponent_mask() << self.mantissa
+    }
+
+    /// Returns the bits of the difference between 1.0 and the next larger representable number
+    #[allow(clippy::cast_sign_loss)]
+    pub(crate) const fn epsilon(self) -> u32 {
+        (self.bias() as u32 - self.mantissa) << self.mantissa
+    }
+
+    /// Returns the bits of positive infinity, which only exists for [`Encoding::Ieee`]
+    pub(crate) const fn infinity(self) -> u32 {
+        self.special()
+    }
+
+    /// Returns the bits of the canonical positive NaN, which is quiet for [`Encoding::Ieee`]
+    pub(crate) const fn nan(self) -> u32 {
+        match self.encoding {
+            Encoding::Ieee => self.special() | (1 << (self.mantissa - 1)),
+            Encoding::Finite => self.special() | self.mantissa_mask()
+        }
+    }
+
+    /// Returns the bits of the largest finite value
+    pub(crate) const fn max(self) -> u32 {
+        match self.encoding {
+            Encoding::Ieee => self.special() - 1,
+            Encoding::Finite => self.nan() - 1
+        }
+    }
+
+    /// Returns the bits of the sign
+    pub(crate) const fn sign(self) -> u32 {
+        1 << (self.exponent + self.mantissa)
+    }
+
+    /// Returns whether `bits` encodes NaN
+    pub(crate) const fn is_nan(self, bits: u32) -> bool {
+        match self.encoding {
+            Encoding::Ieee => bits & !self.sign() > self.special(),
+            Encoding::Finite => bits & !self.sign() == self.nan()
+        }
+    }
+
+    /// Returns whether `bits` encodes positive or negative infinity
+    pub(crate) const fn is_infinite(self, bits: u32) -> bool {
+        match self.encoding {
+            Encoding::Ieee => bits & !self.sign() == self.special(),
+            Encoding::Finite => false
+        }
+    }
+
+    /// Returns the magnitude of the bits which values beyond the largest finite value round to
+    const fn overflow(self) -> u32 {
+        match self.encoding {
+            Encoding::Ieee => self.infinity(),
+            Encoding::Finite => self.max()
+        }
+    }
+
+    /// Converts `bits` to the [`f64`] of the same value, which is always exact
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    pub(crate) fn decode(self, bits: u32) -> f64 {
+        let sign = u64::from(bits & self.sign() != 0) << 63;
+        let exponent = (bits >> self.mantissa) & self.exponent_mask();
+        let mantissa = u64::from(bits & self.mantissa_mask());
+
+        // The f64 fields for a value of mantissa * 2^(power - self.mantissa), where the mantissa
+        // includes its leading bit; the leading bit is normalized to the top of the f64 mantissa
+        let normalized = |mantissa: u64, power: i32| {
+            let leading = 63 - mantissa.leading_zeros();
+            let power = power + leading as i32 - self.mantissa as i32;
+            let exponent = (power + 1023) as u64;
+            (exponent << 52) | ((mantissa << (52 - leading)) & ((1 << 52) - 1))
+        };
+
+        let magnitude = match exponent {
+            // A zero exponent implies a subnormal or zero, both of which are normal in f64
+            0 => match mantissa {
+                0 => 0,
+                mantissa => normalized(mantissa, 1 - self.bias())
+            },
+
+            // Infinity and NaN keep their mantissa in the most significant bits of the f64
+            // mantissa, which preserves the payload of NaN as for the primitive float conversions
+            _ if self.is_nan(bits) || self.is_infinite(bits) => {
+                f64::INFINITY.to_bits() | (mantissa << (52 - self.mantissa))
+            },
+
+            // Otherwise the value is normal, with an implicit leading bit
+            exponent => normalized(mantissa | (1 << self.mantissa), exponent as i32 - self.bias())
+        };
+
+        f64::from_bits(sign | magnitude)
+    }
+
+    /// Returns the unit in the last place of the value encoded by `bits`; infinities and NaN are
+    /// treated as though their exponent were finite, as for the primitive floats
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    pub(crate) fn ulp(self, bits: u32) -> f64 {
+        // Subnormals share the ulp of the smallest normal exponent
+        let exponent = ((bits >> self.mantissa) & self.exponent_mask()).max(1);
+        let power = exponent as i32 - self.bias() - self.mantissa as i32;
+        f64::from_bits(((power + 1023) as u64) << 52)
+    }
+
+    /// Converts `value` to the bits of the nearest representable value, rounding ties to even;
+    /// values beyond the largest finite value round to infinity, or saturate to the largest finite
+    /// value for [`Encoding::Finite`], and NaN yields the canonical NaN
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    pub(crate) fn encode(self, value: f64) -> u32 {
+        let sign = match value.is_sign_negative() {
+            true => self.sign(),
+            false => 0
+        };
+
+        let bits = value.to_bits() & !(1 << 63);
+        let exponent = (bits >> 52) as i32;
+
+        let magnitude = match exponent {
+            // NaN and infinity map directly to their counterparts, if any
+            0x7ff => match bits & ((1 << 52) - 1) {
+                0 => self.overflow(),
+                _ => self.nan()
+            },
+
+            // A zero exponent implies an f64 subnormal or zero, which is far too small to round
+            // away from zero
+            0 => 0,
+
+            // Otherwise round the significand (with its leading bit) to the precision available at
+            // this exponent, which is reduced for values in the subnormal range of the format
+            exponent => {
+                let power = exponent - 1023;
+                let minimum = 1 - self.bias();
+                let significand = (bits & ((1 << 52) - 1)) | (1 << 52);
+                let shift = (52 - self.mantissa as i32 + (minimum - power).max(0)).min(60) as u32;
+
+                let mut kept = significand >> shift;
+                let remainder = significand & ((1 << shift) - 1);
+                let half = 1 << (shift - 1);
+                if remainder > half || (remainder == half && kept & 1 == 1) {
+                    kept += 1;
+                }
+
+                // Adding the kept significand to the exponent field less one accounts for its
+                // leading bit, and also carries into the exponent if rounding overflowed the
+                // mantissa. Subnormals have an exponent field of zero and no leading bit.
+                let field = (power + self.bias() - 1).max(0) as u64;
+                match (field << self.mantissa) + kept {
+                    magnitude if magnitude > u64::from(self.max()) => self.overflow(),
+                    magnitude => magnitude as u32
+                }
+            }
+        };
+
+        sign | magnitude
+    }
+}
+
+// -- Types -- //
+/// Defines a software floating point type named `$name`, stored as `$bits` in the layout of
+/// `$format`, along with its constants, methods and non-casting trait implementations
+macro_rules! minifloat {
+    // Defines the infinities of `$name`, which must use `Encoding::Ieee`
+    (infinity $name:ident) => {
+        #[allow(clippy::cast_possible_truncation)]
+        impl $name {
+            /// Infinity (∞)
+            pub const INFINITY: Self = Self(Self::FORMAT.infinity() as _);
+
+            /// Negative infinity (−∞)
+            pub const NEG_INFINITY: Self = Self(
+                (Self::FORMAT.infinity() | Self::FORMAT.sign()) as _
+            );
+        }
+    };
+
+    (
+        $(#[$attribute:meta])*
+        $name:ident($bits:ty): $format:expr
+    ) => {
+        $(#[$attribute])*
+        #[allow(non_camel_case_types)]
+        #[derive(Copy, Clone, Default)]
+        pub struct $name($bits);
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+        impl $name {
+            /// The layout of this type
+            pub(crate) const FORMAT: Format = $format;
+
+            /// Number of significant digits in base 2, including the implicit leading bit
+            pub const MANTISSA_DIGITS: u32 = Self::FORMAT.mantissa + 1;
+
+            /// Machine epsilon: the difference between 1.0 and the next larger representable number
+            pub const EPSILON: Self = Self(Self::FORMAT.epsilon() as $bits);
+
+            /// Smallest finite value
+            pub const MIN: Self = Self((Self::FORMAT.max() | Self::FORMAT.sign()) as $bits);
+
+            /// Smallest positive normal value
+            pub const MIN_POSITIVE: Self = Self((1 << Self::FORMAT.mantissa) as $bits);
+
+            /// Largest finite value
+            pub const MAX: Self = Self(Self::FORMAT.max() as $bits);
+
+            /// Not a Number (NaN)
+            pub const NAN: Self = Self(Self::FORMAT.nan() as $bits);
+
+            /// Creates the nearest value to `value`, as described by [`Format::encode`]
+            #[inline]
+            pub(crate) fn round_from(value: f64) -> Self {
+                Self(Self::FORMAT.encode(value) as $bits)
+            }
+
+            /// Creates a value from its raw bits
+            #[inline]
+            #[must_use]
+            pub const fn from_bits(bits: $bits) -> Self {
+                Self(bits)
+            }
+
+            /// Returns the raw bits of this value
+            #[inline]
+            #[must_use]
+            pub const fn to_bits(self) -> $bits {
+                self.0
+            }
+
+            /// Creates a value from its memory representation as a byte array in native endianness
+            #[inline]
+            #[must_use]
+            pub const fn from_ne_bytes(bytes: [u8; core::mem::size_of::<$bits>()]) -> Self {
+                Self(<$bits>::from_ne_bytes(bytes))
+            }
+
+            /// Creates a value from its memory representation as a byte array in little endian
+            #[inline]
+            #[must_use]
+            pub const fn from_le_bytes(bytes: [u8; core::mem::size_of::<$bits>()]) -> Self {
+                Self(<$bits>::from_le_bytes(bytes))
+            }
+
+            /// Creates a value from its memory representation as a byte array in big endian
+            #[inline]
+            #[must_use]
+            pub const fn from_be_bytes(bytes: [u8; core::mem::size_of::<$bits>()]) -> Self {
+                Self(<$bits>::from_be_bytes(bytes))
+            }
+
+            /// Returns the memory representation of this value as a byte array in native
+            /// endianness
+            #[inline]
+            #[must_use]
+            pub const fn to_ne_bytes(self) -> [u8; core::mem::size_of::<$bits>()] {
+                self.0.to_ne_bytes()
+            }
+
+            /// Returns the memory representation of this value as a byte array in little endian
+            #[inline]
+            #[must_use]
+            pub const fn to_le_bytes(self) -> [u8; core::mem::size_of::<$bits>()] {
+                self.0.to_le_bytes()
+            }
+
+            /// Returns the memory representation of this value as a byte array in big endian
+            #[inline]
+            #[must_use]
+            pub const fn to_be_bytes(self) -> [u8; core::mem::size_of::<$bits>()] {
+                self.0.to_be_bytes()
+            }
+
+            /// Returns `true` if this value is NaN
+            #[inline]
+            #[must_use]
+            pub const fn is_nan(self) -> bool {
+                Self::FORMAT.is_nan(self.0 as u32)
+            }
+
+            /// Returns `true` if this value is positive or negative infinity
+            #[inline]
+            #[must_use]
+            pub const fn is_infinite(self) -> bool {
+                Self::FORMAT.is_infinite(self.0 as u32)
+            }
+
+            /// Returns `true` if this value is neither infinite nor NaN
+            #[inline]
+            #[must_use]
+            pub const fn is_finite(self) -> bool {
+                !self.is_nan() && !self.is_infinite()
+            }
+
+            /// Returns `true` if this value has a positive sign, including `+0.0`, positive
+            /// infinity and NaNs with a positive sign bit
+            #[inline]
+            #[must_use]
+            pub const fn is_sign_positive(self) -> bool {
+                !self.is_sign_negative()
+            }
+
+            /// Returns `true` if this value has a negative sign, including `-0.0`, negative
+            /// infinity and NaNs with a negative sign bit
+            #[inline]
+            #[must_use]
+            pub const fn is_sign_negative(self) -> bool {
+                self.0 as u32 & Self::FORMAT.sign() != 0
+            }
+        }
+
+        impl From<$name> for f32 {
+            #[inline]
+            #[allow(clippy::cast_possible_truncation)]
+            fn from(value: $name) -> Self {
+                // Every value is exactly representable by f32, so this does not round
+                f64::from(value) as f32
+            }
+        }
+
+        impl From<$name> for f64 {
+            #[inline]
+            fn from(value: $name) -> Self {
+                $name::FORMAT.decode(value.0.into())
+            }
+        }
+
+        impl PartialEq for $name {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                f64::from(*self) == f64::from(*other)
+            }
+        }
+
+        impl PartialOrd for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                f64::from(*self).partial_cmp(&f64::from(*other))
+            }
+        }
+
+        impl core::fmt::Debug for $name {
+            fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Debug::fmt(&f32::from(*self), formatter)
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&f32::from(*self), formatter)
+            }
+        }
+    };
+}
+
+pub(crate) use minifloat;
@@ -0,0 +1,51 @@
+//! Provides additional numerical types which take part in cove's casts
+//!
+//! The types in this module extend cove's casting support beyond the primitives and the `NonZero*`
+//! family defined in [`core::num`]. Each type implements [`Cast`](crate::casts::Cast) along with
+//! the follow-on extension traits appropriate to it; see the documentation for each type for 
+//! details.
+//!
+//! * [`Ranged`]: an integer restricted to a subrange of its primitive type
+//! * [`UInt`] / [`Int`]: integers of arbitrary bit width, for fields of protocols and registers
+//! * [`Fixed`]: a binary fixed-point number in the Q format, for signal processing and firmware
+//! * [`Decimal`]: a decimal fixed-scale number, for monetary amounts in their smallest unit
+//! * [`Ratio`]: an exact rational number, for exact intermediates such as the values of floats
+//! * [`Finite`] / [`NonNan`] / [`NonNegative`] / [`UnitInterval`]: floats restricted to a domain
+//! * [`Complex`]: a complex number whose components cast separately, for IQ samples
+//! * [`f16`]: a half precision float in the IEEE 754 binary16 format
+//! * [`bf16`]: a 16-bit float in the bfloat16 format, with the range of [`f32`]
+//! * [`f128`]: a quadruple precision float in the IEEE 754 binary128 format, for exact accumulation
+//! * [`f8e4m3`] / [`f8e5m2`]: 8-bit floats in the OCP FP8 formats, for quantized data
+//! * [`u256`] / [`i256`]: 256-bit integers, for hashes, cryptographic values and wide accumulators
+//! * [`Secs`] / [`Millis`] / [`Micros`] / [`Nanos`]: time units which cast to and from `Duration`
+
+mod bits;
+mod complex;
+mod decimal;
+mod duration;
+mod fixed;
+mod fp8;
+mod half;
+mod minifloat;
+mod quad;
+mod ranged;
+mod ratio;
+mod refined;
+mod wide;
+
+pub use bits::{Int, UInt};
+pub use complex::Complex;
+pub use decimal::Decimal;
+pub use duration::{Micros, Millis, Nanos, Secs};
+pub use fixed::Fixed;
+pub use fp8::{f8e4m3, f8e5m2};
+pub use half::{bf16, f16};
+pub use quad::f128;
+pub use ranged::Ranged;
+pub use ratio::Ratio;
+pub use refined::{Finite, NonNan, NonNegative, UnitInterval};
+pub use wide::{i256, u256};
+
+pub(crate) use bits::{BitRange, Bits, Width};
+pub(crate) use fixed::Wide;
+pub(crate) use ranged::IntegerRange;
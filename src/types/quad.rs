@@ -0,0 +1,256 @@
+//! Provides the [`f128`] quadruple precision floating point type
+
+use crate::casts::{Cast, Lossy};
+
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter};
+
+/// A 128-bit floating point number in the IEEE 754 binary128 ("quadruple precision") format
+///
+/// [`f128`] has 1 sign bit, 15 exponent bits and 112 mantissa bits, so it represents every integer
+/// of up to 113 bits exactly, including every 64-bit integer, along with every [`f32`] and [`f64`].
+/// This makes it a target for exact accumulation and a reference against which to check casts
+/// between the narrower types. It provides no arithmetic; values are created and inspected via
+/// cove's casts.
+///
+/// [`Debug`] and [`Display`] show the nearest [`f64`] to the value, so they may round.
+///
+/// # Casts
+/// [`f128`] supports [`Cast`](crate::casts::Cast) to and from every primitive number, and from
+/// the `NonZero*` family defined in [`core::num`], which cast as their underlying primitives:
+///
+/// Casts to [`f128`] from the integers of up to 64 bits and from [`f32`] and [`f64`] support
+/// [`Lossless`](crate::casts::Lossless), and also
+/// [`PortableLossless`](crate::casts::PortableLossless) except from [`usize`] and [`isize`]. Casts
+/// from [`u128`] and [`i128`] round to the nearest value, with ties to even, and are lossy unless
+/// the value is exactly representable; the lossy value is available from the
+/// [`LossyCastError`](crate::errors::LossyCastError) and via
+/// [`Closest`](crate::casts::Closest).
+///
+/// Casts from [`f128`] to [`f32`] and [`f64`] round to the nearest value, with ties to even.
+/// Casting NaN is lossless, and values beyond the largest finite value become infinite, in which
+/// case [`Closest`](crate::casts::Closest) saturates to the largest finite value instead. Casts to
+/// the integers truncate and saturate as `as` does for the primitive floats, with NaN becoming
+/// zero, while [`Closest`](crate::casts::Closest) rounds to the nearest integer, with ties away
+/// from zero.
+///
+/// [`Bitwise`](crate::casts::Bitwise) is supported to and from [`u128`] and [`i128`] as well as
+/// from [`NonZeroU128`](core::num::NonZeroU128) and [`NonZeroI128`](core::num::NonZeroI128).
+///
+/// # Examples
+/// ```
+/// use cove::prelude::*;
+/// use cove::f128;
+///
+/// // Every 64-bit integer and f64 is exactly representable
+/// let value = u64::MAX.cast::<f128>().lossless();
+/// assert_eq!(value.cast::<u64>()?, u64::MAX);
+/// assert_eq!(value.cast::<f64>().closest(), 18_446_744_073_709_551_616.0);
+/// assert_eq!(0.1f64.cast::<f128>().lossless().cast::<f64>()?, 0.1);
+///
+/// // 128-bit integers are exact up to 113 bits
+/// assert!((1u128 << 113).cast::<f128>().is_ok());
+/// assert!(((1u128 << 113) + 1).cast::<f128>().is_err());
+/// assert_eq!(i128::MIN.cast::<f128>()?.cast::<i128>()?, i128::MIN);
+///
+/// // Casts out of f128 round to nearest or truncate, as for the primitive floats
+/// let large = (1u128 << 100).cast::<f128>()?;
+/// assert_eq!(large.cast::<f32>()?, 1.267_650_6e30);
+/// assert_eq!(2.5f64.cast::<f128>().lossless().cast::<u8>().closest(), 3);
+/// assert_eq!(f128::MAX.cast::<f64>().closest(), f64::MAX);
+/// assert_eq!(f128::MAX.cast::<u128>().lossy(), u128::MAX);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Default)]
+pub struct f128(u128);
+
+impl f128 {
+    /// The number of bits in the mantissa, excluding the implicit leading bit
+    pub(crate) const MANTISSA: u32 = 112;
+
+    /// The bias of the exponent
+    pub(crate) const BIAS: i32 = 16_383;
+
+    /// The bit of the sign
+    const SIGN: u128 = 1 << 127;
+
+    /// The bits of positive infinity, which is also the mask of the exponent
+    const EXPONENT: u128 = 0x7fff << Self::MANTISSA;
+
+    /// Number of significant digits in base 2, including the implicit leading bit
+    pub const MANTISSA_DIGITS: u32 = Self::MANTISSA + 1;
+
+    /// Machine epsilon: the difference between 1.0 and the next larger representable number
+    pub const EPSILON: Self = Self(0x3f8f << Self::MANTISSA);
+
+    /// Smallest finite value
+    pub const MIN: Self = Self(Self::SIGN | Self::MAX.0);
+
+    /// Smallest positive normal value
+    pub const MIN_POSITIVE: Self = Self(1 << Self::MANTISSA);
+
+    /// Largest finite value
+    pub const MAX: Self = Self(Self::EXPONENT - 1);
+
+    /// Not a Number (NaN)
+    pub const NAN: Self = Self(Self::EXPONENT | 1 << (Self::MANTISSA - 1));
+
+    /// Infinity (∞)
+    pub const INFINITY: Self = Self(Self::EXPONENT);
+
+    /// Negative infinity (−∞)
+    pub const NEG_INFINITY: Self = Self(Self::SIGN | Self::EXPONENT);
+
+    /// Creates a value from its raw bits
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: u128) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw bits of this value
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> u128 {
+        self.0
+    }
+
+    /// Creates a value from its memory representation as a byte array in native endianness
+    #[inline]
+    #[must_use]
+    pub const fn from_ne_bytes(bytes: [u8; 16]) -> Self {
+        Self(u128::from_ne_bytes(bytes))
+    }
+
+    /// Creates a value from its memory representation as a byte array in little endian
+    #[inline]
+    #[must_use]
+    pub const fn from_le_bytes(bytes: [u8; 16]) -> Self {
+        Self(u128::from_le_bytes(bytes))
+    }
+
+    /// Creates a value from its memory representation as a byte array in big endian
+    #[inline]
+    #[must_use]
+    pub const fn from_be_bytes(bytes: [u8; 16]) -> Self {
+        Self(u128::from_be_bytes(bytes))
+    }
+
+    /// Returns the memory representation of this value as a byte array in native endianness
+    #[inline]
+    #[must_use]
+    pub const fn to_ne_bytes(self) -> [u8; 16] {
+        self.0.to_ne_bytes()
+    }
+
+    /// Returns the memory representation of this value as a byte array in little endian
+    #[inline]
+    #[must_use]
+    pub const fn to_le_bytes(self) -> [u8; 16] {
+        self.0.to_le_bytes()
+    }
+
+    /// Returns the memory representation of this value as a byte array in big endian
+    #[inline]
+    #[must_use]
+    pub const fn to_be_bytes(self) -> [u8; 16] {
+        self.0.to_be_bytes()
+    }
+
+    /// Returns `true` if this value is NaN
+    #[inline]
+    #[must_use]
+    pub const fn is_nan(self) -> bool {
+        self.magnitude() > Self::EXPONENT
+    }
+
+    /// Returns `true` if this value is positive or negative infinity
+    #[inline]
+    #[must_use]
+    pub const fn is_infinite(self) -> bool {
+        self.magnitude() == Self::EXPONENT
+    }
+
+    /// Returns `true` if this value is neither infinite nor NaN
+    #[inline]
+    #[must_use]
+    pub const fn is_finite(self) -> bool {
+        self.magnitude() < Self::EXPONENT
+    }
+
+    /// Returns `true` if this value has a positive sign, including `+0.0`, positive infinity and
+    /// NaNs with a positive sign bit
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_positive(self) -> bool {
+        !self.is_sign_negative()
+    }
+
+    /// Returns `true` if this value has a negative sign, including `-0.0`, negative infinity and
+    /// NaNs with a negative sign bit
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_negative(self) -> bool {
+        self.0 & Self::SIGN != 0
+    }
+
+    /// Returns the bits of the absolute value
+    #[inline]
+    const fn magnitude(self) -> u128 {
+        self.0 & !Self::SIGN
+    }
+
+    /// Splits a finite value into its sign and the integers `significand` and `exponent` such that
+    /// its magnitude is exactly `significand * 2^exponent`
+    #[inline]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub(crate) const fn decompose(self) -> (bool, u128, i32) {
+        let field = (self.magnitude() >> Self::MANTISSA) as i32;
+        let fraction = self.0 & ((1 << Self::MANTISSA) - 1);
+
+        // Subnormals share the exponent of the smallest normals, but lack the leading bit
+        let minimum = 1 - Self::BIAS - Self::MANTISSA as i32;
+        match field {
+            0 => (self.is_sign_negative(), fraction, minimum),
+            _ => (self.is_sign_negative(), fraction | 1 << Self::MANTISSA, minimum + field - 1)
+        }
+    }
+}
+
+impl PartialEq for f128 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for f128 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        // Both zeros compare equal, and otherwise the order of sign and magnitude is that of the
+        // bits with the negative values reversed
+        let key = |value: f128| match value.is_sign_negative() {
+            true => !value.0,
+            false => value.0 | Self::SIGN
+        };
+
+        match (*self, *other) {
+            (a, b) if a.is_nan() || b.is_nan() => None,
+            (a, b) if a.magnitude() | b.magnitude() == 0 => Some(Ordering::Equal),
+            (a, b) => Some(key(a).cmp(&key(b)))
+        }
+    }
+}
+
+impl Debug for f128 {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.cast::<f64>().lossy(), formatter)
+    }
+}
+
+impl Display for f128 {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.cast::<f64>().lossy(), formatter)
+    }
+}
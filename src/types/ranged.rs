@@ -0,0 +1,200 @@
+//! Provides the [`Ranged`] integer type
+
+use core::fmt::{Display, Formatter};
+use core::num::{
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+};
+
+/// An integer of primitive type `T` which is restricted to the inclusive range `MIN..=MAX`
+///
+/// [`Ranged`] is intended for domain values which live in a subrange of a primitive, such as
+/// percentages, port numbers or channel indices. Casting to a [`Ranged`] checks both that the
+/// value fits in `T` and that it lies within `MIN..=MAX`, so the range need not be rechecked by
+/// hand after every cast.
+///
+/// The bounds are expressed as [`i128`] since const generic parameters cannot depend on `T`;
+/// consequently a [`Ranged`] over [`u128`] or [`usize`] cannot have bounds above [`i128::MAX`].
+/// The bounds must satisfy `MIN <= MAX` and must both be representable in `T`; this is checked
+/// at compile time whenever a [`Ranged`] is constructed.
+///
+/// # Casts
+/// [`Ranged`] supports [`Cast`](crate::casts::Cast) to and from every primitive number and every
+/// member of the `NonZero*` family defined in [`core::num`].
+///
+/// Casting from a [`Ranged`] behaves exactly as casting from its underlying primitive, except
+/// that [`Lossless`](crate::casts::Lossless) is supported whenever the range itself proves the
+/// cast lossless (e.g. `Ranged<u32, 0, 16_777_216>` → [`f32`]).
+///
+/// Casting to a [`Ranged`] fails with [`FailedCastError`](crate::errors::FailedCastError) if the
+/// value is not representable in `T` or lies outside of the range, in which case
+/// [`Closest`](crate::casts::Closest) clamps the value to the bounds.
+/// [`Lossless`](crate::casts::Lossless) is supported whenever the range of the source type lies
+/// within the bounds (e.g. [`u8`] → `Ranged<u16, 0, 255>`).
+///
+/// Use of [`Lossless`](crate::casts::Lossless) where the range does not prove the cast lossless
+/// is a compilation error; note that this error only arises once the cast is instantiated, so it
+/// may be reported by `cargo build` but not by `cargo check`.
+///
+/// # Examples
+/// ```
+/// use cove::prelude::*;
+/// use cove::types::Ranged;
+///
+/// type Percent = Ranged<u8, 0, 100>;
+///
+/// // Casting into the range checks the bounds
+/// assert_eq!(42i32.cast::<Percent>()?.get(), 42u8);
+/// assert!(101u64.cast::<Percent>().is_err());
+/// assert!((-1.0f32).cast::<Percent>().is_err());
+///
+/// // Closest clamps to the bounds
+/// assert_eq!(250u16.cast::<Percent>().closest().get(), 100u8);
+///
+/// // Casting out of the range behaves as for the underlying primitive, but the range can prove
+/// // a cast lossless where the primitive alone could not
+/// let percent = Percent::new(99).unwrap();
+/// assert_eq!(percent.cast::<i8>().lossless(), 99i8);
+/// assert_eq!(Ranged::<u32, 0, 16_777_216>::new(7).unwrap().cast::<f32>().lossless(), 7f32);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// ```compile_fail
+/// use cove::prelude::*;
+/// use cove::types::Ranged;
+///
+/// // The range could exceed i8, so this fails to compile
+/// let _ = Ranged::<u8, 0, 200>::new(5).unwrap().cast::<i8>().lossless();
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ranged<T, const MIN: i128, const MAX: i128>(T);
+
+impl<T: Display, const MIN: i128, const MAX: i128> Display for Ranged<T, MIN, MAX> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(formatter)
+    }
+}
+
+macro_rules! ranged {
+    ($($int:ty),+) => {
+        $(
+            impl<const MIN: i128, const MAX: i128> Ranged<$int, MIN, MAX> {
+                /// The smallest value of this type
+                pub const MIN: Self = Self::valid(MIN as $int);
+
+                /// The largest value of this type
+                pub const MAX: Self = Self::valid(MAX as $int);
+
+                /// Creates a new value if `value` lies within `MIN..=MAX`, or returns [`None`]
+                /// otherwise
+                #[inline]
+                #[must_use]
+                pub const fn new(value: $int) -> Option<Self> {
+                    match value >= Self::MIN.0 && value <= Self::MAX.0 {
+                        true => Some(Self(value)),
+                        false => None
+                    }
+                }
+
+                /// Creates a new value without checking that it lies within `MIN..=MAX`
+                ///
+                /// # Safety
+                /// `value` must lie within `MIN..=MAX`; cove's casts rely on this invariant, so
+                /// violating it can lead to undefined behavior.
+                #[inline]
+                #[must_use]
+                pub const unsafe fn new_unchecked(value: $int) -> Self {
+                    // Reference the bounds to ensure that they are checked at compile time
+                    let _ = Self::MIN;
+                    Self(value)
+                }
+
+                /// Returns the value as its underlying primitive type
+                #[inline]
+                #[must_use]
+                pub const fn get(self) -> $int {
+                    self.0
+                }
+
+                /// Wraps `value` after checking at compile time that the bounds are valid
+                const fn valid(value: $int) -> Self {
+                    assert!(
+                        MIN <= MAX
+                            && MIN >= <$int as IntegerRange>::LOWER
+                            && MAX <= <$int as IntegerRange>::UPPER,
+                        "Ranged bounds must be ordered and representable in the primitive type"
+                    );
+
+                    Self(value)
+                }
+            }
+        )*
+    };
+}
+
+ranged!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+// -- IntegerRange -- //
+/// Helper trait describing the contiguous range of integers exactly representable by a type, as
+/// used to prove casts involving [`Ranged`] lossless
+pub(crate) trait IntegerRange {
+    /// The lowest integer representable, clamped to the range of [`i128`]
+    const LOWER: i128;
+
+    /// The highest integer representable such that all integers from [`LOWER`](Self::LOWER)
+    /// through it are representable (other than possibly zero), clamped to the range of [`i128`]
+    const UPPER: i128;
+
+    /// Whether zero is representable
+    const HAS_ZERO: bool;
+
+    /// Whether every value of the type is an integer within [`LOWER`](Self::LOWER) through
+    /// [`UPPER`](Self::UPPER); this is false for floats and for types exceeding [`i128::MAX`]
+    const EXHAUSTIVE: bool;
+}
+
+macro_rules! integer_range {
+    ($($ty:ty: $lower:expr, $upper:expr, $has_zero:expr, $exhaustive:expr);+) => {
+        $(
+            #[allow(clippy::cast_lossless)]
+            impl IntegerRange for $ty {
+                const LOWER: i128 = $lower;
+                const UPPER: i128 = $upper;
+                const HAS_ZERO: bool = $has_zero;
+                const EXHAUSTIVE: bool = $exhaustive;
+            }
+        )*
+    };
+}
+
+integer_range!(
+    u8: 0, u8::MAX as i128, true, true;
+    u16: 0, u16::MAX as i128, true, true;
+    u32: 0, u32::MAX as i128, true, true;
+    u64: 0, u64::MAX as i128, true, true;
+    u128: 0, i128::MAX, true, false;
+    usize: 0, usize::MAX as i128, true, true;
+    i8: i8::MIN as i128, i8::MAX as i128, true, true;
+    i16: i16::MIN as i128, i16::MAX as i128, true, true;
+    i32: i32::MIN as i128, i32::MAX as i128, true, true;
+    i64: i64::MIN as i128, i64::MAX as i128, true, true;
+    i128: i128::MIN, i128::MAX, true, true;
+    isize: isize::MIN as i128, isize::MAX as i128, true, true;
+
+    NonZeroU8: 1, u8::MAX as i128, false, true;
+    NonZeroU16: 1, u16::MAX as i128, false, true;
+    NonZeroU32: 1, u32::MAX as i128, false, true;
+    NonZeroU64: 1, u64::MAX as i128, false, true;
+    NonZeroU128: 1, i128::MAX, false, false;
+    NonZeroUsize: 1, usize::MAX as i128, false, true;
+    NonZeroI8: i8::MIN as i128, i8::MAX as i128, false, true;
+    NonZeroI16: i16::MIN as i128, i16::MAX as i128, false, true;
+    NonZeroI32: i32::MIN as i128, i32::MAX as i128, false, true;
+    NonZeroI64: i64::MIN as i128, i64::MAX as i128, false, true;
+    NonZeroI128: i128::MIN, i128::MAX, false, true;
+    NonZeroIsize: isize::MIN as i128, isize::MAX as i128, false, true;
+
+    // Every integer up to 2^MANTISSA_DIGITS in magnitude is exactly representable
+    f32: -(1 << f32::MANTISSA_DIGITS), 1 << f32::MANTISSA_DIGITS, true, false;
+    f64: -(1 << f64::MANTISSA_DIGITS), 1 << f64::MANTISSA_DIGITS, true, false
+);
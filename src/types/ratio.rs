@@ -0,0 +1,216 @@
+//! Provides the [`Ratio`] rational type
+
+use super::Wide;
+use core::fmt::{Display, Formatter};
+
+/// An exact rational number whose numerator and denominator are of the primitive integer type `I`
+///
+/// [`Ratio`] is always kept in lowest terms with a positive denominator, so that every value has
+/// exactly one representation and equality is structural. Every finite float is a dyadic rational,
+/// which makes [`Ratio`] suitable as an exact intermediate wherever a float would round, such as
+/// when converting between floats and decimal or integer amounts.
+///
+/// # Casts
+/// [`Ratio`] supports [`Cast`](crate::casts::Cast) to and from every primitive number, with exact
+/// detection of loss.
+///
+/// Casts from floats are exact whenever the numerator and denominator of the float fit in `I`.
+/// Otherwise they are lossy, in which case the lossy value is the closest [`Ratio`] to the float
+/// (with ties away from zero), found via its continued fraction; values out of range saturate,
+/// NaN yields zero and [`Closest`](crate::casts::Closest) yields the lossy value. Casts from
+/// integers are lossy if the value is out of range, in which case the lossy value wraps around as
+/// for the `as` keyword and [`Closest`](crate::casts::Closest) saturates instead.
+///
+/// Casts to integers are lossy if the value has a fractional part or its integral part is out of
+/// range, in which case the lossy value is the integral part (truncated towards zero) casted as
+/// for the primitive integers; [`Closest`](crate::casts::Closest) rounds to the nearest integer
+/// (with ties away from zero) and saturates instead. Casts to floats are lossy unless the value is
+/// exactly representable, in which case the value is correctly rounded to nearest.
+///
+/// [`Lossless`](crate::casts::Lossless) and [`PortableLossless`](crate::casts::PortableLossless)
+/// are supported from integers whenever they are supported from the integer to `I`, such as
+/// [`i64`] → `Ratio<i128>`.
+///
+/// Loss is measured by [`LossyCastError::loss`](crate::errors::LossyCastError::loss) exactly for
+/// dyadic values and to within 2^-128 otherwise.
+///
+/// # Examples
+/// ```
+/// use cove::prelude::*;
+/// use cove::types::Ratio;
+///
+/// // Floats decompose into their exact dyadic value
+/// let tenth = 0.1f64.cast::<Ratio<i128>>()?;
+/// assert_eq!(tenth.numer(), 3_602_879_701_896_397);
+/// assert_eq!(tenth.denom(), 1 << 55);
+/// assert_eq!(tenth.cast::<f64>()?, 0.1);
+///
+/// // Values which do not fit approximate to the closest ratio
+/// assert!(0.1f64.cast::<Ratio<i16>>().is_err());
+/// assert_eq!(0.1f64.cast::<Ratio<i16>>().closest(), Ratio::<i16>::new(1, 10).unwrap());
+/// assert_eq!(1e10f32.cast::<Ratio<u8>>().closest(), Ratio::<u8>::MAX);
+///
+/// // Casts out of a ratio detect fractional parts and round correctly
+/// let third = Ratio::<i32>::new(-1, 3).unwrap();
+/// assert_eq!(third.cast::<i8>().unwrap_err().to, 0);
+/// assert_eq!(Ratio::<u8>::new(5, 2).unwrap().cast::<u8>().closest(), 3);
+/// assert_eq!(third.cast::<f32>().unwrap_err().to, -1.0 / 3.0);
+/// assert_eq!(7u8.cast::<Ratio<i16>>().lossless(), Ratio::<i16>::from_integer(7));
+/// assert_eq!(third.to_string(), "-1/3");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// ```compile_fail
+/// use cove::prelude::*;
+/// use cove::types::Ratio;
+///
+/// // A u16 could exceed the numerator of Ratio<i16>, so this fails to compile
+/// let _ = 5u16.cast::<Ratio<i16>>().lossless();
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Ratio<I> {
+    /// The numerator, which carries the sign of the value
+    numer: I,
+
+    /// The denominator, which is always positive and coprime to the numerator
+    denom: I
+}
+
+macro_rules! ratio {
+    ($($int:ty as $wide:ty),+) => {
+        $(
+            impl Ratio<$int> {
+                /// The smallest value of this type, whose numerator is the smallest integer
+                pub const MIN: Self = Self::from_integer(<$int>::MIN);
+
+                /// The largest value of this type, whose numerator is the largest integer
+                pub const MAX: Self = Self::from_integer(<$int>::MAX);
+
+                /// The value zero
+                pub const ZERO: Self = Self::from_integer(0);
+
+                /// The largest denominator of this type
+                #[allow(clippy::cast_sign_loss)]
+                pub(crate) const DENOM_BOUND: u128 = <$int>::MAX as u128;
+
+                /// Creates the value `numer / denom` in lowest terms, returning [`None`] if the
+                /// denominator is zero or the value is not representable with a positive
+                /// denominator, as for `i8::MIN / -1`
+                #[inline]
+                #[must_use]
+                #[allow(clippy::cast_lossless)]
+                pub fn new(numer: $int, denom: $int) -> Option<Self> {
+                    let (numer, denom) = (numer as $wide, denom as $wide);
+                    let negative = numer.is_negative() != denom.is_negative();
+                    let divisor = gcd(numer.magnitude(), denom.magnitude());
+
+                    if denom == 0 {
+                        return None;
+                    }
+
+                    let (numer, denom) = (numer.magnitude() / divisor, denom.magnitude() / divisor);
+                    let negative = negative && numer != 0;
+
+                    match numer <= Self::numer_bound(negative) && denom <= Self::DENOM_BOUND {
+                        true => Some(Self::from_sign_magnitude(negative, numer, denom)),
+                        false => None
+                    }
+                }
+
+                /// Creates the value of the integer `value`, whose denominator is one
+                #[inline]
+                #[must_use]
+                pub const fn from_integer(value: $int) -> Self {
+                    Self {numer: value, denom: 1}
+                }
+
+                /// Returns the numerator of the value in lowest terms, which carries its sign
+                #[inline]
+                #[must_use]
+                pub const fn numer(self) -> $int {
+                    self.numer
+                }
+
+                /// Returns the denominator of the value in lowest terms, which is always positive
+                #[inline]
+                #[must_use]
+                pub const fn denom(self) -> $int {
+                    self.denom
+                }
+
+                /// Returns the largest magnitude of the numerator of a value with the given sign
+                #[inline]
+                #[allow(clippy::cast_lossless)]
+                pub(crate) fn numer_bound(negative: bool) -> u128 {
+                    match negative {
+                        true => (<$int>::MIN as $wide).magnitude(),
+                        false => Self::DENOM_BOUND
+                    }
+                }
+
+                /// Returns the sign of the value along with the magnitudes of its numerator and
+                /// denominator
+                #[inline]
+                #[allow(clippy::cast_lossless, clippy::cast_sign_loss)]
+                pub(crate) fn to_sign_magnitude(self) -> (bool, u128, u128) {
+                    let numer = self.numer as $wide;
+                    (numer.is_negative(), numer.magnitude(), self.denom as u128)
+                }
+
+                /// Creates the value with the given sign and magnitudes, which must be coprime and
+                /// within the bounds of this type
+                #[inline]
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                pub(crate) fn from_sign_magnitude(
+                    negative: bool, numer: u128, denom: u128
+                ) -> Self {
+                    // Negating the magnitude as $int wraps the largest negative magnitude to MIN
+                    Self {
+                        numer: match negative {
+                            true => (numer as $int).wrapping_neg(),
+                            false => numer as $int
+                        },
+                        denom: denom as $int
+                    }
+                }
+            }
+
+            impl Display for Ratio<$int> {
+                fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+                    match self.denom {
+                        1 => self.numer.fmt(formatter),
+                        denom => write!(formatter, "{}/{denom}", self.numer)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+ratio!(
+    u8 as u128, u16 as u128, u32 as u128, u64 as u128, u128 as u128, usize as u128,
+    i8 as i128, i16 as i128, i32 as i128, i64 as i128, i128 as i128, isize as i128
+);
+
+/// Returns the greatest common divisor of `a` and `b` via Stein's algorithm, which is zero only if
+/// both are zero
+const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    if a == 0 || b == 0 {
+        return a | b;
+    }
+
+    let shift = (a | b).trailing_zeros();
+    a >>= a.trailing_zeros();
+
+    while b != 0 {
+        b >>= b.trailing_zeros();
+
+        if a > b {
+            (a, b) = (b, a);
+        }
+
+        b -= a;
+    }
+
+    a << shift
+}
@@ -0,0 +1,191 @@
+//! Provides the [`Finite`], [`NonNan`], [`NonNegative`] and [`UnitInterval`] refined float types
+
+use core::fmt::{Display, Formatter};
+
+/// A float of primitive type `F` which is neither NaN nor infinite
+///
+/// [`Finite`] is one of the refined float types, along with [`NonNan`], [`NonNegative`] and
+/// [`UnitInterval`]. Each wraps a primitive float which is validated on construction, so that
+/// casting to it rejects the values which the domain forbids instead of passing them through as
+/// casts between the primitive floats do; this replaces checks such as
+/// [`is_finite`](f64::is_finite) around casts.
+///
+/// # Casts
+/// The refined float types support [`Cast`](crate::casts::Cast) to and from every primitive
+/// number, and between the [`f32`] and [`f64`] forms of the same refined type.
+///
+/// Casting from a refined float behaves exactly as casting from its underlying primitive, so that
+/// for example `Finite<f32>` → [`f64`] supports [`Lossless`](crate::casts::Lossless). Casting
+/// from a [`NonNegative`] to an unsigned integer skips the check of the sign, since the value is
+/// known to be positive.
+///
+/// Casting to a refined float casts to the underlying primitive and then checks the result. The
+/// cast is lossy if either step is, in which case the lossy value is that of the primitive cast
+/// clamped to the nearest valid value, with NaN taken as zero; [`Closest`](crate::casts::Closest)
+/// clamps the closest value of the primitive cast likewise. Values which overflow to infinity are
+/// therefore lossy for [`Finite`], with the lossy value saturating to the largest finite value.
+/// [`Lossless`](crate::casts::Lossless) is supported from the integers whenever their casts to the
+/// underlying primitive are lossless and every integer is valid, which excludes signed integers
+/// for [`NonNegative`] and all integers for [`UnitInterval`].
+///
+/// # Examples
+/// ```
+/// use cove::prelude::*;
+/// use cove::errors::LossKind;
+/// use cove::types::{Finite, NonNegative, UnitInterval};
+///
+/// // Casting into a refined float rejects NaN and infinities
+/// assert_eq!(1.5f64.cast::<Finite<f32>>()?.get(), 1.5f32);
+/// assert_eq!(f64::NAN.cast::<Finite<f32>>().unwrap_err().kind(), LossKind::NaN);
+/// assert_eq!(1e300f64.cast::<Finite<f32>>().unwrap_err().kind(), LossKind::Overflow);
+/// assert_eq!(1e300f64.cast::<Finite<f32>>().closest().get(), f32::MAX);
+///
+/// // Closest picks the nearest valid value
+/// assert_eq!((-3.0f32).cast::<NonNegative<f64>>().closest().get(), 0.0);
+/// assert_eq!(1.25f64.cast::<UnitInterval<f64>>().closest().get(), 1.0);
+///
+/// // Casting out of a refined float behaves as for the underlying primitive
+/// let length = NonNegative::<f64>::new(42.0).unwrap();
+/// assert_eq!(length.cast::<u32>()?, 42u32);
+/// assert_eq!(Finite::<f32>::new(0.1).unwrap().cast::<f64>().lossless(), f64::from(0.1f32));
+/// assert_eq!(7u16.cast::<Finite<f32>>().lossless().get(), 7f32);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Finite<F>(F);
+
+/// A float of primitive type `F` which is not NaN, although it may be infinite
+///
+/// See [`Finite`] for the casts supported by the refined float types.
+///
+/// # Examples
+/// ```
+/// use cove::prelude::*;
+/// use cove::types::NonNan;
+///
+/// assert_eq!(f64::INFINITY.cast::<NonNan<f32>>()?.get(), f32::INFINITY);
+/// assert!(f32::NAN.cast::<NonNan<f64>>().is_err());
+/// assert_eq!(f32::NAN.cast::<NonNan<f64>>().closest().get(), 0.0);
+/// assert_eq!(u64::MAX.cast::<NonNan<f32>>().unwrap_err().to.get(), 18_446_744_073_709_551_616f32);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct NonNan<F>(F);
+
+/// A float of primitive type `F` which is positive or zero, although it may be infinite
+///
+/// See [`Finite`] for the casts supported by the refined float types.
+///
+/// # Examples
+/// ```
+/// use cove::prelude::*;
+/// use cove::types::NonNegative;
+///
+/// assert_eq!(2.5f32.cast::<NonNegative<f32>>()?.cast::<u8>().closest(), 3u8);
+/// assert_eq!((-1i32).cast::<NonNegative<f64>>().unwrap_err().to.get(), 0.0);
+/// assert_eq!(200u8.cast::<NonNegative<f32>>().lossless().get(), 200f32);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct NonNegative<F>(F);
+
+/// A float of primitive type `F` which lies within the closed unit interval `0..=1`
+///
+/// See [`Finite`] for the casts supported by the refined float types.
+///
+/// # Examples
+/// ```
+/// use cove::prelude::*;
+/// use cove::types::UnitInterval;
+///
+/// assert_eq!(0.25f64.cast::<UnitInterval<f32>>()?.get(), 0.25f32);
+/// assert!(1u8.cast::<UnitInterval<f64>>().is_ok());
+/// assert_eq!(2u8.cast::<UnitInterval<f64>>().closest(), UnitInterval::<f64>::MAX);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct UnitInterval<F>(F);
+
+macro_rules! refined {
+    // Implements the API of $refined<$float>, whose values are those within the inclusive range
+    // $min..=$max, given as literals or as names of the constants of $float
+    ($refined:ident: $description:literal, $min:tt ..= $max:tt) => {
+        refined!($refined<f32>: $description, $min ..= $max);
+        refined!($refined<f64>: $description, $min ..= $max);
+    };
+
+    ($refined:ident<$float:ty>: $description:literal, $min:tt ..= $max:tt) => {
+        impl $refined<$float> {
+            #[doc = concat!("The smallest ", $description)]
+            pub const MIN: Self = Self(refined!(@bound $float, $min));
+
+            #[doc = concat!("The largest ", $description)]
+            pub const MAX: Self = Self(refined!(@bound $float, $max));
+
+            /// Creates a new value if `value` is valid, or returns [`None`] otherwise
+            #[inline]
+            #[must_use]
+            pub fn new(value: $float) -> Option<Self> {
+                // Comparisons with NaN are false, so NaN is never valid
+                match (Self::MIN.0 ..= Self::MAX.0).contains(&value) {
+                    true => Some(Self(value)),
+                    false => None
+                }
+            }
+
+            /// Creates a new value without checking that it is valid
+            ///
+            /// # Safety
+            /// `value` must be valid; cove's casts rely on this invariant, so violating it can
+            /// lead to undefined behavior.
+            #[inline]
+            #[must_use]
+            pub const unsafe fn new_unchecked(value: $float) -> Self {
+                Self(value)
+            }
+
+            /// Returns the value as its underlying primitive type
+            #[inline]
+            #[must_use]
+            pub const fn get(self) -> $float {
+                self.0
+            }
+
+            /// Returns the valid value closest to `value`, taking NaN as zero
+            #[inline]
+            pub(crate) fn clamped(value: $float) -> Self {
+                match value.is_nan() {
+                    true => Self(0.0),
+                    false => Self(value.clamp(Self::MIN.0, Self::MAX.0))
+                }
+            }
+        }
+    };
+
+    (@bound $float:ty, $bound:literal) => {
+        $bound
+    };
+
+    (@bound $float:ty, $bound:ident) => {
+        <$float>::$bound
+    };
+}
+
+refined!(Finite: "finite value", MIN ..= MAX);
+refined!(NonNan: "value that is not NaN", NEG_INFINITY ..= INFINITY);
+refined!(NonNegative: "value that is positive or zero", 0.0 ..= INFINITY);
+refined!(UnitInterval: "value within the unit interval", 0.0 ..= 1.0);
+
+macro_rules! display {
+    ($($refined:ident),+) => {
+        $(
+            impl<F: Display> Display for $refined<F> {
+                fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+                    self.0.fmt(formatter)
+                }
+            }
+        )*
+    };
+}
+
+display!(Finite, NonNan, NonNegative, UnitInterval);
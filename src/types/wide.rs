@@ -0,0 +1,376 @@
+//! Provides the [`u256`] and [`i256`] 256-bit integer types
+
+use core::fmt::{Debug, Display, Formatter, LowerHex, UpperHex};
+
+/// A 256-bit unsigned integer
+///
+/// [`u256`] is a storage type for values which exceed [`u128`], such as checksums, hashes and
+/// token amounts. It provides no arithmetic; instead, it exists to be casted to and from cove's
+/// other numerical types with exactness checking, and to be exchanged as bytes or as words via
+/// [`u256::to_words`] / [`u256::from_words`] and the `*_bytes` functions. It is formatted in
+/// decimal by [`Display`] and [`Debug`], and in hexadecimal by [`LowerHex`] and [`UpperHex`].
+///
+/// # Casts
+/// [`u256`] supports [`Cast`](crate::casts::Cast) to and from every primitive number, every member
+/// of the `NonZero*` family defined in [`core::num`], and [`i256`], following the same rules as the
+/// primitive integers:
+///
+/// * [`Lossless`](crate::casts::Lossless) and
+///     [`PortableLossless`](crate::casts::PortableLossless) are supported from every unsigned
+///     primitive and `NonZeroU*`
+/// * Casts between integers are lossy if the value is out of range, in which case the lossy value
+///     is the same as for the `as` keyword; [`Closest`](crate::casts::Closest) saturates instead
+///     and [`Wrapping`](crate::casts::Wrapping) reduces the value modulo 2^N
+/// * Casts to [`f32`] / [`f64`] round to nearest with ties to even, and are lossy unless the value
+///     is exactly representable; [`Closest`](crate::casts::Closest) saturates [`f32`] overflow
+/// * Casts from [`f32`] / [`f64`] are lossy unless the value is an integer within range, and
+///     support [`CastSplit`](crate::casts::CastSplit), [`Rounded`](crate::casts::Rounded),
+///     [`Stochastic`](crate::casts::Stochastic) and [`Wrapping`](crate::casts::Wrapping) as for
+///     the primitive integers
+///
+/// [`Bitwise`](crate::casts::Bitwise) is supported to and from [`i256`]. Arrays are not
+/// [`Cast`](crate::casts::Cast) types, so the bitwise views as `[u128; 2]` and `[u8; 32]` are
+/// instead provided by the inherent [`u256::to_words`] / [`u256::from_words`] and
+/// [`u256::to_ne_bytes`] / [`u256::from_ne_bytes`] (along with their little and big endian
+/// counterparts), which never lose information.
+///
+/// # Examples
+/// ```
+/// use cove::prelude::*;
+/// use cove::types::{i256, u256};
+///
+/// // Casts into u256 are lossless from unsigned primitives, and checked otherwise
+/// let value = u128::MAX.cast::<u256>().lossless();
+/// assert_eq!(value.to_words(), [u128::MAX, 0]);
+/// assert_eq!((-1i8).cast::<u256>().unwrap_err().to, u256::MAX);
+/// assert_eq!((-1i8).cast::<u256>().closest(), u256::MIN);
+/// assert_eq!(2f64.powi(200).cast::<u256>()?, u256::from_words([0, 1 << 72]));
+///
+/// // Casts out of u256 saturate or wrap as for the primitives
+/// assert_eq!(u256::MAX.cast::<u64>().closest(), u64::MAX);
+/// assert_eq!(u256::from_words([7, 1]).cast::<u8>().wrapping(), 7u8);
+/// assert_eq!(u256::MAX.cast::<f64>().unwrap_err().to, 2f64.powi(256));
+/// assert_eq!(u256::MAX.cast::<i256>().bitwise(), (-1i8).cast::<i256>().lossless());
+///
+/// // Bitwise views as arrays are inherent conversions rather than casts
+/// assert_eq!(u256::from_le_bytes(value.to_le_bytes()), value);
+/// assert_eq!(value.to_be_bytes()[16 ..], [u8::MAX; 16]);
+///
+/// assert_eq!(value.to_string(), "340282366920938463463374607431768211455");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct u256 {
+    // Declared most significant first so that the derived ordering is numerical
+    high: u128,
+    low: u128
+}
+
+/// A 256-bit signed integer in two's complement
+///
+/// [`i256`] is a storage type for signed values which exceed [`i128`]. It provides no arithmetic;
+/// instead, it exists to be casted to and from cove's other numerical types with exactness
+/// checking, and to be exchanged as bytes or as words via [`i256::to_words`] /
+/// [`i256::from_words`] and the `*_bytes` functions. It is formatted in decimal by [`Display`] and
+/// [`Debug`], and in two's complement hexadecimal by [`LowerHex`] and [`UpperHex`].
+///
+/// # Casts
+/// [`i256`] supports [`Cast`](crate::casts::Cast) to and from every primitive number, every member
+/// of the `NonZero*` family defined in [`core::num`], and [`u256`], following the same rules as the
+/// primitive integers:
+///
+/// * [`Lossless`](crate::casts::Lossless) and
+///     [`PortableLossless`](crate::casts::PortableLossless) are supported from every primitive
+///     integer and `NonZero*`
+/// * Casts between integers are lossy if the value is out of range, in which case the lossy value
+///     is the same as for the `as` keyword; [`Closest`](crate::casts::Closest) saturates instead
+///     and [`Wrapping`](crate::casts::Wrapping) reduces the value modulo 2^N
+/// * Casts to [`f32`] / [`f64`] round to nearest with ties to even, and are lossy unless the value
+///     is exactly representable; [`Closest`](crate::casts::Closest) saturates [`f32`] overflow
+/// * Casts from [`f32`] / [`f64`] are lossy unless the value is an integer within range, and
+///     support [`CastSplit`](crate::casts::CastSplit), [`Rounded`](crate::casts::Rounded),
+///     [`Stochastic`](crate::casts::Stochastic) and [`Wrapping`](crate::casts::Wrapping) as for
+///     the primitive integers
+///
+/// [`Bitwise`](crate::casts::Bitwise) is supported to and from [`u256`]. Arrays are not
+/// [`Cast`](crate::casts::Cast) types, so the bitwise views as `[u128; 2]` and `[u8; 32]` are
+/// instead provided by the inherent [`i256::to_words`] / [`i256::from_words`] and
+/// [`i256::to_ne_bytes`] / [`i256::from_ne_bytes`] (along with their little and big endian
+/// counterparts), which never lose information.
+///
+/// # Examples
+/// ```
+/// use cove::prelude::*;
+/// use cove::types::{i256, u256};
+///
+/// // Casts into i256 are lossless from every primitive integer
+/// let value = i128::MIN.cast::<i256>().lossless();
+/// assert_eq!(value.to_words(), [1 << 127, u128::MAX]);
+/// assert_eq!(u256::MAX.cast::<i256>().unwrap_err().to, (-1i8).cast::<i256>().lossless());
+/// assert_eq!(u256::MAX.cast::<i256>().closest(), i256::MAX);
+///
+/// // Casts out of i256 saturate or wrap as for the primitives
+/// assert_eq!(i256::MIN.cast::<i128>().closest(), i128::MIN);
+/// assert_eq!(i256::MIN.cast::<f32>().closest(), f32::MIN);
+/// assert_eq!(value.cast::<u8>().wrapping(), 0u8);
+///
+/// // Bitwise views as arrays are inherent conversions rather than casts
+/// assert_eq!(i256::from_words(value.to_words()), value);
+/// assert_eq!(value.to_be_bytes()[15 .. 18], [0xff, 0x80, 0x00]);
+///
+/// assert_eq!(value.to_string(), "-170141183460469231731687303715884105728");
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct i256 {
+    // Declared most significant first so that the derived ordering is numerical, which holds for
+    // two's complement since only the most significant word is signed
+    high: i128,
+    low: u128
+}
+
+impl u256 {
+    /// The smallest value of this type
+    pub const MIN: Self = Self {high: 0, low: 0};
+
+    /// The largest value of this type
+    pub const MAX: Self = Self {high: u128::MAX, low: u128::MAX};
+}
+
+impl i256 {
+    /// The smallest value of this type
+    pub const MIN: Self = Self {high: i128::MIN, low: 0};
+
+    /// The largest value of this type
+    pub const MAX: Self = Self {high: i128::MAX, low: u128::MAX};
+
+    /// Returns `true` if this value is negative
+    #[inline]
+    #[must_use]
+    pub const fn is_negative(self) -> bool {
+        self.high < 0
+    }
+}
+
+macro_rules! wide {
+    ($($name:ident: $high:ty),+) => {
+        $(
+            #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+            impl $name {
+                /// The size of this type in bits
+                pub const BITS: u32 = 256;
+
+                /// Creates a value from its two 128-bit words, least significant first
+                #[inline]
+                #[must_use]
+                pub const fn from_words(words: [u128; 2]) -> Self {
+                    Self {high: words[1] as $high, low: words[0]}
+                }
+
+                /// Returns the two 128-bit words of this value, least significant first
+                #[inline]
+                #[must_use]
+                pub const fn to_words(self) -> [u128; 2] {
+                    [self.low, self.high as u128]
+                }
+
+                /// Creates a value from its memory representation as a byte array in little endian
+                #[inline]
+                #[must_use]
+                pub const fn from_le_bytes(bytes: [u8; 32]) -> Self {
+                    let (low, high) = split(bytes);
+                    Self::from_words([u128::from_le_bytes(low), u128::from_le_bytes(high)])
+                }
+
+                /// Creates a value from its memory representation as a byte array in big endian
+                #[inline]
+                #[must_use]
+                pub const fn from_be_bytes(bytes: [u8; 32]) -> Self {
+                    let (high, low) = split(bytes);
+                    Self::from_words([u128::from_be_bytes(low), u128::from_be_bytes(high)])
+                }
+
+                /// Creates a value from its memory representation as a byte array in native
+                /// endianness
+                #[inline]
+                #[must_use]
+                pub const fn from_ne_bytes(bytes: [u8; 32]) -> Self {
+                    #[cfg(target_endian = "little")] {
+                        Self::from_le_bytes(bytes)
+                    }
+
+                    #[cfg(target_endian = "big")] {
+                        Self::from_be_bytes(bytes)
+                    }
+                }
+
+                /// Returns the memory representation of this value as a byte array in little endian
+                #[inline]
+                #[must_use]
+                pub const fn to_le_bytes(self) -> [u8; 32] {
+                    let [low, high] = self.to_words();
+                    join(low.to_le_bytes(), high.to_le_bytes())
+                }
+
+                /// Returns the memory representation of this value as a byte array in big endian
+                #[inline]
+                #[must_use]
+                pub const fn to_be_bytes(self) -> [u8; 32] {
+                    let [low, high] = self.to_words();
+                    join(high.to_be_bytes(), low.to_be_bytes())
+                }
+
+                /// Returns the memory representation of this value as a byte array in native
+                /// endianness
+                #[inline]
+                #[must_use]
+                pub const fn to_ne_bytes(self) -> [u8; 32] {
+                    #[cfg(target_endian = "little")] {
+                        self.to_le_bytes()
+                    }
+
+                    #[cfg(target_endian = "big")] {
+                        self.to_be_bytes()
+                    }
+                }
+            }
+
+            impl Debug for $name {
+                fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+                    Display::fmt(self, formatter)
+                }
+            }
+
+            impl LowerHex for $name {
+                fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+                    let mut buffer = [0; 64];
+                    let digits = hexadecimal(self.to_words(), b"0123456789abcdef", &mut buffer);
+                    formatter.pad_integral(true, "0x", digits)
+                }
+            }
+
+            impl UpperHex for $name {
+                fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+                    let mut buffer = [0; 64];
+                    let digits = hexadecimal(self.to_words(), b"0123456789ABCDEF", &mut buffer);
+                    formatter.pad_integral(true, "0x", digits)
+                }
+            }
+        )*
+    }
+}
+
+wide!(u256: u128, i256: i128);
+
+impl Display for u256 {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut buffer = [0; 78];
+        formatter.pad_integral(true, "", decimal(self.to_words(), &mut buffer))
+    }
+}
+
+impl Display for i256 {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        // The magnitude of a negative value is its two's complement negation, which is correct
+        // even for MIN when interpreted as unsigned
+        let [low, high] = self.to_words();
+        let magnitude = match self.is_negative() {
+            true => {
+                let low_negated = low.wrapping_neg();
+                [low_negated, (!high).wrapping_add(u128::from(low == 0))]
+            },
+            false => [low, high]
+        };
+
+        let mut buffer = [0; 78];
+        formatter.pad_integral(!self.is_negative(), "", decimal(magnitude, &mut buffer))
+    }
+}
+
+// -- Helpers -- //
+/// Splits `bytes` into its first and second halves
+const fn split(bytes: [u8; 32]) -> ([u8; 16], [u8; 16]) {
+    let (mut first, mut second) = ([0; 16], [0; 16]);
+    let mut index = 0;
+
+    while index < 16 {
+        first[index] = bytes[index];
+        second[index] = bytes[index + 16];
+        index += 1;
+    }
+
+    (first, second)
+}
+
+/// Joins `first` and `second` into a single array, in that order
+const fn join(first: [u8; 16], second: [u8; 16]) -> [u8; 32] {
+    let mut bytes = [0; 32];
+    let mut index = 0;
+
+    while index < 16 {
+        bytes[index] = first[index];
+        bytes[index + 16] = second[index];
+        index += 1;
+    }
+
+    bytes
+}
+
+/// Writes the unsigned value of `words` (least significant first) in decimal to the end of
+/// `buffer`, returning the digits written
+#[allow(clippy::cast_possible_truncation)]
+fn decimal(words: [u128; 2], buffer: &mut [u8; 78]) -> &str {
+    // Split into 64-bit limbs, most significant first, and repeatedly divide by the largest power
+    // of ten which fits in a limb; each remainder yields the next 19 least significant digits
+    const DIVISOR: u128 = 10_000_000_000_000_000_000;
+    let [low, high] = words;
+    let mut limbs = [(high >> 64) as u64, high as u64, (low >> 64) as u64, low as u64];
+    let mut start = buffer.len();
+
+    loop {
+        let mut remainder = 0u128;
+        for limb in &mut limbs {
+            let current = (remainder << 64) | u128::from(*limb);
+            *limb = (current / DIVISOR) as u64;
+            remainder = current % DIVISOR;
+        }
+
+        let finished = limbs == [0; 4];
+        for _ in 0 .. 19 {
+            start -= 1;
+            buffer[start] = b'0' + (remainder % 10) as u8;
+            remainder /= 10;
+
+            if finished && remainder == 0 {
+                break;
+            }
+        }
+
+        if finished {
+            break;
+        }
+    }
+
+    // Only ASCII digits have been written
+    core::str::from_utf8(&buffer[start ..]).unwrap_or_default()
+}
+
+/// Writes the unsigned value of `words` (least significant first) in hexadecimal to the end of
+/// `buffer` using `digits`, returning the digits written
+#[allow(clippy::cast_possible_truncation)]
+fn hexadecimal<'buffer>(
+    words: [u128; 2],
+    digits: &[u8; 16],
+    buffer: &'buffer mut [u8; 64]
+) -> &'buffer str {
+    let [low, high] = words;
+    for (index, nibble) in buffer.iter_mut().rev().enumerate() {
+        let word = if index < 32 { low } else { high };
+        *nibble = digits[((word >> ((index % 32) * 4)) & 0xf) as usize];
+    }
+
+    // Strip leading zeros, keeping at least one digit
+    let leading = buffer[.. 63].iter().take_while(|&&digit| digit == b'0').count();
+    core::str::from_utf8(&buffer[leading ..]).unwrap_or_default()
+}
@@ -0,0 +1,145 @@
+//! These tests cover the software `bf16` type, checking it against the truncated bits of `f32`
+
+use cove::prelude::*;
+use cove::casts::RoundingMode;
+use cove::errors::LossKind;
+use cove::{bf16, f16};
+use core::num::{NonZeroI16, NonZeroU8};
+
+/// Rounds `value` to the bits of the nearest bf16 with ties to even, by the well-known bit trick
+/// on the f32 bits; this is only valid for values which are not NaN
+fn reference(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let rounding = 0x7fff + ((bits >> 16) & 1);
+    ((bits + rounding) >> 16) as u16
+}
+
+#[test]
+fn constants() {
+    assert_eq!(f32::from(bf16::MAX).to_bits(), 0x7f7f_0000);
+    assert_eq!(f32::from(bf16::MIN).to_bits(), 0xff7f_0000);
+    assert_eq!(f32::from(bf16::MIN_POSITIVE), f32::MIN_POSITIVE);
+    assert_eq!(f32::from(bf16::EPSILON), 2f32.powi(-7));
+    assert_eq!(f32::from(bf16::INFINITY), f32::INFINITY);
+    assert!(bf16::NAN.is_nan());
+    assert_eq!(bf16::MANTISSA_DIGITS, 8);
+}
+
+#[test]
+fn exhaustive() {
+    for bits in 0 ..= u16::MAX {
+        let value = bf16::from_bits(bits);
+        let widened = value.cast::<f32>().lossless();
+
+        // The bits of bf16 are the upper half of those of f32
+        match value.is_nan() {
+            true => assert!(widened.is_nan() && widened.is_sign_negative() == (bits >= 0x8000)),
+            false => {
+                assert_eq!(widened.to_bits(), u32::from(bits) << 16);
+                assert_eq!(widened.cast::<bf16>().unwrap().to_bits(), bits);
+                assert_eq!(value.cast::<f64>().lossless().cast::<bf16>().unwrap().to_bits(), bits);
+            }
+        }
+
+        // Casting to integers agrees with casting from the same value as f32
+        assert_eq!(value.cast::<i64>().is_ok(), widened.cast::<i64>().is_ok());
+        assert_eq!(value.cast::<u128>().closest(), widened.cast::<u128>().closest());
+        assert_eq!(value.cast::<i8>().wrapping(), widened.cast::<i8>().wrapping());
+    }
+}
+
+#[test]
+fn random() {
+    // Initialization: determine the initial seed
+    let mut random = crate::util::random_seed();
+
+    // Perform the tests
+    for _ in 0 .. crate::util::settings::FAST_ITERATIONS {
+        // Generate the test value and next random number
+        let (buffer, next_random) = crate::util::random_bytes(random);
+        let value = f32::from_ne_bytes(buffer);
+        random = next_random;
+
+        // Validate against the reference rounding, and that Closest only differs on overflow
+        let casted = value.cast::<bf16>();
+        match value.is_nan() {
+            true => assert!(casted.unwrap().is_nan()),
+            false => {
+                let expected = reference(value);
+                assert_eq!(casted.lossy().to_bits(), expected);
+                assert_eq!(casted.is_ok(), u32::from(expected) << 16 == value.to_bits());
+
+                match casted.lossy().is_infinite() && value.is_finite() {
+                    true => assert_eq!(casted.closest().to_bits() & 0x7fff, 0x7f7f),
+                    false => assert_eq!(casted.closest().to_bits(), expected)
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn integers() {
+    // Into bf16
+    assert_eq!(255u8.cast::<bf16>().lossless(), bf16::from_bits(0x437f));
+    let expected = 200u8.cast::<bf16>().lossless();
+    assert_eq!(NonZeroU8::new(200).unwrap().cast::<bf16>().lossless(), expected);
+    assert_eq!(256u16.cast::<bf16>().unwrap(), bf16::from_bits(0x4380));
+    assert_eq!(257u16.cast::<bf16>().lossy(), bf16::from_bits(0x4380));
+    assert_eq!(259i32.cast::<bf16>().lossy(), bf16::from_bits(0x4382));
+    assert_eq!(u128::MAX.cast::<bf16>().lossy(), bf16::from_bits(0x7f80));
+    assert_eq!(u128::MAX.cast::<bf16>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!(i128::MIN.cast::<bf16>().unwrap(), bf16::from_bits(0xff00));
+    assert_eq!(NonZeroI16::new(-257).unwrap().cast::<bf16>().closest(), bf16::from_bits(0xc380));
+
+    // Rounding an integer to f64 first would create a tie here and round down
+    let value = (1u64 << 60) + (1 << 52) + 1;
+    let expected = ((1u64 << 60) + (1 << 53)).cast::<bf16>().unwrap();
+    assert_eq!(value.cast::<bf16>().lossy(), expected);
+    assert_eq!(value.cast::<bf16>().unwrap_err().ulps(), -(1.0 - 2f64.powi(-52)) / 2.0);
+
+    // Out of bf16
+    let value = 2.5f32.cast::<bf16>().unwrap();
+    assert_eq!(value.cast::<u8>().closest(), 3u8);
+    assert_eq!(value.cast::<i32>().rounded(RoundingMode::TowardZero), 2i32);
+    assert_eq!(value.cast_split::<u8>().unwrap(), (2u8, 0.5f32.cast::<bf16>().unwrap()));
+    assert_eq!(bf16::MAX.cast::<u128>().unwrap(), 0xffu128 << 120);
+    assert_eq!(bf16::MAX.cast::<u64>().closest(), u64::MAX);
+    assert!(bf16::NAN.cast::<NonZeroU8>().is_err());
+}
+
+#[test]
+fn half() {
+    // Each of f16 and bf16 has values outside of the other
+    assert_eq!(f16::MAX.cast::<bf16>().lossy(), 65536u32.cast::<bf16>().unwrap());
+    assert_eq!(1e10f32.cast::<bf16>().lossy().cast::<f16>().closest(), f16::MAX);
+    assert_eq!(1e10f32.cast::<bf16>().lossy().cast::<f16>().lossy(), f16::INFINITY);
+    assert!(bf16::NAN.cast::<f16>().unwrap().is_nan());
+    assert_eq!(f16::from_bits(0x3c01).cast::<bf16>().unwrap_err().kind(), LossKind::Precision);
+    let expected = 1.5f32.cast::<bf16>().unwrap();
+    assert_eq!(1.5f32.cast::<f16>().unwrap().cast::<bf16>().unwrap(), expected);
+
+    // Bitwise reinterprets the bits to and from the 16-bit integers
+    assert_eq!(0x3f80u16.cast::<bf16>().bitwise(), bf16::from_bits(0x3f80));
+    assert_eq!(bf16::from_bits(0x3f80).cast::<i16>().bitwise(), 0x3f80i16);
+}
+
+#[test]
+fn nan_payloads() {
+    // Bitwise casts through the integers preserve the payloads of NaNs, which the numerical casts
+    // between f16 and bf16 do not
+    for bits in [0x7e01u16, 0x7c01, 0xfe55, 0x7fff] {
+        let value = f16::from_bits(bits);
+        assert_eq!(value.cast::<u16>().bitwise(), bits);
+        assert_eq!(value.cast::<i16>().bitwise().cast::<f16>().bitwise().to_bits(), bits);
+        assert_eq!(bits.cast::<f16>().bitwise().to_bits(), bits);
+        assert_eq!(bits.cast::<bf16>().bitwise().cast::<u16>().bitwise(), bits);
+        assert!(value.cast::<bf16>().unwrap().is_nan());
+    }
+
+    for bits in [0x7f81u16, 0x7fc1, 0xffaa] {
+        let value = bf16::from_bits(bits);
+        assert_eq!(value.cast::<u16>().bitwise(), bits);
+        assert_eq!(value.cast::<i16>().bitwise().cast::<bf16>().bitwise().to_bits(), bits);
+    }
+}
@@ -0,0 +1,137 @@
+//! These tests cover the integer types of arbitrary bit width `UInt` and `Int`
+
+use cove::prelude::*;
+use cove::casts::{RoundingMode, StochasticRng, Tolerance};
+use cove::errors::LossKind;
+use cove::types::{Int, UInt};
+use core::num::{NonZeroI8, NonZeroU16, NonZeroU8};
+
+/// Generator which always yields the same bits, for exercising Stochastic reproducibly
+struct Constant(u64);
+
+impl StochasticRng for Constant {
+    fn next_u64(&mut self) -> u64 {
+        self.0
+    }
+}
+
+#[test]
+fn constants() {
+    assert_eq!(UInt::<1>::MAX.get(), 1u8);
+    assert_eq!(UInt::<3>::MAX.get(), 7u8);
+    assert_eq!(UInt::<24>::MAX.get(), 0xff_ffffu32);
+    assert_eq!(UInt::<128>::MAX.get(), u128::MAX);
+    assert_eq!(UInt::<12>::MIN.get(), 0u16);
+    assert_eq!(Int::<1>::MIN.get(), -1i8);
+    assert_eq!(Int::<1>::MAX.get(), 0i8);
+    assert_eq!(Int::<12>::MIN.get(), -2048i16);
+    assert_eq!(Int::<12>::MAX.get(), 2047i16);
+    assert_eq!(Int::<128>::MIN.get(), i128::MIN);
+    assert_eq!(UInt::<17>::BITS, 17);
+
+    assert_eq!(UInt::<3>::new(8), None);
+    assert_eq!(Int::<3>::new(-5), None);
+    assert!(Int::<3>::new(-4).unwrap() < Int::<3>::new(3).unwrap());
+    assert_eq!(Int::<12>::new(-7).unwrap().to_string(), "-7");
+}
+
+#[test]
+fn exhaustive() {
+    for value in i16::MIN ..= i16::MAX {
+        // Into UInt<12>, checked against masking by hand
+        let casted = value.cast::<UInt<12>>();
+        let masked = (value as u16) & 0xfff;
+        assert_eq!(casted.is_ok(), (0 .. 4096).contains(&value));
+        assert_eq!(casted.lossy().get(), masked);
+        assert_eq!(casted.wrapping().get(), masked);
+        assert_eq!(casted.closest().get(), value.clamp(0, 4095) as u16);
+
+        // Into Int<12>, checked against sign extension by hand
+        let casted = value.cast::<Int<12>>();
+        let extended = ((value as u16) << 4) as i16 >> 4;
+        assert_eq!(casted.is_ok(), (-2048 .. 2048).contains(&value));
+        assert_eq!(casted.lossy().get(), extended);
+        assert_eq!(casted.closest().get(), value.clamp(-2048, 2047));
+
+        // Between widths, as for the primitives
+        let narrow = value.cast::<Int<12>>().lossy();
+        assert_eq!(narrow.cast::<i16>().lossless(), extended);
+        assert_eq!(narrow.cast::<i8>().closest(), extended.cast::<i8>().closest());
+        assert_eq!(narrow.cast::<Int<8>>().wrapping().get(), extended as i8);
+        assert_eq!(narrow.cast::<UInt<12>>().bitwise().get(), masked);
+        assert_eq!(narrow.cast::<UInt<12>>().bitwise().cast::<Int<12>>().bitwise(), narrow);
+        assert_eq!(narrow.cast::<UInt<4>>().closest().get(), extended.clamp(0, 15) as u8);
+        assert_eq!(narrow.cast::<Int<16>>().lossless().get(), extended);
+        assert_eq!(narrow.cast::<f32>().lossless(), f32::from(extended));
+    }
+}
+
+#[test]
+fn lossless() {
+    assert_eq!(200u8.cast::<UInt<8>>().lossless().get(), 200u8);
+    assert_eq!(200u8.cast::<Int<9>>().lossless().get(), 200i16);
+    assert_eq!((-100i8).cast::<Int<8>>().lossless().get(), -100i8);
+    assert_eq!(NonZeroU8::MAX.cast::<UInt<8>>().lossless(), UInt::<8>::MAX);
+    assert_eq!(NonZeroI8::MIN.cast::<Int<100>>().lossless().get(), -128i128);
+    assert_eq!(UInt::<24>::MAX.cast::<f32>().lossless(), 16_777_215f32);
+    assert_eq!(Int::<25>::MIN.cast::<f32>().lossless(), -16_777_216f32);
+    assert_eq!(UInt::<53>::MAX.cast::<f64>().lossless(), 2f64.powi(53) - 1.0);
+    assert_eq!(UInt::<128>::MAX.cast::<u128>().lossless(), u128::MAX);
+    assert_eq!(UInt::<7>::MAX.cast::<Int<8>>().lossless().get(), 127i8);
+    assert_eq!(UInt::<64>::MAX.cast::<UInt<65>>().lossless().get(), u128::from(u64::MAX));
+    assert_eq!(Int::<64>::MIN.cast::<i64>().lossless(), i64::MIN);
+}
+
+#[test]
+fn lossy() {
+    // Out of range
+    let error = 300u16.cast::<UInt<8>>().unwrap_err();
+    assert_eq!(error.to.get(), 44u8);
+    assert_eq!(error.kind(), LossKind::Overflow);
+    assert_eq!(error.loss(), 256.0);
+    assert_eq!((-1i32).cast::<UInt<5>>().unwrap_err().kind(), LossKind::Underflow);
+    assert_eq!(UInt::<9>::MAX.cast::<u8>().unwrap_err().to, u8::MAX);
+    assert_eq!(UInt::<128>::MAX.cast::<i128>().closest(), i128::MAX);
+    assert_eq!(Int::<128>::MIN.cast::<UInt<128>>().closest(), UInt::<128>::MIN);
+    assert_eq!(Int::<128>::MIN.cast::<UInt<128>>().wrapping().get(), 1 << 127);
+    assert_eq!(UInt::<128>::MAX.cast::<f32>().closest(), f32::MAX);
+    assert_eq!(UInt::<25>::MAX.cast::<f32>().lossy(), 2f32.powi(25));
+
+    // Nonzeros
+    assert_eq!(UInt::<3>::MIN.cast::<NonZeroU8>().closest(), NonZeroU8::MIN);
+    assert!(Int::<3>::new(0).unwrap().cast::<NonZeroI8>().is_err());
+    assert_eq!(UInt::<16>::MAX.cast::<NonZeroU16>().unwrap(), NonZeroU16::MAX);
+    assert_eq!(NonZeroU16::MAX.cast::<UInt<9>>().closest(), UInt::<9>::MAX);
+    assert_eq!(NonZeroU16::MAX.cast::<UInt<9>>().wrapping(), UInt::<9>::MAX);
+
+    // Within measures the lossy value as for the primitives
+    assert_eq!(15.5f32.cast::<UInt<4>>().within(Tolerance::Absolute(1.0)), Ok(UInt::<4>::MAX));
+    assert!(16.5f32.cast::<UInt<4>>().within(Tolerance::Absolute(1.0)).is_err());
+    assert!(16u8.cast::<UInt<4>>().within(Tolerance::Absolute(1.0)).is_err());
+}
+
+#[test]
+fn floats() {
+    assert_eq!(5f32.cast::<UInt<3>>().unwrap().get(), 5u8);
+    assert_eq!(5.5f32.cast::<UInt<3>>().unwrap_err().to.get(), 5u8);
+    assert_eq!(5.5f32.cast::<UInt<3>>().closest().get(), 6u8);
+    assert_eq!(5.5f64.cast::<UInt<3>>().rounded(RoundingMode::Floor).get(), 5u8);
+    assert_eq!(7.5f64.cast::<UInt<3>>().rounded(RoundingMode::Ceil).get(), 7u8);
+    assert_eq!(100f32.cast::<UInt<3>>().lossy(), UInt::<3>::MAX);
+    assert_eq!(100f32.cast::<UInt<3>>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!(12f32.cast::<UInt<3>>().wrapping().get(), 4u8);
+    assert_eq!((-1f64).cast::<UInt<3>>().closest(), UInt::<3>::MIN);
+    assert_eq!((-1f64).cast::<UInt<3>>().wrapping(), UInt::<3>::MAX);
+    assert_eq!(f32::NAN.cast::<Int<7>>().closest().get(), 0i8);
+    assert_eq!(f64::NEG_INFINITY.cast::<Int<7>>().lossy(), Int::<7>::MIN);
+    assert_eq!((-64f32).cast::<Int<7>>().unwrap(), Int::<7>::MIN);
+    assert_eq!((-2.5f32).cast::<Int<7>>().rounded(RoundingMode::HalfEven).get(), -2i8);
+    assert_eq!(u128::MAX.cast::<f64>().lossy().cast::<UInt<128>>().closest(), UInt::<128>::MAX);
+
+    // Stochastic rounding stays within the neighboring values and clamps to the width
+    let (mut low, mut high) = (Constant(0), Constant(u64::MAX));
+    assert_eq!(1.5f32.cast::<UInt<2>>().stochastic(&mut low).get(), 2u8);
+    assert_eq!(1.5f32.cast::<UInt<2>>().stochastic(&mut high).get(), 1u8);
+    assert_eq!(3.5f32.cast::<UInt<2>>().stochastic(&mut low), UInt::<2>::MAX);
+    assert_eq!((-0.5f64).cast::<Int<2>>().stochastic(&mut low).get(), -1i8);
+}
@@ -0,0 +1,50 @@
+//! These tests cover casts between bool and the primitive integers and NonZero types
+
+use cove::prelude::*;
+use cove::errors::LossKind;
+use core::num::{NonZeroI64, NonZeroU8, NonZeroU128, NonZeroIsize};
+
+#[test]
+fn into_bool() {
+    // Only zero and one are accepted, exhaustively for u8 and i8
+    for value in u8::MIN ..= u8::MAX {
+        assert_eq!(value.cast::<bool>().ok(), [false, true].get(usize::from(value)).copied());
+    }
+
+    for value in i8::MIN ..= i8::MAX {
+        assert_eq!(value.cast::<bool>().is_ok(), value == 0 || value == 1);
+    }
+
+    assert!(!0u128.cast::<bool>().unwrap());
+    assert!(1isize.cast::<bool>().unwrap());
+    assert!(NonZeroU8::new(1).unwrap().cast::<bool>().unwrap());
+    assert!(NonZeroI64::new(-1).unwrap().cast::<bool>().is_err());
+    assert_eq!(2u32.cast::<bool>().unwrap_err().from, 2);
+}
+
+#[test]
+fn from_bool() {
+    assert_eq!(true.cast::<u8>().lossless(), 1);
+    assert_eq!(false.cast::<i128>().portable_lossless(), 0);
+    assert_eq!(true.cast::<usize>().portable_lossless(), 1);
+    assert_eq!(true.cast::<isize>().lossless(), 1);
+    assert_eq!(true.cast::<NonZeroU128>().unwrap().get(), 1);
+    assert!(false.cast::<NonZeroIsize>().is_err());
+    assert_eq!(false.cast::<NonZeroIsize>().closest().get(), 1);
+}
+
+#[test]
+fn closest() {
+    assert!(2u8.cast::<bool>().closest());
+    assert!(u128::MAX.cast::<bool>().closest());
+    assert!(!(-1i32).cast::<bool>().closest());
+    assert!(!i64::MIN.cast::<bool>().closest());
+    assert!(NonZeroI64::new(7).unwrap().cast::<bool>().closest());
+}
+
+#[test]
+fn errors() {
+    assert_eq!(2u8.cast::<bool>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!((-1i16).cast::<bool>().unwrap_err().kind(), LossKind::Underflow);
+    assert!(2u8.cast::<bool>().unwrap_err().to_string().contains('2'));
+}
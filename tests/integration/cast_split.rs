@@ -0,0 +1,100 @@
+//! These tests cover the `CastSplit` trait for floating point primitives to integers
+
+use cove::prelude::*;
+
+#[test]
+fn split() {
+    assert_eq!(7.25f64.cast_split::<u32>(), Ok((7u32, 0.25f64)));
+    assert_eq!((-7.25f32).cast_split::<i64>(), Ok((-7i64, -0.25f32)));
+    assert_eq!(3f32.cast_split::<u8>(), Ok((3u8, 0f32)));
+    assert_eq!(0.1f64.cast_split::<i128>(), Ok((0i128, 0.1f64)));
+    assert_eq!(1e-40f32.cast_split::<usize>(), Ok((0usize, 1e-40f32)));
+    assert_eq!(1e300f64.cast_split::<u128>().unwrap_err().to, u128::MAX);
+}
+
+#[test]
+fn exact() {
+    // The remainder is exact even where it has few significant bits left
+    let value = 8_388_607.5f32;
+    assert_eq!(value.cast_split::<u32>(), Ok((8_388_607u32, 0.5f32)));
+
+    let value = 4_503_599_627_370_495.5f64;
+    assert_eq!(value.cast_split::<i64>(), Ok((4_503_599_627_370_495i64, 0.5f64)));
+}
+
+#[test]
+fn bounds() {
+    assert_eq!(255.75f64.cast_split::<u8>(), Ok((255u8, 0.75f64)));
+    assert_eq!(256f64.cast_split::<u8>().unwrap_err().to, 255u8);
+    assert_eq!((-0.75f32).cast_split::<u16>(), Ok((0u16, -0.75f32)));
+    assert_eq!((-1f32).cast_split::<u16>().unwrap_err().to, 0u16);
+    assert_eq!((-128.5f64).cast_split::<i8>(), Ok((-128i8, -0.5f64)));
+    assert!((-129f64).cast_split::<i8>().is_err());
+
+    // Bounds which are not exactly representable as floats
+    assert_eq!(4_294_967_040f32.cast_split::<u32>(), Ok((4_294_967_040u32, 0f32)));
+    assert!(4_294_967_296f32.cast_split::<u32>().is_err());
+    assert_eq!((-2_147_483_648f32).cast_split::<i32>(), Ok((i32::MIN, 0f32)));
+    assert!((-2_147_483_904f32).cast_split::<i32>().is_err());
+    assert!(170_141_183_460_469_231_731_687_303_715_884_105_728f64.cast_split::<i128>().is_err());
+    assert_eq!(f32::MAX.cast_split::<u128>(), Ok((f32::MAX as u128, 0f32)));
+}
+
+#[test]
+fn special() {
+    assert_eq!(f32::INFINITY.cast_split::<u128>().unwrap_err().to, u128::MAX);
+    assert_eq!(f64::NEG_INFINITY.cast_split::<i16>().unwrap_err().to, i16::MIN);
+    assert_eq!(f64::NAN.cast_split::<u64>().unwrap_err().to, 0u64);
+    assert_eq!(f32::NAN.cast_split::<i8>().unwrap_err().closest(), 0i8);
+}
+
+macro_rules! random {
+    ($name:ident as $source:ty => $($target:ty),+) => {
+        #[test]
+        #[allow(clippy::cast_possible_truncation, clippy::float_cmp)]
+        fn $name () {
+            // Initialization: determine the initial seed
+            let mut random = crate::util::random_seed();
+
+            // Perform the tests
+            for _ in 0 .. crate::util::settings::FAST_ITERATIONS {
+                // Generate the test values and next random number; random bits rarely yield
+                // values with a fractional part, so also generate a value in units of 1/8
+                let (buffer, next_random) = crate::util::random_bytes(random);
+                let value = <$source>::from_ne_bytes(buffer);
+                let eighths = <$source>::from(random as i16) / 8.0;
+                random = next_random;
+
+                // Validate that the split agrees with truncation via std whenever the truncated
+                // value can be cast, and that it otherwise fails as the cast does
+                $(
+                    for value in [value, eighths] {
+                        match value.trunc().cast::<$target>() {
+                            Ok(integral) => assert_eq!(
+                                value.cast_split::<$target>(),
+                                Ok((integral, value.fract()))
+                            ),
+
+                            Err(_) => assert_eq!(
+                                value.cast_split::<$target>().unwrap_err().to,
+                                value.cast::<$target>().unwrap_err().to
+                            )
+                        }
+                    }
+                )*
+            }
+        }
+    };
+}
+
+random!(
+    random_f32 as f32 =>
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize
+);
+
+random!(
+    random_f64 as f64 =>
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize
+);
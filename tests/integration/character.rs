@@ -0,0 +1,72 @@
+//! These tests cover casts between char and the primitive integers and NonZero types
+
+use cove::prelude::*;
+use cove::errors::LossKind;
+use core::num::{NonZeroI8, NonZeroU8, NonZeroU32};
+
+#[test]
+fn into_char() {
+    // Every scalar value is accepted, exhaustively for u32
+    for value in 0 ..= 0x11_0000u32 {
+        assert_eq!(value.cast::<char>().ok(), char::from_u32(value));
+    }
+
+    assert_eq!(0x41i64.cast::<char>().unwrap(), 'A');
+    assert_eq!(0x1f600u128.cast::<char>().unwrap(), '😀');
+    assert_eq!(NonZeroU32::new(0x10_ffff).unwrap().cast::<char>().unwrap(), char::MAX);
+    assert_eq!(u8::MAX.cast::<char>().lossless(), 'ÿ');
+    assert_eq!(NonZeroU8::new(b'a').unwrap().cast::<char>().portable_lossless(), 'a');
+
+    // Surrogates, values above char::MAX and negative values fail
+    for value in [0xd800u32, 0xdfff, 0x11_0000, u32::MAX] {
+        assert_eq!(value.cast::<char>().unwrap_err().from, value);
+    }
+
+    assert!((-1i8).cast::<char>().is_err());
+    assert!(NonZeroI8::new(-65).unwrap().cast::<char>().is_err());
+    assert!(u64::MAX.cast::<char>().is_err());
+}
+
+#[test]
+fn from_char() {
+    assert_eq!('A'.cast::<u32>().lossless(), 0x41);
+    assert_eq!(char::MAX.cast::<i32>().portable_lossless(), 0x10_ffff);
+    assert_eq!(char::MAX.cast::<u128>().lossless(), 0x10_ffff);
+    assert_eq!('😀'.cast::<NonZeroU32>().unwrap().get(), 0x1f600);
+    assert!('\0'.cast::<NonZeroU32>().is_err());
+    assert_eq!('\0'.cast::<NonZeroU32>().closest().get(), 1);
+
+    #[cfg(not(target_pointer_width = "16"))]
+    assert_eq!(char::MAX.cast::<usize>().lossless(), 0x10_ffff);
+
+    // Narrow integers are exact within range and otherwise truncate as for the as keyword
+    assert_eq!('ÿ'.cast::<u8>().unwrap(), 0xff);
+    assert_eq!('ÿ'.cast::<i8>().unwrap_err().to, -1);
+    assert_eq!('Ā'.cast::<u8>().lossy(), 0);
+    assert_eq!('Ā'.cast::<u8>().closest(), u8::MAX);
+    assert_eq!('😀'.cast::<u16>().lossy(), 0xf600);
+    assert_eq!('😀'.cast::<i16>().closest(), i16::MAX);
+    assert_eq!('😀'.cast::<NonZeroU8>().closest(), NonZeroU8::MAX);
+}
+
+#[test]
+fn closest() {
+    // Invalid scalar values are replaced
+    assert_eq!(0xd800u32.cast::<char>().closest(), char::REPLACEMENT_CHARACTER);
+    assert_eq!(u128::MAX.cast::<char>().closest(), char::REPLACEMENT_CHARACTER);
+    assert_eq!((-1isize).cast::<char>().closest(), char::REPLACEMENT_CHARACTER);
+    assert_eq!(0x263au16.cast::<char>().closest(), '☺');
+}
+
+#[test]
+fn errors() {
+    assert_eq!(0xd800u32.cast::<char>().unwrap_err().kind(), LossKind::Invalid);
+    assert_eq!(0xdfffi64.cast::<char>().unwrap_err().kind(), LossKind::Invalid);
+    assert_eq!(0x11_0000u32.cast::<char>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!((-1i32).cast::<char>().unwrap_err().kind(), LossKind::Underflow);
+    assert_eq!('Ā'.cast::<u8>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!(LossKind::Invalid.to_string(), "invalid");
+
+    let error: Box<dyn std::error::Error> = Box::new(0xd800u32.cast::<char>().unwrap_err());
+    assert!(error.to_string().contains("55296"), "{error}");
+}
@@ -0,0 +1,106 @@
+//! These tests cover the complex type `Complex`, checking that it casts each component as the
+//! component types do and reports the loss of each
+
+use cove::prelude::*;
+use cove::errors::{FailedCastError, LossKind, LossyCastError};
+use cove::types::{Complex, Ranged};
+use core::any::type_name;
+use core::num::NonZeroI16;
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn components() {
+    // Every pair of values casts as its components do
+    let values = [0.0, -0.5, 1.5, 2.5, 40_000.0, -1e10, 0.1, f64::NAN, f64::INFINITY];
+
+    for re in values {
+        for im in values {
+            let result = Complex::new(re, im).cast::<Complex<i16>>();
+            let (re_result, im_result) = (re.cast::<i16>(), im.cast::<i16>());
+
+            assert_eq!(result.is_ok(), re_result.is_ok() && im_result.is_ok());
+            assert_eq!(result.lossy(), Complex::new(re_result.lossy(), im_result.lossy()));
+            assert_eq!(result.closest(), Complex::new(re_result.closest(), im_result.closest()));
+
+            if let Err(error) = result {
+                let kinds = error.kinds();
+                assert_eq!(kinds.re, re_result.err().map(|error| error.kind()));
+                assert_eq!(kinds.im, im_result.err().map(|error| error.kind()));
+
+                let losses = error.losses();
+                assert!(losses.re == re_result.err().map_or(0.0, |error| error.loss().to_f64())
+                    || losses.re.to_f64().is_nan());
+            }
+
+            let result = Complex::new(re, im).cast::<Complex<f32>>();
+            assert_eq!(result.is_ok(), re.cast::<f32>().is_ok() && im.cast::<f32>().is_ok());
+            assert_eq!(result.closest().re.to_bits(), re.cast::<f32>().closest().to_bits());
+        }
+    }
+}
+
+#[test]
+fn lossless() {
+    // The follow-on traits follow those of the components
+    let sample = Complex::new(i16::MIN, i16::MAX);
+    assert_eq!(sample.cast::<Complex<f32>>().lossless(), Complex::new(-32_768.0, 32_767.0));
+    assert_eq!(sample.cast::<Complex<i64>>().portable_lossless(), Complex::new(-32_768, 32_767));
+    assert_eq!(Complex::new(1.5f32, 2.0).cast::<Complex<f64>>().lossless().im, 2.0);
+
+    let value = Complex::new(7u8, 200).cast::<Complex<Ranged<u8, 0, 255>>>().lossless();
+    assert_eq!((value.re.get(), value.im.get()), (7, 200));
+}
+
+#[test]
+fn errors() {
+    let error = Complex::new(300u16, 20).cast::<Complex<u8>>().unwrap_err();
+    assert_eq!(error, LossyCastError {from: Complex::new(300, 20), to: Complex::new(44, 20)});
+    assert_eq!(error.kinds(), Complex::new(Some(LossKind::Overflow), None));
+    assert_eq!((error.losses().re.to_f64(), error.losses().im.to_f64()), (256.0, 0.0));
+    assert_eq!(error.closest(), Complex::new(255, 20));
+
+    let (from, to) = (type_name::<Complex<u16>>(), type_name::<Complex<u8>>());
+    assert_eq!(
+        error.to_string(),
+        format!("Numerical cast was lossy [300+20i ({from}) -> 44+20i ({to})]")
+    );
+
+    // The detailed message names the kind of loss of each component
+    assert_eq!(
+        error.detailed().to_string(),
+        format!(
+            "Numerical cast was lossy (re: overflow, im: exact) [300+20i ({from}) -> 44+20i ({to})]"
+        )
+    );
+
+    let error = Complex::new(2.5f64, -1e10).cast::<Complex<i32>>().unwrap_err();
+    let (from, to) = (type_name::<Complex<f64>>(), type_name::<Complex<i32>>());
+    assert_eq!(
+        error.detailed().to_string(),
+        format!(
+            "Numerical cast was lossy (re: fraction, im: underflow) [2.5-10000000000i ({from}) -> \
+                2-2147483648i ({to})]"
+        )
+    );
+
+    // Failed casts keep the origin value, and Closest follows the components
+    let error = Complex::new(5i32, 0).cast::<Complex<NonZeroI16>>().unwrap_err();
+    assert_eq!(error, FailedCastError::new(Complex::new(5, 0)));
+    assert_eq!(error.kinds(), Complex::new(None, Some(LossKind::Zero)));
+    assert_eq!(error.closest().re, NonZeroI16::new(5).unwrap());
+    assert_eq!(error.closest().im, 0i32.cast::<NonZeroI16>().closest());
+    let (from, to) = (type_name::<Complex<i32>>(), type_name::<Complex<NonZeroI16>>());
+    assert_eq!(error.to_string(), format!("Numerical cast failed [5+0i ({from}) -> ({to})]"));
+    assert_eq!(
+        error.detailed().to_string(),
+        format!("Numerical cast failed (re: exact, im: zero) [5+0i ({from}) -> ({to})]")
+    );
+}
+
+#[test]
+fn display() {
+    assert_eq!(Complex::new(1.5f64, -2.0).to_string(), "1.5-2i");
+    assert_eq!(Complex::new(0i8, 3).to_string(), "0+3i");
+    assert_eq!(format!("{:.2}", Complex::new(0.125f32, 1.0)), "0.12+1.00i");
+    assert_eq!(Complex::<u8>::default(), Complex::new(0, 0));
+}
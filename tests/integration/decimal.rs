@@ -0,0 +1,192 @@
+//! These tests cover the scaled decimal type `Decimal`, checking it exhaustively against integer
+//! and f64 arithmetic for 16-bit underlying integers
+
+use cove::prelude::*;
+use cove::errors::{LossKind, LossyCastError};
+use cove::types::Decimal;
+
+type Cents = Decimal<i64, 2>;
+
+/// Divides `value` by `divisor`, rounding to nearest with ties to even
+fn even(value: i32, divisor: i32) -> i32 {
+    let (quotient, remainder) = (value / divisor, value % divisor);
+
+    match (2 * remainder.abs()).cmp(&divisor) {
+        core::cmp::Ordering::Less => quotient,
+        core::cmp::Ordering::Equal if quotient % 2 == 0 => quotient,
+        _ => quotient + value.signum()
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn exhaustive() {
+    for bits in i16::MIN ..= i16::MAX {
+        let decimal = Decimal::<i16, 2>::from_bits(bits);
+        let value = f64::from(bits) / 100.0;
+
+        // Into and from floats, which correspond to every value with so few digits
+        assert_eq!(decimal.cast::<f64>().unwrap(), value);
+        assert_eq!(decimal.cast::<f32>().unwrap(), f32::from(bits) / 100.0);
+        assert_eq!(value.cast::<Decimal<i16, 2>>().unwrap(), decimal);
+        assert_eq!((f32::from(bits) / 100.0).cast::<Decimal<i16, 2>>().unwrap(), decimal);
+
+        // Into integers, truncating and then wrapping or rounding and then saturating
+        let casted = decimal.cast::<i8>();
+        let integral = i32::from(bits) / 100;
+        assert_eq!(casted.is_ok(), bits % 100 == 0 && i8::try_from(integral).is_ok());
+        assert_eq!(casted.lossy(), integral as i8);
+        assert_eq!(casted.closest(), even(i32::from(bits), 100).clamp(-128, 127) as i8);
+        assert_eq!(decimal.cast::<u64>().closest(), even(i32::from(bits), 100).max(0) as u64);
+
+        // Between scales, rounding with ties to even when scaling down and saturating when up
+        let casted = decimal.cast::<Decimal<i16, 1>>();
+        assert_eq!(casted.is_ok(), bits % 10 == 0);
+        assert_eq!(casted.lossy().to_bits(), bits / 10);
+        assert_eq!(casted.closest().to_bits(), even(i32::from(bits), 10) as i16);
+        assert_eq!(decimal.cast::<Decimal<i32, 3>>().lossless().to_bits(), i32::from(bits) * 10);
+
+        let casted = decimal.cast::<Decimal<i16, 3>>();
+        assert_eq!(casted.is_ok(), (-3276 ..= 3276).contains(&bits));
+        assert_eq!(casted.lossy().to_bits(), bits.wrapping_mul(10));
+        assert_eq!(casted.closest().to_bits(), (i32::from(bits) * 10).clamp(-32768, 32767) as i16);
+    }
+}
+
+#[test]
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+fn correspondence() {
+    // Floats and decimals correspond in both directions or in neither
+    let mut state = 0x9e37_79b9_7f4a_7c15u64;
+
+    for _ in 0 .. 100_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        let decimal = Decimal::<i64, 9>::from_bits((state as i64) >> (state % 48));
+        if let Ok(float) = decimal.cast::<f32>() {
+            assert_eq!(float.cast::<Decimal<i64, 9>>().unwrap(), decimal);
+        }
+
+        let float = f32::from_bits(state as u32 & 0x5fff_ffff);
+        if let Ok(decimal) = float.cast::<Decimal<i64, 9>>() {
+            assert_eq!(decimal.cast::<f32>().unwrap(), float);
+        }
+
+        let float = f64::from_bits(state & 0x43ff_ffff_ffff_ffff);
+        if let Ok(decimal) = float.cast::<Decimal<i128, 20>>() {
+            assert_eq!(decimal.cast::<f64>().unwrap(), float);
+        }
+    }
+}
+
+#[test]
+fn from_floats() {
+    // Floats correspond to the decimal of which they are the nearest float
+    assert_eq!(12.34f64.cast::<Cents>().unwrap().to_bits(), 1234);
+    assert_eq!((-0.07f64).cast::<Cents>().unwrap().to_bits(), -7);
+    assert_eq!(0.1f32.cast::<Decimal<i32, 1>>().unwrap().to_bits(), 1);
+    assert_eq!(0.1f32.cast::<Decimal<i64, 9>>().unwrap().to_bits(), 100_000_001);
+    assert_eq!((-0.0f64).cast::<Decimal<u8, 2>>().unwrap().to_bits(), 0);
+    assert_eq!(1e20f64.cast::<Decimal<u128, 18>>().unwrap().to_bits(), 10u128.pow(38));
+
+    // Values with too many places are lossy, truncating the exact value of the float but rounding
+    // it with ties to even for Closest
+    let error = 12.345f64.cast::<Cents>().unwrap_err();
+    assert_eq!(error, LossyCastError {from: 12.345, to: Cents::from_bits(1234)});
+    assert_eq!(error.kind(), LossKind::Precision);
+    assert!((error.loss().to_f64() - 0.005).abs() < 1e-12);
+    assert_eq!(error.closest().to_bits(), 1235);
+    assert_eq!(0.125f64.cast::<Cents>().closest().to_bits(), 12);
+    assert_eq!(0.375f64.cast::<Cents>().closest().to_bits(), 38);
+    assert_eq!((-0.125f64).cast::<Cents>().closest().to_bits(), -12);
+    assert_eq!(2.675f64.cast::<Cents>().closest().to_bits(), 267);
+    assert_eq!(1e-300f64.cast::<Cents>().unwrap_err().to.to_bits(), 0);
+
+    // Values out of range saturate, and NaN yields zero
+    let error = 1e300f64.cast::<Cents>().unwrap_err();
+    assert_eq!(error.to, Cents::MAX);
+    assert_eq!(error.kind(), LossKind::Overflow);
+    assert_eq!(f64::NEG_INFINITY.cast::<Cents>().closest(), Cents::MIN);
+    assert_eq!((-1.5f64).cast::<Decimal<u8, 1>>().closest().to_bits(), 0);
+    assert_eq!(2.56f32.cast::<Decimal<u8, 2>>().closest().to_bits(), u8::MAX);
+    assert_eq!(f64::NAN.cast::<Cents>().unwrap_err().kind(), LossKind::NaN);
+    assert_eq!(f64::NAN.cast::<Cents>().closest().to_bits(), 0);
+}
+
+#[test]
+fn into_floats() {
+    assert_eq!(Cents::from_bits(1234).cast::<f64>().unwrap(), 12.34);
+    assert_eq!(Cents::from_bits(-1).cast::<f32>().unwrap(), -0.01);
+    assert_eq!(Decimal::<u128, 0>::MAX.cast::<f64>().unwrap_err().to, 2f64.powi(128));
+
+    // Floats lacking the precision to identify the decimal are lossy
+    let decimal = Decimal::<i64, 4>::from_bits(123_456_789);
+    let error = decimal.cast::<f32>().unwrap_err();
+    assert_eq!(error.to, 12_345.679);
+    assert_eq!(error.kind(), LossKind::Precision);
+    assert_eq!(decimal.cast::<f64>().unwrap(), 12_345.678_9);
+
+    // Values beyond the range of f32 overflow to infinity, but Closest saturates
+    let error = Decimal::<u128, 0>::MAX.cast::<f32>().unwrap_err();
+    assert_eq!(error.to, f32::INFINITY);
+    assert_eq!(error.closest(), f32::MAX);
+}
+
+#[test]
+fn integers() {
+    // Casts from integers detect overflow, wrapping as for the `as` keyword or saturating
+    assert_eq!(25i32.cast::<Cents>().lossless().to_bits(), 2500);
+    assert_eq!(255u8.cast::<Decimal<u16, 2>>().lossless().to_bits(), 25_500);
+    assert_eq!(300i32.cast::<Decimal<i16, 2>>().unwrap().to_bits(), 30_000);
+
+    let error = 400i32.cast::<Decimal<i16, 2>>().unwrap_err();
+    assert_eq!(error, LossyCastError {from: 400, to: Decimal::<i16, 2>::from_bits(-25_536)});
+    assert_eq!(error.kind(), LossKind::Overflow);
+    assert_eq!(error.closest(), Decimal::<i16, 2>::MAX);
+    assert_eq!((-1i8).cast::<Decimal<u32, 3>>().closest().to_bits(), 0);
+    assert_eq!(u64::MAX.cast::<Cents>().closest(), Cents::MAX);
+
+    // Casts into integers detect fractional parts and range
+    assert_eq!(Decimal::<i16, 0>::MIN.cast::<i32>().lossless(), -32_768);
+    assert_eq!(Cents::from_bits(-250).cast::<i32>().closest(), -2);
+    assert_eq!(Cents::from_bits(-350).cast::<i32>().closest(), -4);
+    assert_eq!(Cents::from_bits(1299).cast::<u8>().lossy(), 12);
+
+    let error = Cents::from_bits(1299).cast::<u8>().unwrap_err();
+    assert_eq!(error.kind(), LossKind::Fraction);
+    assert_eq!(error.closest(), 13);
+    assert_eq!(Cents::MAX.cast::<i128>().unwrap_err().to, i128::from(i64::MAX / 100));
+}
+
+#[test]
+fn scales() {
+    let value = Decimal::<i128, 38>::from_bits(5 * 10i128.pow(37));
+    assert_eq!(value.cast::<Decimal<u8, 0>>().closest().to_bits(), 0);
+    assert_eq!(value.cast::<Decimal<u8, 1>>().unwrap().to_bits(), 5);
+    let value = Decimal::<u8, 0>::MAX;
+    assert_eq!(value.cast::<Decimal<u128, 36>>().lossless().to_bits(), 255 * 10u128.pow(36));
+    assert_eq!(value.cast::<Decimal<i128, 38>>().closest(), Decimal::<i128, 38>::MAX);
+
+    // Bitwise reinterprets the underlying integer
+    assert_eq!(1234i64.cast::<Cents>().bitwise(), Cents::from_bits(1234));
+    assert_eq!(12i64.cast::<Cents>().bitwise(), Cents::from_bits(12));
+    assert_eq!(Cents::from_bits(1200).cast::<i64>().bitwise(), 1200);
+    assert_eq!(Cents::from_bits(1234).cast::<i64>().bitwise(), 1234);
+    assert_eq!(Decimal::<i8, 3>::from_bits(-7).cast::<i8>().bitwise(), -7);
+}
+
+#[test]
+fn display() {
+    assert_eq!(Cents::from_bits(1234).to_string(), "12.34");
+    assert_eq!(Cents::from_bits(1200).to_string(), "12.00");
+    assert_eq!(Cents::from_bits(-5).to_string(), "-0.05");
+    assert_eq!(Decimal::<u8, 0>::MAX.to_string(), "255");
+    assert_eq!(Decimal::<i128, 38>::MIN.to_string(), "-1.70141183460469231731687303715884105728");
+    assert_eq!(format!("{:>+8}", Cents::from_bits(705)), "   +7.05");
+
+    // Error messages describe the values
+    let message = 0.125f64.cast::<Cents>().unwrap_err().to_string();
+    assert!(message.contains("0.12"), "{message}");
+}
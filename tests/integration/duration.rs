@@ -0,0 +1,143 @@
+//! These tests cover casts between Duration and the time unit types
+
+use cove::prelude::*;
+use cove::casts::Tolerance;
+use cove::errors::LossKind;
+use cove::types::{Micros, Millis, Nanos, Secs};
+use core::time::Duration;
+
+#[test]
+fn into_integer_units() {
+    for micros in 0 ..= 100_000u64 {
+        let duration = Duration::from_micros(micros);
+        let exact = micros % 1_000 == 0;
+
+        let expected = Micros(u32::try_from(micros).unwrap());
+        assert_eq!(duration.cast::<Micros<u32>>().unwrap(), expected);
+        assert_eq!(duration.cast::<Nanos<u64>>().unwrap(), Nanos(micros * 1_000));
+        assert_eq!(duration.cast::<Millis<u16>>().is_ok(), exact);
+        assert_eq!(duration.cast::<Millis<u16>>().lossy(), Millis((micros / 1_000) as u16));
+        assert_eq!(duration.cast::<Millis<i8>>().lossy(), Millis((micros / 1_000) as i8));
+        assert_eq!(
+            duration.cast::<Millis<i8>>().closest(),
+            Millis(((micros + 500) / 1_000).min(127) as i8)
+        );
+    }
+
+    assert_eq!(Duration::MAX.cast::<Nanos<u128>>().lossless(), Nanos(Duration::MAX.as_nanos()));
+    let nanos = i128::try_from(Duration::MAX.as_nanos()).unwrap();
+    assert_eq!(Duration::MAX.cast::<Nanos<i128>>().portable_lossless(), Nanos(nanos));
+    assert_eq!(Duration::MAX.cast::<Secs<u64>>().lossy(), Secs(u64::MAX));
+    assert_eq!(Duration::MAX.cast::<Secs<u64>>().closest(), Secs(u64::MAX));
+    assert_eq!(Duration::MAX.cast::<Secs<i64>>().closest(), Secs(i64::MAX));
+    assert_eq!(Duration::MAX.cast::<Millis<u32>>().closest(), Millis(u32::MAX));
+    assert_eq!(Duration::new(1, 500_000_000).cast::<Secs<u8>>().closest(), Secs(2));
+    assert_eq!(Duration::new(1, 499_999_999).cast::<Secs<u8>>().closest(), Secs(1));
+}
+
+#[test]
+#[allow(clippy::float_cmp, clippy::cast_precision_loss)]
+fn into_float_units() {
+    // Quotients are rounded correctly, and are exact if and only if they cast back exactly
+    for nanos in (0 .. 1u64 << 52).step_by(0x0123_4567_89ab) {
+        let duration = Duration::from_nanos(nanos);
+        let secs = duration.cast::<Secs<f64>>();
+        assert_eq!(secs.lossy(), Secs(nanos as f64 / 1e9));
+        assert_eq!(secs.closest(), secs.lossy());
+        assert_eq!(secs.is_ok(), secs.lossy().cast::<Duration>() == Ok(duration));
+
+        let millis = duration.cast::<Millis<f32>>().lossy();
+        assert_eq!(millis, Millis((nanos as f64 / 1e6) as f32));
+    }
+
+    assert_eq!(Duration::from_millis(1_500).cast::<Secs<f32>>().unwrap(), Secs(1.5));
+    assert_eq!(Duration::from_nanos(1).cast::<Secs<f64>>().lossy(), Secs(1e-9));
+    assert_eq!(Duration::MAX.cast::<Nanos<f32>>().lossy(), Nanos(1.844_674_4e28));
+    assert_eq!(Duration::new(u64::MAX, 0).cast::<Secs<f64>>().lossy(), Secs(2f64.powi(64)));
+}
+
+#[test]
+fn from_integer_units() {
+    assert_eq!(Secs(3u8).cast::<Duration>().lossless(), Duration::from_secs(3));
+    let millis = Duration::from_millis(u64::MAX);
+    assert_eq!(Millis(u64::MAX).cast::<Duration>().portable_lossless(), millis);
+    assert_eq!(Nanos(7usize).cast::<Duration>().lossless(), Duration::from_nanos(7));
+    assert_eq!(Secs(u64::MAX).cast::<Duration>().lossless(), Duration::from_secs(u64::MAX));
+    assert_eq!(Micros(-5i32).cast::<Duration>().unwrap_err().to, Duration::ZERO);
+    assert_eq!(Micros(5i32).cast::<Duration>().unwrap(), Duration::from_micros(5));
+    assert_eq!(Secs(u128::MAX).cast::<Duration>().closest(), Duration::MAX);
+    assert_eq!(Secs(i128::MIN).cast::<Duration>().closest(), Duration::ZERO);
+    assert_eq!(Nanos(Duration::MAX.as_nanos()).cast::<Duration>().unwrap(), Duration::MAX);
+    assert!(Nanos(Duration::MAX.as_nanos() + 1).cast::<Duration>().is_err());
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn from_float_units() {
+    // Multiples of 2^-10 seconds are whole nanoseconds when even and halfway between when odd
+    for k in 0 .. 100_000u32 {
+        let secs = Secs(f64::from(k) / 1024.0);
+        let nanos = Duration::from_nanos(u64::from(k) * 1_953_125 / 2);
+        let cast = secs.cast::<Duration>();
+
+        assert_eq!(cast.is_ok(), k % 2 == 0);
+        assert_eq!(cast.lossy(), nanos);
+        assert_eq!(cast.closest(), nanos + Duration::from_nanos(u64::from(k % 2)));
+
+        let millis = Millis(f64::from(k) / 4.0).cast::<Duration>();
+        assert_eq!(millis.unwrap(), Duration::from_micros(u64::from(k) * 250));
+
+        let earlier = Secs(f64::from(k) / 1024.0 - 1.0).cast::<Duration>();
+        assert_eq!(earlier.lossy(), match k >= 1024 {
+            true => Duration::from_nanos(u64::from(k - 1024) * 1_953_125 / 2),
+            false => Duration::ZERO
+        });
+    }
+
+    // The error term of the product decides the floor and rounding of large values
+    assert_eq!(Secs(1.5f64).cast::<Duration>().unwrap(), Duration::from_millis(1_500));
+    assert_eq!(Secs(0.1f64).cast::<Duration>().lossy(), Duration::from_nanos(100_000_000));
+    assert_eq!(Secs(0.3f64).cast::<Duration>().lossy(), Duration::from_nanos(299_999_999));
+    assert_eq!(Secs(0.3f64).cast::<Duration>().closest(), Duration::from_nanos(300_000_000));
+    assert_eq!(Secs(1e-10f64).cast::<Duration>().lossy(), Duration::ZERO);
+    assert_eq!(Secs(6e-10f64).cast::<Duration>().closest(), Duration::from_nanos(1));
+    assert_eq!(Nanos(0.5f32).cast::<Duration>().closest(), Duration::from_nanos(1));
+    assert_eq!(Secs(-0.0f64).cast::<Duration>().unwrap(), Duration::ZERO);
+    assert_eq!(Secs(2f64.powi(64)).cast::<Duration>().closest(), Duration::MAX);
+    assert_eq!(Secs(f64::INFINITY).cast::<Duration>().lossy(), Duration::MAX);
+    assert_eq!(Secs(f64::MAX).cast::<Duration>().closest(), Duration::MAX);
+    assert_eq!(Millis(f64::NAN).cast::<Duration>().closest(), Duration::ZERO);
+    assert_eq!(Secs(-1.5f32).cast::<Duration>().closest(), Duration::ZERO);
+    assert_eq!(Secs(1.844_674_407_370_955_2e19).cast::<Duration>().lossy(), Duration::MAX);
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn errors() {
+    // Loss is classified and measured in nanoseconds
+    let error = Duration::from_micros(1_500).cast::<Millis<u32>>().unwrap_err();
+    assert_eq!(error.kind(), LossKind::Fraction);
+    assert_eq!(error.loss(), 500_000.0);
+    assert_eq!(Duration::MAX.cast::<Millis<u32>>().unwrap_err().kind(), LossKind::Overflow);
+    let error = Duration::from_nanos(1).cast::<Secs<f64>>().unwrap_err();
+    assert_eq!(error.kind(), LossKind::Precision);
+    assert_eq!(Secs(-1i8).cast::<Duration>().unwrap_err().kind(), LossKind::Underflow);
+    assert_eq!(Secs(-1i8).cast::<Duration>().unwrap_err().loss(), -1e9);
+    assert_eq!(Secs(u128::MAX).cast::<Duration>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!(Secs(f64::NAN).cast::<Duration>().unwrap_err().kind(), LossKind::NaN);
+    assert_eq!(Secs(f32::INFINITY).cast::<Duration>().unwrap_err().kind(), LossKind::Infinity);
+    assert_eq!(Secs(1e20f64).cast::<Duration>().unwrap_err().kind(), LossKind::Overflow);
+
+    let error = Secs(2f64.powi(-30)).cast::<Duration>().unwrap_err();
+    assert_eq!(error.kind(), LossKind::Fraction);
+    assert_eq!(error.loss(), 1e9 / 2f64.powi(30));
+
+    // Within accepts loss within a tolerance, measured in nanoseconds
+    let cast = Secs(0.3f64).cast::<Duration>();
+    assert!(cast.within(Tolerance::Absolute(1.0)).is_ok());
+    assert!(Duration::from_micros(1_500).cast::<Millis<u8>>().within(Tolerance::Ulps(1)).is_ok());
+
+    // The units display with their symbols
+    assert_eq!(Millis(1.5).to_string(), "1.5 ms");
+    assert_eq!(format!("{:>4}", Micros(7)), "   7 µs");
+}
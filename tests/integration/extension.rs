@@ -0,0 +1,75 @@
+//! These tests cover extending cove's casts to types defined outside of the crate, which should be
+//! able to reuse cove's error types along with their Display and Error implementations
+
+use cove::prelude::*;
+use cove::base::CastImpl;
+use cove::errors::{FailedCastError, LossyCastError};
+use core::fmt::{Display, Formatter};
+use core::num::NonZeroU8;
+use std::error::Error;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Wrapper(u16);
+
+impl Cast for Wrapper {}
+
+impl Display for Wrapper {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(formatter, "Wrapper({})", self.0)
+    }
+}
+
+impl CastImpl<u8> for Wrapper {
+    type Error = LossyCastError<Self, u8>;
+
+    fn cast_impl(self) -> Result<u8, Self::Error> {
+        self.0.cast::<u8>().map_err(|error| LossyCastError {from: Self(error.from), to: error.to})
+    }
+}
+
+impl CastImpl<NonZeroU8> for Wrapper {
+    type Error = FailedCastError<Self, NonZeroU8>;
+
+    fn cast_impl(self) -> Result<NonZeroU8, Self::Error> {
+        self.0.cast::<NonZeroU8>().map_err(|error| FailedCastError::new(Self(error.from)))
+    }
+}
+
+/// Casts through the `?` operator into a boxed `std::error::Error`
+fn to_u8(value: Wrapper) -> Result<u8, Box<dyn Error>> {
+    Ok(value.cast::<u8>()?)
+}
+
+/// Casts through the `?` operator into a boxed `std::error::Error`
+fn to_nonzero(value: Wrapper) -> Result<NonZeroU8, Box<dyn Error>> {
+    Ok(value.cast::<NonZeroU8>()?)
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        Wrapper(300).cast::<u8>().unwrap_err().to_string(),
+        format!("Numerical cast was lossy [Wrapper(300) ({}) -> 44 (u8)]", type_name())
+    );
+
+    assert!(
+        Wrapper(0).cast::<NonZeroU8>().unwrap_err().to_string()
+            .starts_with(&format!("Numerical cast failed [Wrapper(0) ({}) -> (", type_name()))
+    );
+}
+
+#[test]
+fn question_mark() {
+    assert_eq!(to_u8(Wrapper(8)).unwrap(), 8);
+    assert_eq!(
+        to_u8(Wrapper(300)).unwrap_err().to_string(),
+        format!("Numerical cast was lossy [Wrapper(300) ({}) -> 44 (u8)]", type_name())
+    );
+
+    assert_eq!(to_nonzero(Wrapper(5)).unwrap().get(), 5);
+    assert!(to_nonzero(Wrapper(256)).unwrap_err().to_string().contains("[Wrapper(256) ("));
+}
+
+fn type_name() -> &'static str {
+    core::any::type_name::<Wrapper>()
+}
@@ -0,0 +1,205 @@
+//! These tests cover the software `f128` type, checking it against the casts of the primitives for
+//! random values, which it represents exactly
+
+use cove::prelude::*;
+use cove::errors::{LossKind, LossyCastError};
+use cove::f128;
+use core::num::{NonZeroI8, NonZeroI128, NonZeroU64, NonZeroU128, NonZeroUsize};
+
+/// Yields pseudorandom 128-bit values with varying numbers of significant bits, along with the
+/// extremes and small integers
+fn values() -> impl Iterator<Item = u128> {
+    let mut state = 0x9e37_79b9_7f4a_7c15u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let special = [0, 1, 2, 3, u128::MAX, u128::MAX >> 1, 1 << 127, 1 << 113, (1 << 113) + 1];
+
+    special.into_iter().chain((0 .. 100_000).map(move |_| {
+        let value = u128::from(next()) << 64 | u128::from(next());
+        value >> (next() % 128)
+    }))
+}
+
+#[test]
+fn constants() {
+    assert_eq!(f128::MAX.cast::<f64>().unwrap_err().to, f64::INFINITY);
+    assert_eq!(f128::MIN.cast::<f64>().closest(), f64::MIN);
+    assert_eq!(f128::EPSILON.cast::<f64>().unwrap(), 2f64.powi(-112));
+    assert_eq!(f128::MIN_POSITIVE.cast::<f64>().unwrap_err().to, 0.0);
+    assert_eq!(f128::INFINITY.cast::<f64>().unwrap(), f64::INFINITY);
+    assert_eq!(f128::NEG_INFINITY.cast::<f32>().unwrap(), f32::NEG_INFINITY);
+    assert!(f128::NAN.is_nan() && f128::NAN.cast::<f64>().unwrap().is_nan());
+    assert_eq!(f128::MANTISSA_DIGITS, 113);
+    assert_eq!(1u8.cast::<f128>().lossless().to_bits(), 0x3fff << 112);
+    assert_eq!(f128::from_le_bytes(f128::MAX.to_le_bytes()), f128::MAX);
+
+    // Comparisons follow IEEE 754
+    assert_ne!(f128::NAN, f128::NAN);
+    assert_eq!((-0.0f64).cast::<f128>().lossless(), 0u8.cast::<f128>().lossless());
+    assert!(f128::MIN < (-1i8).cast::<f128>().lossless());
+    assert!(f128::NEG_INFINITY < f128::MIN && f128::MAX < f128::INFINITY);
+    assert!((-2i8).cast::<f128>().lossless() < (-1i8).cast::<f128>().lossless());
+    assert!(f128::EPSILON > 0u8.cast::<f128>().lossless());
+    assert_eq!(f128::NAN.partial_cmp(&f128::MAX), None);
+
+    // Formatting shows the nearest f64
+    assert_eq!(0.1f64.cast::<f128>().lossless().to_string(), "0.1");
+    assert_eq!(format!("{:?}", (-2.5f32).cast::<f128>().lossless()), "-2.5");
+    assert_eq!(f128::MAX.to_string(), "inf");
+}
+
+#[test]
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::float_cmp)]
+fn integers() {
+    for value in values() {
+        // Integers of up to 64 bits are lossless and round trip exactly, and the nearest f64 is
+        // that of the primitive cast
+        let narrow = value as u64;
+        let quad = narrow.cast::<f128>().lossless();
+        assert_eq!(quad.cast::<u64>(), Ok(narrow));
+        assert_eq!(quad.cast::<f64>().lossy(), narrow.cast::<f64>().lossy());
+        assert_eq!(quad.cast::<f32>().lossy(), narrow.cast::<f32>().lossy());
+        assert_eq!(quad.cast::<f64>().is_ok(), narrow.cast::<f64>().is_ok());
+        assert_eq!(quad.cast::<u32>().ok(), narrow.cast::<u32>().ok());
+        assert_eq!(quad.cast::<u32>().closest(), narrow.cast::<u32>().closest());
+
+        let narrow = narrow as i64;
+        let quad = narrow.cast::<f128>().portable_lossless();
+        assert_eq!(quad.cast::<i64>(), Ok(narrow));
+        assert_eq!(quad.cast::<f64>().lossy(), narrow.cast::<f64>().lossy());
+        assert_eq!(quad.cast::<i16>().closest(), narrow.cast::<i16>().closest());
+        assert_eq!(quad.cast::<u8>().closest(), narrow.cast::<u8>().closest());
+        assert_eq!(quad.cast::<isize>().ok(), narrow.cast::<isize>().ok());
+
+        // 128-bit integers are exact exactly when they have at most 113 significant bits
+        let exact = value >> value.trailing_zeros().min(127) < 1 << 113;
+        let result = value.cast::<f128>();
+        assert_eq!(result.is_ok(), exact, "{value}");
+        assert_eq!(result.closest(), result.lossy());
+
+        if exact {
+            let quad = result.unwrap();
+            assert_eq!(quad.cast::<u128>(), Ok(value));
+            assert_eq!(quad.cast::<f64>().lossy(), value.cast::<f64>().lossy());
+            assert_eq!(quad.cast::<f32>().lossy(), value.cast::<f32>().lossy());
+        } else {
+            // The error is within half of the last place of 113 bits
+            let error = result.unwrap_err();
+            let last = 2f64.powi(15 - value.leading_zeros() as i32);
+            assert!(error.loss().abs() <= last / 2.0, "{value}");
+            assert_eq!(error.kind(), LossKind::Precision);
+        }
+
+        let value = value as i128;
+        let magnitude = value.unsigned_abs();
+        let result = value.cast::<f128>();
+        assert_eq!(result.is_ok(), magnitude >> magnitude.trailing_zeros().min(127) < 1 << 113);
+
+        if let Ok(quad) = result {
+            assert_eq!(quad.cast::<i128>(), Ok(value));
+            assert_eq!(quad.cast::<f64>().lossy(), value.cast::<f64>().lossy());
+            assert_eq!(quad.cast::<u128>().closest(), value.cast::<u128>().closest());
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss, clippy::float_cmp)]
+fn floats() {
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+
+    for _ in 0 .. 100_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let value = f64::from_bits(state);
+
+        // Floats round trip exactly, and casting back to f32 rounds as the primitive cast does
+        let quad = value.cast::<f128>().lossless();
+        match value.is_nan() {
+            true => assert!(quad.is_nan() && quad.cast::<f32>().unwrap().is_nan()),
+            false => {
+                assert_eq!(quad.cast::<f64>().unwrap().to_bits(), value.to_bits());
+                assert_eq!(quad.cast::<f32>().is_ok(), value.cast::<f32>().is_ok());
+                assert_eq!(quad.cast::<f32>().lossy().to_bits(), (value as f32).to_bits());
+                assert_eq!(quad.cast::<f32>().closest(), value.cast::<f32>().closest());
+            }
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        let narrow = value as f32;
+        let quad = narrow.cast::<f128>().portable_lossless();
+        assert!(quad.cast::<f32>().unwrap().to_bits() == narrow.to_bits() || narrow.is_nan());
+        assert!(quad.cast::<f64>().unwrap() == f64::from(narrow) || narrow.is_nan());
+
+        // Casts to integers truncate and saturate, while Closest rounds, as for the primitives
+        let value = match state % 3 {
+            0 => value,
+            1 => (state >> 11) as f64 / 64.0 - 2f64.powi(45),
+            _ => f64::from(narrow) * 1e20
+        };
+
+        let quad = value.cast::<f128>().lossless();
+        assert_eq!(quad.cast::<i32>().ok(), value.cast::<i32>().ok(), "{value}");
+        assert_eq!(quad.cast::<i32>().lossy(), value.cast::<i32>().lossy(), "{value}");
+        assert_eq!(quad.cast::<u64>().lossy(), value.cast::<u64>().lossy(), "{value}");
+        assert_eq!(quad.cast::<i64>().closest(), value.cast::<i64>().closest(), "{value}");
+        assert_eq!(quad.cast::<u128>().ok(), value.cast::<u128>().ok(), "{value}");
+        assert_eq!(quad.cast::<i128>().lossy(), value.cast::<i128>().lossy(), "{value}");
+        assert_eq!(quad.cast::<i128>().closest(), value.cast::<i128>().closest(), "{value}");
+    }
+}
+
+#[test]
+fn errors() {
+    let error = u128::MAX.cast::<f128>().unwrap_err();
+    assert_eq!(error, LossyCastError {from: u128::MAX, to: f128::from_bits(0x407f << 112)});
+    assert_eq!(error.kind(), LossKind::Precision);
+    assert_eq!(error.loss(), -1.0);
+
+    let error = f128::MAX.cast::<f64>().unwrap_err();
+    assert_eq!(error.kind(), LossKind::Overflow);
+    assert_eq!(error.closest(), f64::MAX);
+    let value = ((1u64 << 60) + 1).cast::<f128>().lossless();
+    assert_eq!(value.cast::<f64>().unwrap_err().loss(), 1.0);
+    assert_eq!(f128::MIN.cast::<f32>().unwrap_err().kind(), LossKind::Underflow);
+    assert_eq!(f128::MIN_POSITIVE.cast::<f32>().unwrap_err().kind(), LossKind::Precision);
+    assert_eq!(f128::MAX.cast::<u128>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!(f128::NAN.cast::<u8>().unwrap_err().kind(), LossKind::NaN);
+    assert_eq!(f128::NAN.cast::<u8>().closest(), 0);
+    assert_eq!(f128::INFINITY.cast::<i8>().unwrap_err().kind(), LossKind::Infinity);
+    assert_eq!(f128::NEG_INFINITY.cast::<i8>().closest(), i8::MIN);
+    assert_eq!(f128::EPSILON.cast::<u8>().unwrap_err().kind(), LossKind::Fraction);
+
+    // Bitwise casts reinterpret the bits
+    assert_eq!(f128::NAN.cast::<u128>().bitwise(), f128::NAN.to_bits());
+    assert_eq!((-1i128).cast::<f128>().bitwise().to_bits(), u128::MAX);
+    assert_eq!(NonZeroU128::MAX.cast::<f128>().bitwise().to_bits(), u128::MAX);
+    assert_eq!(NonZeroI128::MIN.cast::<f128>().bitwise().to_bits(), 1 << 127);
+    let one = NonZeroU128::new(0x3fff << 112).unwrap();
+    assert_eq!(one.cast::<f128>().bitwise(), 1u8.cast::<f128>().lossless());
+    assert_eq!(NonZeroI128::new(1).unwrap().cast::<f128>().bitwise().to_bits(), 1);
+}
+
+#[test]
+fn nonzero() {
+    // NonZero* cast as their underlying primitives
+    assert_eq!(NonZeroU64::MAX.cast::<f128>().lossless(), u64::MAX.cast::<f128>().lossless());
+    let value = NonZeroI8::new(-5).unwrap().cast::<f128>().portable_lossless();
+    assert_eq!(value, (-5i8).cast::<f128>().lossless());
+    assert_eq!(NonZeroUsize::MIN.cast::<f128>().unwrap(), 1u8.cast::<f128>().lossless());
+
+    let error = NonZeroU128::MAX.cast::<f128>().unwrap_err();
+    assert_eq!(error, LossyCastError {from: NonZeroU128::MAX, to: u128::MAX.cast().lossy()});
+    assert_eq!(error.kind(), LossKind::Precision);
+    assert_eq!(error.loss(), -1.0);
+    assert_eq!(error.closest(), error.to);
+
+    let value = NonZeroI128::new(-(1 << 113)).unwrap();
+    assert_eq!(value.cast::<f128>().unwrap(), (-(1i128 << 113)).cast::<f128>().unwrap());
+}
@@ -0,0 +1,157 @@
+//! These tests cover the software `f16` type, exhaustively where there are few enough values
+
+use cove::prelude::*;
+use cove::casts::{RoundingMode, Tolerance};
+use cove::errors::LossKind;
+use cove::f16;
+use core::num::{NonZeroI8, NonZeroU32, NonZeroU8};
+
+/// Decodes `bits` as IEEE binary16 by the textbook formula, independently of cove
+fn reference(bits: u16) -> f64 {
+    let sign = if bits & 0x8000 == 0 { 1.0 } else { -1.0 };
+    let exponent = i32::from((bits >> 10) & 0x1f);
+    let mantissa = f64::from(bits & 0x3ff);
+
+    sign * match exponent {
+        0 => mantissa * 2f64.powi(-24),
+        0x1f if mantissa == 0.0 => f64::INFINITY,
+        0x1f => f64::NAN,
+        exponent => (1024.0 + mantissa) * 2f64.powi(exponent - 25)
+    }
+}
+
+#[test]
+fn constants() {
+    assert_eq!(f64::from(f16::MAX), 65504.0);
+    assert_eq!(f64::from(f16::MIN), -65504.0);
+    assert_eq!(f64::from(f16::MIN_POSITIVE), 2f64.powi(-14));
+    assert_eq!(f64::from(f16::EPSILON), 2f64.powi(-10));
+    assert_eq!(f64::from(f16::INFINITY), f64::INFINITY);
+    assert_eq!(f64::from(f16::NEG_INFINITY), f64::NEG_INFINITY);
+    assert!(f16::NAN.is_nan());
+    assert_eq!(f16::MANTISSA_DIGITS, 11);
+    assert_eq!(f16::from_bits(0x3c00).to_be_bytes(), [0x3c, 0x00]);
+    assert_eq!(f16::from_le_bytes([0x00, 0x3c]).to_bits(), 0x3c00);
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn exhaustive() {
+    for bits in 0 ..= u16::MAX {
+        let value = f16::from_bits(bits);
+        let expected = reference(bits);
+
+        // Decoding agrees with the reference, and classification with the decoded value
+        let decoded = f64::from(value);
+        assert!(decoded.to_bits() == expected.to_bits() || (decoded.is_nan() && expected.is_nan()));
+        assert_eq!(value.is_nan(), expected.is_nan());
+        assert_eq!(value.is_infinite(), expected.is_infinite());
+        assert_eq!(value.is_finite(), expected.is_finite());
+        assert_eq!(value.is_sign_negative(), bits & 0x8000 != 0);
+
+        // Casting to the wider floats is lossless, and casting back recovers the same bits
+        let widened = value.cast::<f32>().lossless();
+        assert_eq!(widened.is_sign_negative(), value.is_sign_negative());
+        match value.is_nan() {
+            true => assert!(widened.is_nan() && widened.cast::<f16>().unwrap().is_nan()),
+            false => {
+                assert_eq!(widened.to_bits(), (expected as f32).to_bits());
+                assert_eq!(f32::from(value).cast::<f16>().unwrap().to_bits(), bits);
+                assert_eq!(decoded.cast::<f16>().unwrap().to_bits(), bits);
+            }
+        }
+
+        // Casting to integers agrees with casting from the same value as f32
+        assert_eq!(value.cast::<i32>().is_ok(), f32::from(value).cast::<i32>().is_ok());
+        assert_eq!(value.cast::<i32>().lossy(), f32::from(value).cast::<i32>().lossy());
+        assert_eq!(value.cast::<u8>().closest(), f32::from(value).cast::<u8>().closest());
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn rounding() {
+    // Every midpoint between adjacent positive values rounds to the one with an even mantissa, and
+    // values to either side of the midpoint round to the nearer value
+    for bits in 0 .. 0x7bff_u16 {
+        let (lower, upper) = (reference(bits), reference(bits + 1));
+        let midpoint = (lower + upper) / 2.0;
+        let nudge = (upper - lower) / 1024.0;
+        let even = bits + (bits & 1);
+
+        assert_eq!(midpoint.cast::<f16>().lossy().to_bits(), even);
+        assert_eq!((-midpoint).cast::<f16>().lossy().to_bits(), even | 0x8000);
+        assert_eq!((midpoint - nudge).cast::<f16>().lossy().to_bits(), bits);
+        assert_eq!((midpoint + nudge).cast::<f16>().lossy().to_bits(), bits + 1);
+        assert!(midpoint.cast::<f16>().is_err());
+    }
+
+    // Beyond the largest finite value, the midpoint to the next power of two overflows
+    assert_eq!(65519.99f64.cast::<f16>().lossy(), f16::MAX);
+    assert_eq!(65520f32.cast::<f16>().lossy(), f16::INFINITY);
+    assert_eq!(65520f32.cast::<f16>().closest(), f16::MAX);
+    assert_eq!((-1e10f64).cast::<f16>().closest(), f16::MIN);
+    assert_eq!(f32::INFINITY.cast::<f16>().unwrap(), f16::INFINITY);
+    assert_eq!(f64::NEG_INFINITY.cast::<f16>().closest(), f16::NEG_INFINITY);
+
+    // Below the smallest subnormal, half of it rounds to zero but anything more does not
+    assert_eq!(2f64.powi(-25).cast::<f16>().lossy().to_bits(), 0x0000);
+    assert_eq!((-2f64.powi(-25)).cast::<f16>().lossy().to_bits(), 0x8000);
+    assert_eq!(3f64.powi(-25).cast::<f16>().lossy().to_bits(), 0x0000);
+    assert_eq!((2f64.powi(-25) * 1.001).cast::<f16>().lossy().to_bits(), 0x0001);
+    assert_eq!(f64::MIN_POSITIVE.cast::<f16>().lossy().to_bits(), 0x0000);
+    assert_eq!((-0.0f32).cast::<f16>().unwrap().to_bits(), 0x8000);
+}
+
+#[test]
+fn integers() {
+    // Into f16
+    assert_eq!(255u8.cast::<f16>().lossless(), f16::from_bits(0x5bf8));
+    assert_eq!((-128i8).cast::<f16>().lossless(), f16::from_bits(0xd800));
+    assert_eq!(NonZeroU8::new(3).unwrap().cast::<f16>().lossless(), f16::from_bits(0x4200));
+    assert_eq!(NonZeroI8::new(-1).unwrap().cast::<f16>().lossless(), f16::from_bits(0xbc00));
+    assert_eq!(2048u16.cast::<f16>().unwrap(), f16::from_bits(0x6800));
+    assert_eq!(2049u32.cast::<f16>().lossy(), f16::from_bits(0x6800));
+    assert_eq!(2051i64.cast::<f16>().lossy(), f16::from_bits(0x6802));
+    assert_eq!(65504usize.cast::<f16>().unwrap(), f16::MAX);
+    assert_eq!(u128::MAX.cast::<f16>().lossy(), f16::INFINITY);
+    assert_eq!(u128::MAX.cast::<f16>().closest(), f16::MAX);
+    assert_eq!(i128::MIN.cast::<f16>().closest(), f16::MIN);
+    assert_eq!(NonZeroU32::new(70000).unwrap().cast::<f16>().closest(), f16::MAX);
+    assert_eq!(NonZeroU32::new(70000).unwrap().cast::<f16>().unwrap_err().to, f16::INFINITY);
+
+    // Out of f16
+    let value = 2.5f32.cast::<f16>().unwrap();
+    assert_eq!(value.cast::<u8>().unwrap_err().to, 2u8);
+    assert_eq!(value.cast::<u8>().closest(), 3u8);
+    assert_eq!(value.cast::<i8>().rounded(RoundingMode::Floor), 2i8);
+    assert_eq!(value.cast::<i16>().rounded(RoundingMode::HalfEven), 2i16);
+    assert_eq!(value.cast_split::<u8>().unwrap(), (2u8, 0.5f32.cast::<f16>().unwrap()));
+    assert_eq!(f16::MAX.cast::<u16>().unwrap(), 65504u16);
+    assert_eq!(f16::MAX.cast::<u8>().wrapping(), 224u8);
+    assert_eq!(f16::NEG_INFINITY.cast::<i32>().closest(), i32::MIN);
+    assert_eq!(f16::NAN.cast::<u64>().closest(), 0u64);
+    assert_eq!(f16::MIN.cast::<NonZeroI8>().closest().get(), i8::MIN);
+    assert!(f16::from_bits(0).cast::<NonZeroU8>().is_err());
+}
+
+#[test]
+fn bitwise() {
+    assert_eq!(0x3c00u16.cast::<f16>().bitwise(), f16::from_bits(0x3c00));
+    assert_eq!((-1i16).cast::<f16>().bitwise().to_bits(), 0xffff);
+    assert_eq!(f16::NEG_INFINITY.cast::<u16>().bitwise(), 0xfc00u16);
+    assert_eq!(f16::MIN.cast::<i16>().bitwise(), 0xfbffu16 as i16);
+}
+
+#[test]
+fn measurement() {
+    let error = 2049u32.cast::<f16>().unwrap_err();
+    assert_eq!(error.loss(), 1.0);
+    assert_eq!(error.ulps(), 0.5);
+    assert_eq!(error.kind(), LossKind::Precision);
+    assert_eq!(error.within(Tolerance::Ulps(1)).unwrap(), f16::from_bits(0x6800));
+
+    assert_eq!(1e5f32.cast::<f16>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!(f16::from_bits(0x3e00).cast::<u8>().unwrap_err().kind(), LossKind::Fraction);
+    assert_eq!(f16::from_bits(0x3e00).cast::<u8>().unwrap_err().loss(), 0.5);
+}
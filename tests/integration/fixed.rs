@@ -0,0 +1,165 @@
+//! These tests cover the fixed-point type `Fixed`, checking it exhaustively against f64 arithmetic
+//! for 16-bit underlying integers
+
+use cove::prelude::*;
+use cove::casts::Tolerance;
+use cove::errors::LossKind;
+use cove::types::Fixed;
+
+macro_rules! exhaustive {
+    ($($name:ident: $frac:literal),+) => {
+        $(
+            #[test]
+            #[allow(clippy::float_cmp)]
+            fn $name() {
+                type Q = Fixed<i16, $frac>;
+                let scale = 2f64.powi($frac);
+
+                for bits in i16::MIN ..= i16::MAX {
+                    let fixed = Q::from_bits(bits);
+                    let value = f64::from(bits) / scale;
+
+                    // Into floats, which are always exact for 16 bits
+                    assert_eq!(fixed.cast::<f64>().lossless(), value);
+                    assert_eq!(fixed.cast::<f32>().lossless(), value as f32);
+                    assert_eq!(fixed.to_string().parse::<f64>().unwrap(), value);
+
+                    // Into integers, truncating and then wrapping or rounding and then saturating
+                    let casted = fixed.cast::<i8>();
+                    let exact = value.fract() == 0.0 && (-128.0 ..= 127.0).contains(&value);
+                    assert_eq!(casted.is_ok(), exact);
+                    assert_eq!(casted.lossy(), value.trunc() as i64 as i8);
+                    assert_eq!(casted.closest(), value.round() as i8);
+                    assert_eq!(fixed.cast::<u64>().closest(), value.round() as u64);
+
+                    // From floats, exactly and halfway to the next value, which truncates towards
+                    // zero and rounds away from zero
+                    assert_eq!(value.cast::<Q>().unwrap(), fixed);
+                    assert_eq!((value as f32).cast::<Q>().unwrap(), fixed);
+                    let between = (value + 0.5 / scale).cast::<Q>();
+                    assert!(between.is_err());
+                    assert_eq!(between.lossy().to_bits(), bits + i16::from(bits < 0));
+                    assert_eq!(between.closest().to_bits(), match bits < 0 {
+                        true => bits,
+                        false => bits.saturating_add(1)
+                    });
+
+                    // From integers, wrapping or saturating
+                    let integer = bits >> 4;
+                    let casted = integer.cast::<Q>();
+                    let scaled = f64::from(integer) * scale;
+                    let fits = f64::from(i16::MIN) <= scaled && scaled <= f64::from(i16::MAX);
+                    assert_eq!(casted.is_ok(), fits);
+                    assert_eq!(casted.lossy().to_bits(), integer.checked_shl($frac).unwrap_or(0));
+                    assert_eq!(casted.closest(), match (fits, integer > 0) {
+                        (true, _) => casted.unwrap(),
+                        (false, true) => Q::MAX,
+                        (false, false) => Q::MIN
+                    });
+
+                    // Bitwise reinterprets the underlying integer in both directions
+                    assert_eq!(bits.cast::<Q>().bitwise(), fixed);
+                    assert_eq!(fixed.cast::<i16>().bitwise(), bits);
+                }
+            }
+        )*
+    };
+}
+
+exhaustive!(exhaustive_q0: 0, exhaustive_q4: 4, exhaustive_q15: 15, exhaustive_q16: 16);
+
+#[test]
+fn constants() {
+    assert_eq!(Fixed::<i16, 15>::MIN.to_bits(), i16::MIN);
+    assert_eq!(Fixed::<u8, 4>::MAX.to_bits(), u8::MAX);
+    assert_eq!(Fixed::<i32, 16>::EPSILON.cast::<f64>().lossless(), 2f64.powi(-16));
+    assert_eq!(Fixed::<u64, 32>::FRAC, 32);
+    assert_eq!(Fixed::<i8, 4>::default().to_bits(), 0);
+    assert!(Fixed::<i8, 4>::from_bits(-1) < Fixed::<i8, 4>::EPSILON);
+}
+
+#[test]
+fn display() {
+    assert_eq!(Fixed::<i16, 15>::MIN.to_string(), "-1");
+    assert_eq!(Fixed::<i16, 15>::EPSILON.to_string(), "0.000030517578125");
+    assert_eq!(Fixed::<u8, 0>::MAX.to_string(), "255");
+    assert_eq!(Fixed::<i32, 16>::from_bits(-0x1_8000).to_string(), "-1.5");
+    assert_eq!(format!("{:>8}", Fixed::<u16, 8>::from_bits(0x180)), "     1.5");
+    assert_eq!(format!("{:+}", Fixed::<u16, 8>::from_bits(0x180)), "+1.5");
+
+    // The widest fractions are written exactly, digit for digit
+    let expected = "0.00000000000000000000000000000000000000293873587705571876992184134305561419454\
+        666389193021880377187926569604314863681793212890625";
+    assert_eq!(Fixed::<u128, 128>::EPSILON.to_string(), expected);
+    let expected = "340282366920938463463374607431768211455";
+    assert_eq!(Fixed::<u128, 0>::MAX.to_string(), expected);
+    assert!(Fixed::<u128, 128>::MAX.to_string().starts_with("0.99999999999999999999999999999"));
+    assert_eq!(Fixed::<i128, 128>::MIN.to_string(), "-0.5");
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn wide() {
+    // Rounding the underlying integer may carry to 2^128 and overflow f32 before scaling
+    assert_eq!(Fixed::<u128, 1>::MAX.cast::<f32>().lossy(), 2f32.powi(127));
+    assert_eq!(Fixed::<u128, 1>::MAX.cast::<f32>().closest(), 2f32.powi(127));
+    assert_eq!(Fixed::<u128, 0>::MAX.cast::<f32>().lossy(), f32::INFINITY);
+    assert_eq!(Fixed::<u128, 0>::MAX.cast::<f32>().closest(), f32::MAX);
+    assert_eq!(Fixed::<u128, 128>::EPSILON.cast::<f32>().unwrap(), f32::from_bits(1 << 21));
+    assert_eq!(Fixed::<i128, 128>::MIN.cast::<f64>().unwrap(), -0.5);
+
+    // Integers with all fractional bits are never exact but for zero
+    assert_eq!(0u8.cast::<Fixed<u128, 128>>().unwrap(), Fixed::default());
+    assert_eq!(1u8.cast::<Fixed<u128, 128>>().unwrap_err().to, Fixed::default());
+    assert_eq!(1u8.cast::<Fixed<u128, 128>>().closest(), Fixed::<u128, 128>::MAX);
+    assert_eq!((-1i8).cast::<Fixed<i128, 128>>().closest(), Fixed::<i128, 128>::MIN);
+    assert_eq!(Fixed::<i128, 128>::MIN.cast::<i8>().closest(), -1);
+    assert_eq!(Fixed::<i128, 128>::MIN.cast::<i8>().unwrap_err().to, 0);
+    assert_eq!(Fixed::<u128, 128>::MAX.cast::<u8>().closest(), 1);
+    assert_eq!(u128::MAX.cast::<Fixed<u128, 0>>().lossless().cast::<u128>().lossless(), u128::MAX);
+
+    // Large floats overflow the scaling to infinity, which is lossy regardless
+    assert_eq!(f64::MAX.cast::<Fixed<i64, 32>>().closest(), Fixed::<i64, 32>::MAX);
+    assert_eq!(f64::NEG_INFINITY.cast::<Fixed<i64, 32>>().lossy(), Fixed::<i64, 32>::MIN);
+    assert_eq!(f64::NAN.cast::<Fixed<i64, 32>>().closest(), Fixed::default());
+    assert_eq!(f64::MIN_POSITIVE.cast::<Fixed<u128, 128>>().closest(), Fixed::default());
+}
+
+#[test]
+fn lossless() {
+    assert_eq!(i16::MIN.cast::<Fixed<i32, 16>>().lossless().to_bits(), i32::MIN);
+    assert_eq!(u8::MAX.cast::<Fixed<u16, 8>>().lossless().to_bits(), 0xff00);
+    assert_eq!(u8::MAX.cast::<Fixed<i16, 7>>().lossless().to_bits(), 0x7f80);
+    assert_eq!(i8::MIN.cast::<Fixed<i16, 8>>().lossless(), Fixed::<i16, 8>::MIN);
+    assert_eq!(Fixed::<u8, 0>::MAX.cast::<u8>().lossless(), u8::MAX);
+    assert_eq!(Fixed::<i16, 0>::MIN.cast::<i32>().lossless(), -32768);
+    assert_eq!(Fixed::<i32, 31>::MIN.cast::<f64>().lossless(), -1.0);
+    assert_eq!(Fixed::<u32, 8>::MAX.cast::<f64>().lossless(), f64::from(u32::MAX) / 256.0);
+    assert_eq!(Fixed::<u16, 16>::MAX.cast::<f32>().lossless(), 1.0 - 2f32.powi(-16));
+}
+
+#[test]
+fn lossy() {
+    // Loss is measured and classified exactly
+    let error = 0.1f32.cast::<Fixed<i16, 15>>().unwrap_err();
+    assert_eq!(error.kind(), LossKind::Precision);
+    assert_eq!(error.loss(), f64::from(0.1f32) - 3276.0 / 32768.0);
+    assert_eq!(1.5f32.cast::<Fixed<i16, 15>>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!((-1i8).cast::<Fixed<u8, 4>>().unwrap_err().kind(), LossKind::Underflow);
+    assert_eq!(f32::NAN.cast::<Fixed<u8, 4>>().unwrap_err().kind(), LossKind::NaN);
+    let error = Fixed::<i32, 16>::from_bits(0x2_c000).cast::<u8>().unwrap_err();
+    assert_eq!(error.kind(), LossKind::Fraction);
+    assert_eq!(error.loss(), 0.75);
+    assert_eq!(u32::MAX.cast::<Fixed<u32, 16>>().unwrap_err().kind(), LossKind::Overflow);
+
+    // Within accepts the lossy value if the loss is within tolerance
+    let tolerance = Tolerance::Absolute(2f64.powi(-15));
+    assert!(0.1f32.cast::<Fixed<i16, 15>>().within(tolerance).is_ok());
+    assert!(0.1f32.cast::<Fixed<i16, 8>>().within(tolerance).is_err());
+    assert!(0.1f32.cast::<Fixed<i16, 8>>().within(Tolerance::Ulps(1)).is_ok());
+    assert!(2.75f64.cast::<Fixed<u8, 2>>().unwrap().cast::<u8>().within(tolerance).is_err());
+
+    // Error messages describe the values
+    let message = 0.1f32.cast::<Fixed<i16, 15>>().unwrap_err().to_string();
+    assert!(message.contains("0.099975585937"), "{message}");
+}
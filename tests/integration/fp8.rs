@@ -0,0 +1,194 @@
+//! These tests cover the software FP8 types `f8e4m3` and `f8e5m2` exhaustively
+
+use cove::prelude::*;
+use cove::errors::LossKind;
+use cove::types::{f8e4m3, f8e5m2};
+use cove::{bf16, f16};
+use core::num::{NonZeroI8, NonZeroU8};
+
+/// Decodes `bits` by the textbook formula for a format with the given number of exponent and
+/// mantissa bits, independently of cove; `finite` selects the E4M3 handling of the largest exponent
+fn reference(bits: u8, exponent: u32, mantissa: u32, finite: bool) -> f64 {
+    let sign = if bits & 0x80 == 0 { 1.0 } else { -1.0 };
+    let bias = (1 << (exponent - 1)) - 1;
+    let field = i32::from(bits >> mantissa) & ((1 << exponent) - 1);
+    let fraction = f64::from(bits & ((1 << mantissa) - 1)) / f64::from(1 << mantissa);
+    let largest = (1 << exponent) - 1;
+
+    sign * match field {
+        0 => fraction * 2f64.powi(1 - bias),
+        _ if finite && field == largest && fraction == 1.0 - 1.0 / f64::from(1 << mantissa) => {
+            f64::NAN
+        },
+        _ if !finite && field == largest && fraction == 0.0 => f64::INFINITY,
+        _ if !finite && field == largest => f64::NAN,
+        _ => (1.0 + fraction) * 2f64.powi(field - bias)
+    }
+}
+
+macro_rules! exhaustive {
+    ($name:ident as $float:ty: $exponent:literal, $mantissa:literal, $finite:literal) => {
+        #[test]
+        #[allow(clippy::float_cmp)]
+        fn $name() {
+            for bits in 0 ..= u8::MAX {
+                let value = <$float>::from_bits(bits);
+                let expected = reference(bits, $exponent, $mantissa, $finite);
+
+                // Decoding and classification agree with the reference
+                let widened = value.cast::<f64>().lossless();
+                assert!(widened == expected || (widened.is_nan() && expected.is_nan()));
+                assert_eq!(widened.is_sign_negative(), bits & 0x80 != 0);
+                assert_eq!(value.is_nan(), expected.is_nan());
+                assert_eq!(value.is_infinite(), expected.is_infinite());
+                assert_eq!(value.is_finite(), expected.is_finite());
+
+                // Widening to the other floats is lossless, and casting back recovers the bits
+                let half = value.cast::<f16>().lossless();
+                let brain = value.cast::<bf16>().lossless();
+                assert_eq!(half.is_nan() && brain.is_nan(), value.is_nan());
+                if !value.is_nan() {
+                    assert_eq!(f64::from(half).to_bits(), widened.to_bits());
+                    assert_eq!(f64::from(brain).to_bits(), widened.to_bits());
+                    assert_eq!(f32::from(value).cast::<$float>().unwrap().to_bits(), bits);
+                    assert_eq!(half.cast::<$float>().unwrap().to_bits(), bits);
+                    assert_eq!(brain.cast::<$float>().unwrap().to_bits(), bits);
+                }
+
+                // Midpoints with the next larger value round to even, other values to nearest
+                if bits < 0x7f && value.is_finite() && <$float>::from_bits(bits + 1).is_finite() {
+                    let upper = f64::from(<$float>::from_bits(bits + 1));
+                    let midpoint = (widened + upper) / 2.0;
+                    let nudge = (upper - widened) / 64.0;
+                    assert_eq!(midpoint.cast::<$float>().lossy().to_bits(), bits + (bits & 1));
+                    assert_eq!((midpoint - nudge).cast::<$float>().lossy().to_bits(), bits);
+                    assert_eq!((midpoint + nudge).cast::<$float>().lossy().to_bits(), bits + 1);
+                }
+
+                // Casting to integers agrees with casting from the same value as f32
+                let single = f32::from(value);
+                assert_eq!(value.cast::<i16>().is_ok(), single.cast::<i16>().is_ok());
+                assert_eq!(value.cast::<u8>().closest(), single.cast::<u8>().closest());
+                assert_eq!(value.cast::<i8>().wrapping(), single.cast::<i8>().wrapping());
+
+                // As for the primitive floats, -0.0 casts losslessly to 0 and so Bitwise yields 0
+                assert_eq!(value.cast::<u8>().bitwise(), if bits == 0x80 { 0 } else { bits });
+            }
+        }
+    }
+}
+
+exhaustive!(exhaustive_e4m3 as f8e4m3: 4, 3, true);
+exhaustive!(exhaustive_e5m2 as f8e5m2: 5, 2, false);
+
+#[test]
+fn e4m3() {
+    // Constants; there are no infinities, and the largest exponent is mostly finite
+    assert_eq!(f32::from(f8e4m3::MAX), 448.0);
+    assert_eq!(f32::from(f8e4m3::MIN), -448.0);
+    assert_eq!(f32::from(f8e4m3::MIN_POSITIVE), 2f32.powi(-6));
+    assert_eq!(f32::from(f8e4m3::EPSILON), 0.125);
+    assert_eq!(f8e4m3::NAN.to_bits(), 0x7f);
+    assert_eq!(f32::from(f8e4m3::from_bits(0x78)), 256.0);
+
+    // Overflow saturates
+    assert_eq!(463.99f32.cast::<f8e4m3>().lossy(), f8e4m3::MAX);
+    assert_eq!(464f64.cast::<f8e4m3>().lossy(), f8e4m3::MAX);
+    assert_eq!(1e30f32.cast::<f8e4m3>().unwrap_err().to, f8e4m3::MAX);
+    assert_eq!(1e30f32.cast::<f8e4m3>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!((-1e30f64).cast::<f8e4m3>().closest(), f8e4m3::MIN);
+    assert_eq!(u128::MAX.cast::<f8e4m3>().lossy(), f8e4m3::MAX);
+    assert_eq!(i64::MIN.cast::<f8e4m3>().closest(), f8e4m3::MIN);
+    assert_eq!(f16::INFINITY.cast::<f8e4m3>().closest(), f8e4m3::MAX);
+
+    // Infinities have no counterpart, so saturate as lossy casts
+    let error = f32::INFINITY.cast::<f8e4m3>().unwrap_err();
+    assert_eq!(error.to, f8e4m3::MAX);
+    assert_eq!(error.kind(), LossKind::Infinity);
+    assert_eq!(f64::NEG_INFINITY.cast::<f8e4m3>().lossy(), f8e4m3::MIN);
+    assert_eq!(f8e5m2::NEG_INFINITY.cast::<f8e4m3>().closest(), f8e4m3::MIN);
+
+    // NaN is lossless, and keeps its sign
+    assert_eq!(f32::NAN.cast::<f8e4m3>().unwrap().to_bits(), 0x7f);
+    assert_eq!((-f64::NAN).cast::<f8e4m3>().unwrap().to_bits(), 0xff);
+    assert!(f8e5m2::NAN.cast::<f8e4m3>().unwrap().is_nan());
+
+    // Integers
+    assert_eq!(16u8.cast::<f8e4m3>().unwrap(), f8e4m3::from_bits(0x58));
+    assert_eq!(17u8.cast::<f8e4m3>().lossy(), f8e4m3::from_bits(0x58));
+    let nonzero = NonZeroU8::new(19).unwrap();
+    assert_eq!(nonzero.cast::<f8e4m3>().closest(), f8e4m3::from_bits(0x5a));
+    assert_eq!(f8e4m3::MAX.cast::<u16>().unwrap(), 448u16);
+    assert_eq!(f8e4m3::from_bits(0x3c).cast::<u8>().unwrap_err().kind(), LossKind::Fraction);
+}
+
+#[test]
+fn e5m2() {
+    // Constants
+    assert_eq!(f32::from(f8e5m2::MAX), 57344.0);
+    assert_eq!(f32::from(f8e5m2::MIN_POSITIVE), 2f32.powi(-14));
+    assert_eq!(f32::from(f8e5m2::EPSILON), 0.25);
+    assert_eq!(f32::from(f8e5m2::INFINITY), f32::INFINITY);
+    assert_eq!(f8e5m2::NAN.to_bits(), 0x7e);
+
+    // The bits of f8e5m2 are the upper half of those of f16
+    for bits in 0 ..= u8::MAX {
+        let value = f8e5m2::from_bits(bits).cast::<f16>().lossless();
+        assert!(value.to_bits() == u16::from(bits) << 8 || value.is_nan());
+    }
+
+    // Overflow becomes infinite, but Closest saturates
+    assert_eq!(61439.99f32.cast::<f8e5m2>().lossy(), f8e5m2::MAX);
+    assert_eq!(61440f32.cast::<f8e5m2>().lossy(), f8e5m2::INFINITY);
+    assert_eq!(61440f32.cast::<f8e5m2>().closest(), f8e5m2::MAX);
+    assert_eq!(i128::MIN.cast::<f8e5m2>().lossy(), f8e5m2::NEG_INFINITY);
+    assert_eq!(i128::MIN.cast::<f8e5m2>().closest(), f8e5m2::MIN);
+    assert_eq!(f16::MAX.cast::<f8e5m2>().closest(), f8e5m2::MAX);
+
+    // Infinities and NaN are preserved
+    assert_eq!(f32::NEG_INFINITY.cast::<f8e5m2>().unwrap(), f8e5m2::NEG_INFINITY);
+    assert_eq!(f64::NEG_INFINITY.cast::<f8e5m2>().closest(), f8e5m2::NEG_INFINITY);
+    assert!(f16::NAN.cast::<f8e5m2>().unwrap().is_nan());
+
+    // Integers
+    let nonzero = NonZeroI8::new(-12).unwrap();
+    assert_eq!(nonzero.cast::<f8e5m2>().unwrap(), f8e5m2::from_bits(0xca));
+    assert_eq!(9u64.cast::<f8e5m2>().lossy(), f8e5m2::from_bits(0x48));
+    assert_eq!(f8e5m2::MAX.cast::<i16>().closest(), i16::MAX);
+    assert_eq!(f8e5m2::from_bits(0x3d).cast_split::<u8>().unwrap().0, 1u8);
+}
+
+#[test]
+fn between() {
+    // Each format has values outside of the other
+    assert_eq!(f8e4m3::from_bits(0x39).cast::<f8e5m2>().lossy(), f8e5m2::from_bits(0x3c));
+    assert_eq!(f8e4m3::from_bits(0x39).cast::<f8e5m2>().unwrap_err().ulps(), 0.5);
+    assert_eq!(f8e5m2::MAX.cast::<f8e4m3>().lossy(), f8e4m3::MAX);
+    assert_eq!(f8e5m2::INFINITY.cast::<f8e4m3>().unwrap_err().kind(), LossKind::Infinity);
+    assert_eq!(f8e4m3::MAX.cast::<f8e5m2>().unwrap(), 448u16.cast::<f8e5m2>().unwrap());
+
+    // Bitwise reinterprets the bits to and from the 8-bit integers
+    assert_eq!(0x7fu8.cast::<f8e4m3>().bitwise().to_bits(), 0x7f);
+    assert_eq!((-1i8).cast::<f8e5m2>().bitwise().to_bits(), 0xff);
+    assert_eq!(f8e5m2::INFINITY.cast::<u8>().bitwise().cast::<f8e4m3>().bitwise().to_bits(), 0x7c);
+    assert_eq!(NonZeroU8::new(0x38).unwrap().cast::<f8e4m3>().bitwise(), 1u8.cast().lossy());
+}
+
+#[test]
+fn nan_payloads() {
+    // Bitwise casts through the integers preserve the payloads of NaNs, which the numerical casts
+    // between the formats do not
+    for bits in [0x7du8, 0x7e, 0x7f, 0xfd, 0xfe, 0xff] {
+        let value = f8e5m2::from_bits(bits);
+        assert_eq!(value.cast::<u8>().bitwise(), bits);
+        assert_eq!(value.cast::<i8>().bitwise().cast::<f8e5m2>().bitwise().to_bits(), bits);
+        assert_eq!(bits.cast::<f8e5m2>().bitwise().to_bits(), bits);
+        assert!(value.cast::<f8e4m3>().unwrap().is_nan());
+    }
+
+    for bits in [0x7fu8, 0xff] {
+        let value = f8e4m3::from_bits(bits);
+        assert_eq!(value.cast::<u8>().bitwise(), bits);
+        assert_eq!(value.cast::<i8>().bitwise().cast::<f8e4m3>().bitwise().to_bits(), bits);
+    }
+}
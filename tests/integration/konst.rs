@@ -0,0 +1,149 @@
+//! These tests cover the const-evaluable casts in `cove::konst`, checking that they agree with the
+//! casting traits for all supported casts
+
+use crate::util::IsNaN;
+use cove::konst::ConstCast;
+use cove::prelude::*;
+
+macro_rules! agree {
+    ($name:ident as $source:ty => $($target:ty),*) => {
+        #[test]
+        #[allow(clippy::float_cmp)]
+        fn $name () {
+            // Initialization: allocate space for the test buffers and determine the initial seed
+            let mut random = crate::util::random_seed();
+
+            // Perform the tests
+            for _ in 0 .. crate::util::settings::FAST_ITERATIONS {
+                // Generate the test value and next random number
+                let (buffer, next_random) = crate::util::random_bytes(random);
+                let value = <$source>::from_ne_bytes(buffer);
+                random = next_random;
+
+                // Validate that the verdicts and values agree with the casting traits
+                $(
+                    {
+                        let expected = value.cast::<$target>();
+                        let casted = ConstCast::<$source, $target>::cast(value);
+                        assert_eq!(casted.is_ok(), expected.is_ok());
+
+                        let lhs = ConstCast::<$source, $target>::lossy(value);
+                        let rhs = expected.lossy();
+                        assert!(lhs == rhs || (lhs.is_nan() && rhs.is_nan()));
+                        assert!(lhs == casted.lossy() || lhs.is_nan());
+
+                        if expected.is_ok() {
+                            let lhs = ConstCast::<$source, $target>::unwrap(value);
+                            assert!(lhs == rhs || (lhs.is_nan() && rhs.is_nan()));
+                        }
+                    }
+                )*
+            }
+        }
+    }
+}
+
+agree!(
+    random_u8 as u8 =>
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+agree!(
+    random_u16 as u16 =>
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+agree!(
+    random_u32 as u32 =>
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+agree!(
+    random_u64 as u64 =>
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+agree!(
+    random_u128 as u128 =>
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+agree!(
+    random_usize as usize =>
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+agree!(
+    random_i8 as i8 =>
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+agree!(
+    random_i16 as i16 =>
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+agree!(
+    random_i32 as i32 =>
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+agree!(
+    random_i64 as i64 =>
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+agree!(
+    random_i128 as i128 =>
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+agree!(
+    random_isize as isize =>
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+agree!(random_f32 as f32 => f32, f64);
+agree!(random_f64 as f64 => f64);
+
+#[test]
+fn edges() {
+    // Integer to float casts are exact whenever the significant bits fit the mantissa
+    assert_eq!(ConstCast::<u32, f32>::cast(16_777_216), Ok(16_777_216f32));
+    assert!(ConstCast::<u32, f32>::cast(16_777_217).is_err());
+    assert!(ConstCast::<u128, f32>::cast(u128::MAX).is_err());
+    assert_eq!(ConstCast::<i128, f32>::cast(i128::MIN), Ok(-2f32.powi(127)));
+    assert_eq!(ConstCast::<i64, f64>::cast(0), Ok(0f64));
+
+    let max = u128::MAX - (1 << 104) + 1;
+    assert_eq!(ConstCast::<u128, f32>::cast(max), Ok(f32::MAX));
+    assert_eq!(ConstCast::<u128, f32>::cast(max), max.cast::<f32>());
+
+    // Integer casts must preserve both the value and the sign
+    assert!(ConstCast::<i8, u8>::cast(-1).is_err());
+    assert!(ConstCast::<u8, i8>::cast(128).is_err());
+    assert_eq!(ConstCast::<i128, u8>::cast(255), Ok(255u8));
+}
+
+#[test]
+fn const_context() {
+    const LENGTH: usize = ConstCast::<u32, usize>::unwrap(12);
+    const LOSSY: i8 = ConstCast::<u16, i8>::lossy(0xff);
+    const LOSSLESS: f64 = ConstCast::<i32, f64>::lossless(-7);
+    const FITS: [bool; 2] = [
+        ConstCast::<u64, u8>::cast(255).is_ok(),
+        ConstCast::<u64, u8>::cast(256).is_ok()
+    ];
+
+    let table = [0u8; LENGTH];
+    assert_eq!(table.len(), 12);
+    assert_eq!(LOSSY, -1);
+    assert_eq!(LOSSLESS, -7.0);
+    assert_eq!(FITS, [true, false]);
+}
+
+#[test]
+#[should_panic(expected = "Numerical cast was lossy")]
+fn unwrap_panics() {
+    let value = core::hint::black_box(300u32);
+    let _ = ConstCast::<u32, u8>::unwrap(value);
+}
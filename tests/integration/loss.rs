@@ -0,0 +1,94 @@
+//! These tests cover the measurement of loss on `LossyCastError`
+
+use cove::prelude::*;
+use cove::types::Ranged;
+use core::num::{NonZeroI8, NonZeroI128, NonZeroU64, NonZeroU128};
+
+#[test]
+fn integers() {
+    assert_eq!(300u16.cast::<u8>().unwrap_err().loss(), 256.0);
+    assert_eq!((-1i32).cast::<u32>().unwrap_err().loss(), -4_294_967_296.0);
+    assert_eq!(200u8.cast::<i8>().unwrap_err().loss(), 256.0);
+    assert_eq!(i128::MIN.cast::<u128>().unwrap_err().loss(), -2f64.powi(128));
+    assert_eq!(u128::MAX.cast::<u8>().unwrap_err().loss().components(), [2f64.powi(128), -256.0]);
+    assert_eq!(u128::MAX.cast::<i128>().unwrap_err().loss(), 2f64.powi(128));
+}
+
+#[test]
+fn floats_to_integers() {
+    assert_eq!(2.5f32.cast::<u8>().unwrap_err().loss(), 0.5);
+    assert_eq!((-2.5f64).cast::<i64>().unwrap_err().loss(), -0.5);
+    assert_eq!(300.25f64.cast::<u8>().unwrap_err().loss(), 45.25);
+    assert_eq!((-0.5f32).cast::<u128>().unwrap_err().loss(), -0.5);
+    let loss = 1e300f64.cast::<i128>().unwrap_err().loss();
+    assert_eq!(loss.components(), [1e300, -2f64.powi(127), 1.0]);
+    assert_eq!(f32::INFINITY.cast::<u64>().unwrap_err().loss(), f64::INFINITY);
+    assert_eq!(f64::NEG_INFINITY.cast::<i8>().unwrap_err().loss(), f64::NEG_INFINITY);
+    assert!(f32::NAN.cast::<i32>().unwrap_err().loss().to_f64().is_nan());
+}
+
+#[test]
+fn integers_to_floats() {
+    assert_eq!(16_777_217u32.cast::<f32>().unwrap_err().loss(), 1.0);
+    assert_eq!((u64::MAX - 1000).cast::<f64>().unwrap_err().loss(), -1001.0);
+    assert_eq!(u128::MAX.cast::<f64>().unwrap_err().loss(), -1.0);
+    assert_eq!((i128::MIN + 1).cast::<f32>().unwrap_err().loss(), 1.0);
+    assert_eq!(u128::MAX.cast::<f32>().unwrap_err().loss(), f64::NEG_INFINITY);
+
+    // The difference is exact even when it is far below the precision of either value
+    let value = (1u128 << 127) + (1 << 100) + (1 << 60);
+    assert_eq!(value.cast::<f32>().unwrap_err().loss(), 2f64.powi(100) + 2f64.powi(60));
+    assert_eq!(value.cast::<f64>().unwrap_err().loss(), 2f64.powi(60));
+
+    // ...and even when it is not representable by f64
+    let value = (1u128 << 127) + (1 << 103) - 1;
+    let loss = value.cast::<f32>().unwrap_err().loss();
+    assert_eq!(loss.components(), [2f64.powi(103), -1.0]);
+    assert_ne!(loss, 2f64.powi(103));
+    assert!(loss < 2f64.powi(103) && loss > 2f64.powi(102));
+    assert_eq!(loss.to_f64(), 2f64.powi(103));
+
+    let loss = u128::MAX.cast::<f64>().unwrap_err().loss();
+    assert_eq!(loss.components(), [-1.0]);
+    assert!(loss < 0.0 && loss > -1.5);
+}
+
+#[test]
+fn floats() {
+    let error = 0.1f64.cast::<f32>().unwrap_err();
+    assert_eq!(error.loss(), 0.1 - f64::from(0.1f32));
+    assert_eq!(1e-50f64.cast::<f32>().unwrap_err().loss(), 1e-50);
+    assert_eq!(1e300f64.cast::<f32>().unwrap_err().loss(), f64::NEG_INFINITY);
+}
+
+#[test]
+fn ulps() {
+    assert_eq!(16_777_217u32.cast::<f32>().unwrap_err().ulps(), 0.5);
+    assert_eq!(16_777_219u64.cast::<f32>().unwrap_err().ulps(), -0.5);
+    assert_eq!((i64::MAX).cast::<f64>().unwrap_err().ulps(), -1.0 / 2048.0);
+    assert_eq!(u128::MAX.cast::<f64>().unwrap_err().ulps(), -2f64.powi(-76));
+
+    // Rounding to nearest is always within half an ulp
+    let ulps = 0.1f64.cast::<f32>().unwrap_err().ulps();
+    assert!(ulps.abs() <= 0.5);
+
+    // Subnormal targets share the ulp of the smallest normal exponent
+    let error = 1e-45f64.cast::<f32>().unwrap_err();
+    assert_eq!(error.ulps(), (1e-45 - f64::from(error.to)) / 2f64.powi(-149));
+}
+
+#[test]
+fn nonzero() {
+    let value = NonZeroI8::new(-1).unwrap();
+    assert_eq!(value.cast::<u8>().unwrap_err().loss(), -256.0);
+    assert_eq!(NonZeroU128::MAX.cast::<f64>().unwrap_err().loss(), -1.0);
+    assert_eq!(NonZeroI128::MIN.cast::<i64>().unwrap_err().loss(), -2f64.powi(127));
+    assert_eq!(NonZeroU64::MAX.cast::<f32>().unwrap_err().ulps(), -2f64.powi(-41));
+}
+
+#[test]
+fn ranged() {
+    let value = Ranged::<i32, -300, 300>::new(-300).unwrap();
+    assert_eq!(value.cast::<u8>().unwrap_err().loss(), -512.0);
+    assert_eq!(value.cast::<i8>().unwrap_err().loss(), -256.0);
+}
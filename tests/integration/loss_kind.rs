@@ -0,0 +1,90 @@
+//! These tests cover `LossKind` classification of cove's error types
+
+use cove::errors::LossKind;
+use cove::prelude::*;
+use cove::types::Ranged;
+use core::num::{NonZeroI16, NonZeroU8, NonZeroU128};
+
+#[test]
+fn lossy() {
+    assert_eq!(300i32.cast::<u8>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!(u128::MAX.cast::<i8>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!((-129i16).cast::<i8>().unwrap_err().kind(), LossKind::Underflow);
+    assert_eq!(i128::MIN.cast::<usize>().unwrap_err().kind(), LossKind::Underflow);
+    assert_eq!(u128::MAX.cast::<f32>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!(u128::MAX.cast::<f64>().unwrap_err().kind(), LossKind::Precision);
+    assert_eq!((-16_777_217i32).cast::<f32>().unwrap_err().kind(), LossKind::Precision);
+}
+
+#[test]
+fn float() {
+    assert_eq!(2.5f64.cast::<u8>().unwrap_err().kind(), LossKind::Fraction);
+    assert_eq!(255.5f64.cast::<u8>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!((-0.5f32).cast::<u64>().unwrap_err().kind(), LossKind::Underflow);
+    assert_eq!(1e20f32.cast::<i64>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!(f32::NAN.cast::<i32>().unwrap_err().kind(), LossKind::NaN);
+    assert_eq!(f64::NEG_INFINITY.cast::<i32>().unwrap_err().kind(), LossKind::Infinity);
+
+    assert_eq!(0.1f64.cast::<f32>().unwrap_err().kind(), LossKind::Precision);
+    assert_eq!(1e-300f64.cast::<f32>().unwrap_err().kind(), LossKind::Precision);
+    assert_eq!(1e300f64.cast::<f32>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!((-1e300f64).cast::<f32>().unwrap_err().kind(), LossKind::Underflow);
+}
+
+#[test]
+fn nonzero() {
+    assert_eq!(0i64.cast::<NonZeroU8>().unwrap_err().kind(), LossKind::Zero);
+    assert_eq!((-0f32).cast::<NonZeroI16>().unwrap_err().kind(), LossKind::Zero);
+    assert_eq!(0.5f32.cast::<NonZeroU8>().unwrap_err().kind(), LossKind::Underflow);
+    assert_eq!(256u16.cast::<NonZeroU8>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!((-1i8).cast::<NonZeroU128>().unwrap_err().kind(), LossKind::Underflow);
+    assert_eq!(2.5f64.cast::<NonZeroI16>().unwrap_err().kind(), LossKind::Fraction);
+
+    let value = NonZeroI16::new(-1).unwrap();
+    assert_eq!(value.cast::<u8>().unwrap_err().kind(), LossKind::Underflow);
+    assert_eq!(value.cast::<NonZeroU8>().unwrap_err().kind(), LossKind::Underflow);
+}
+
+#[test]
+fn ranged() {
+    assert_eq!(9u8.cast::<Ranged<u8, 10, 20>>().unwrap_err().kind(), LossKind::Underflow);
+    assert_eq!(0u8.cast::<Ranged<u8, 10, 20>>().unwrap_err().kind(), LossKind::Underflow);
+    assert_eq!(21.0f32.cast::<Ranged<u8, 10, 20>>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!(10.5f32.cast::<Ranged<u8, 10, 20>>().unwrap_err().kind(), LossKind::Fraction);
+
+    let value = Ranged::<i32, -300, 300>::new(300).unwrap();
+    assert_eq!(value.cast::<i8>().unwrap_err().kind(), LossKind::Overflow);
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        format!("{}", 260u32.cast::<u8>().unwrap_err()),
+        "Numerical cast was lossy [260 (u32) -> 4 (u8)]"
+    );
+
+    // The type name of NonZero* types varies between compiler versions
+    assert!(
+        format!("{}", 0u32.cast::<NonZeroU8>().unwrap_err())
+            .starts_with("Numerical cast failed [0 (u32) -> (core::num::")
+    );
+
+    // The detailed messages include the kind
+    assert_eq!(
+        format!("{}", 260u32.cast::<u8>().unwrap_err().detailed()),
+        "Numerical cast was lossy (overflow) [260 (u32) -> 4 (u8)]"
+    );
+
+    assert_eq!(
+        (-2.5f64).cast::<u8>().unwrap_err().detailed().to_string(),
+        "Numerical cast was lossy (underflow) [-2.5 (f64) -> 0 (u8)]"
+    );
+
+    assert!(
+        format!("{}", 0u32.cast::<NonZeroU8>().unwrap_err().detailed())
+            .starts_with("Numerical cast failed (zero) [0 (u32) -> (core::num::")
+    );
+
+    assert_eq!(format!("{}", LossKind::Fraction), "fraction");
+    assert_eq!(format!("{}", LossKind::NaN), "NaN");
+}
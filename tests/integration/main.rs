@@ -1,9 +1,36 @@
 mod assumed_lossless;
+mod bf16;
+mod bits;
 mod bitwise;
+mod boolean;
 mod cast;
+mod cast_split;
+mod character;
 mod closest;
+mod complex;
+mod decimal;
+mod duration;
+mod extension;
+mod f128;
+mod f16;
+mod fixed;
+mod fp8;
+mod konst;
+mod loss;
 mod lossless;
+mod loss_kind;
 mod lossy;
 mod nonzero;
+mod option;
+mod portable_lossless;
 mod random;
-mod util;
\ No newline at end of file
+mod ranged;
+mod ratio;
+mod refined;
+mod rounded;
+mod stochastic;
+mod util;
+mod wide;
+mod within;
+mod wrapping;
+mod wrappers;
\ No newline at end of file
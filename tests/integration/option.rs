@@ -0,0 +1,80 @@
+//! These tests cover casts to, from, and between `Option<NonZero*>`, which should behave exactly as
+//! the underlying primitive with `None` as zero
+
+use cove::prelude::*;
+use cove::errors::{FailedCastError, LossKind, LossyCastError};
+use core::num::{NonZeroI8, NonZeroI32, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64};
+
+#[test]
+fn into_option() {
+    // Zero becomes None, and casts from the same width are lossless
+    assert_eq!(0u32.cast::<Option<NonZeroU32>>().lossless(), None);
+    assert_eq!(7u32.cast::<Option<NonZeroU32>>().lossless(), NonZeroU32::new(7));
+    assert_eq!(0u8.cast::<Option<NonZeroU64>>().portable_lossless(), None);
+    assert_eq!((-5i8).cast::<Option<NonZeroI32>>().lossless(), NonZeroI32::new(-5));
+    assert_eq!(NonZeroU8::MIN.cast::<Option<NonZeroU16>>().lossless(), NonZeroU16::new(1));
+
+    // Range checking as for the underlying primitive
+    let error = 300u32.cast::<Option<NonZeroU8>>().unwrap_err();
+    assert_eq!(error, LossyCastError {from: 300u32, to: NonZeroU8::new(44)});
+    assert_eq!(error.kind(), LossKind::Overflow);
+    assert_eq!(error.loss(), 256.0);
+    assert_eq!(256u32.cast::<Option<NonZeroU8>>().lossy(), None);
+    assert_eq!(256u32.cast::<Option<NonZeroU8>>().closest(), NonZeroU8::new(u8::MAX));
+    assert_eq!((-1i32).cast::<Option<NonZeroU8>>().unwrap_err().kind(), LossKind::Underflow);
+    assert_eq!((-1i32).cast::<Option<NonZeroU8>>().closest(), None);
+    assert_eq!(2.5f64.cast::<Option<NonZeroI8>>().closest(), NonZeroI8::new(3));
+    assert_eq!(f32::NAN.cast::<Option<NonZeroU8>>().unwrap_err().kind(), LossKind::NaN);
+
+    // Casts from NonZero* behave as for the underlying primitive
+    let error = NonZeroU16::new(256).unwrap().cast::<Option<NonZeroU8>>().unwrap_err();
+    assert_eq!(error, LossyCastError {from: NonZeroU16::new(256).unwrap(), to: None});
+    assert_eq!(error.closest(), NonZeroU8::new(u8::MAX));
+}
+
+#[test]
+fn from_option() {
+    // None becomes zero, and casts to primitives of sufficient width are lossless
+    assert_eq!(None::<NonZeroU32>.cast::<u32>().lossless(), 0u32);
+    assert_eq!(NonZeroU32::new(7).cast::<u32>().lossless(), 7u32);
+    assert_eq!(NonZeroU8::new(200).cast::<u64>().portable_lossless(), 200u64);
+    assert_eq!(NonZeroI8::new(-3).cast::<f32>().lossless(), -3f32);
+
+    // Range checking across widths
+    let error = NonZeroU16::new(300).cast::<u8>().unwrap_err();
+    assert_eq!(error, LossyCastError {from: NonZeroU16::new(300), to: 44u8});
+    assert_eq!(error.kind(), LossKind::Overflow);
+    assert_eq!(NonZeroU16::new(300).cast::<u8>().closest(), u8::MAX);
+    assert_eq!(NonZeroI32::new(-1).cast::<u32>().unwrap_err().kind(), LossKind::Underflow);
+    assert_eq!(NonZeroU32::new(16_777_217).cast::<f32>().unwrap_err().kind(), LossKind::Precision);
+
+    // Casts to NonZero* fail for None
+    let error = None::<NonZeroU32>.cast::<NonZeroU8>().unwrap_err();
+    assert_eq!(error, FailedCastError::new(None));
+    assert_eq!(error.closest(), NonZeroU8::MIN);
+    assert_eq!(NonZeroU32::new(9).cast::<NonZeroU8>().unwrap(), NonZeroU8::new(9).unwrap());
+}
+
+#[test]
+fn between_options() {
+    assert_eq!(NonZeroU8::new(200).cast::<Option<NonZeroU16>>().lossless(), NonZeroU16::new(200));
+    assert_eq!(None::<NonZeroU8>.cast::<Option<NonZeroI32>>().lossless(), None);
+    assert_eq!(NonZeroU16::new(256).cast::<Option<NonZeroU8>>().lossy(), None);
+    assert_eq!(NonZeroU16::new(256).cast::<Option<NonZeroU8>>().closest(), NonZeroU8::new(255));
+    assert_eq!(NonZeroI8::new(-1).cast::<Option<NonZeroU8>>().closest(), None);
+}
+
+#[test]
+fn bitwise() {
+    // The niche layout makes None equivalent to zero
+    assert_eq!(0u32.cast::<Option<NonZeroU32>>().bitwise(), None);
+    assert_eq!(None::<NonZeroU32>.cast::<u32>().bitwise(), 0u32);
+    assert_eq!((-1i32).cast::<Option<NonZeroU32>>().bitwise(), NonZeroU32::new(u32::MAX));
+    assert_eq!(NonZeroU32::new(u32::MAX).cast::<i32>().bitwise(), -1i32);
+    assert_eq!(1f32.cast::<Option<NonZeroU32>>().bitwise(), NonZeroU32::new(0x3f80_0000));
+    assert_eq!(NonZeroU32::new(0x3f80_0000).cast::<f32>().bitwise(), 1f32);
+    let bits = i32::from_ne_bytes((-2.5f32).to_ne_bytes());
+    assert_eq!((-2.5f32).cast::<Option<NonZeroI32>>().bitwise(), NonZeroI32::new(bits));
+    assert_eq!(NonZeroI8::new(-1).cast::<Option<NonZeroU8>>().bitwise(), NonZeroU8::new(255));
+    assert_eq!(0.0f64.cast::<Option<NonZeroU64>>().bitwise(), None);
+}
@@ -0,0 +1,117 @@
+//! These tests cover the `PortableLossless` trait. As with `Lossless`, the compilation failure
+//! cases are covered by doctests; the success cases are checked here.
+
+use crate::util::IsNaN;
+use cove::bounds::CastToPortableLossless;
+use cove::prelude::*;
+use core::num::{
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+};
+
+macro_rules! success {
+    ($name:ident as $source:ty => $($target:ty),*) => {
+        #[test]
+        #[allow(clippy::float_cmp)]
+        fn $name () {
+            // Initialization: allocate space for the test buffers and determine the initial seed
+            let mut random = crate::util::random_seed();
+
+            // Perform the tests
+            for _ in 0 .. crate::util::settings::FAST_ITERATIONS {
+                // Generate the test value and next random number
+                let (buffer, next_random) = crate::util::random_bytes(random);
+                let value = <$source>::from_ne_bytes(buffer);
+                random = next_random;
+
+                // Validate that the cast yields the same value as the `as` keyword for each target
+                $(
+                    {
+                        let lhs = value.cast::<$target>().portable_lossless();
+                        let rhs = value as $target;
+                        assert!(lhs == rhs || (lhs.is_nan() && rhs.is_nan()));
+                    }
+                )*
+            }
+        }
+    }
+}
+
+success!(random_u8   as u8   => u8, u16, u32, u64, u128, i16, i32, i64, i128, f32, f64);
+success!(random_u16  as u16  =>     u16, u32, u64, u128,      i32, i64, i128, f32, f64);
+success!(random_u32  as u32  =>          u32, u64, u128,           i64, i128,      f64);
+success!(random_u64  as u64  =>               u64, u128,                i128          );
+success!(random_u128 as u128 =>                    u128                               );
+
+success!(random_i8   as i8   => i8, i16, i32, i64, i128, f32, f64);
+success!(random_i16  as i16  =>     i16, i32, i64, i128, f32, f64);
+success!(random_i32  as i32  =>          i32, i64, i128,      f64);
+success!(random_i64  as i64  =>               i64, i128          );
+success!(random_i128 as i128 =>                    i128          );
+
+success!(random_f32 as f32 => f32, f64);
+success!(random_f64 as f64 => f64);
+
+success!(random_usize as usize => usize, u128);
+success!(random_isize as isize => isize, i128);
+
+success!(random_usize_from_u8  as u8  => usize, isize);
+success!(random_usize_from_u16 as u16 => usize);
+success!(random_isize_from_i8  as i8  => isize);
+success!(random_isize_from_i16 as i16 => isize);
+
+#[test]
+fn nonzero() {
+    let value = NonZeroU8::new(200).unwrap();
+    assert_eq!(value.cast::<NonZeroU16>().portable_lossless(), NonZeroU16::new(200).unwrap());
+    assert_eq!(value.cast::<NonZeroI16>().portable_lossless(), NonZeroI16::new(200).unwrap());
+    assert_eq!(value.cast::<f32>().portable_lossless(), 200f32);
+    assert_eq!(value.cast::<NonZeroUsize>().portable_lossless(), NonZeroUsize::new(200).unwrap());
+    assert_eq!(value.cast::<NonZeroIsize>().portable_lossless(), NonZeroIsize::new(200).unwrap());
+    assert_eq!(value.cast::<usize>().portable_lossless(), 200usize);
+    assert_eq!(value.cast::<isize>().portable_lossless(), 200isize);
+
+    let value = NonZeroU16::MAX;
+    assert_eq!(value.cast::<NonZeroUsize>().portable_lossless().get(), 65_535usize);
+    assert_eq!(value.cast::<usize>().portable_lossless(), 65_535usize);
+
+    let value = NonZeroI8::MIN;
+    assert_eq!(value.cast::<NonZeroIsize>().portable_lossless(), NonZeroIsize::new(-128).unwrap());
+    assert_eq!(value.cast::<isize>().portable_lossless(), -128isize);
+
+    let value = NonZeroI16::MIN;
+    assert_eq!(value.cast::<NonZeroIsize>().portable_lossless().get(), -32_768isize);
+    assert_eq!(value.cast::<isize>().portable_lossless(), -32_768isize);
+
+    let value = NonZeroUsize::MAX;
+    assert_eq!(value.cast::<NonZeroU128>().portable_lossless().get(), usize::MAX as u128);
+    assert_eq!(value.cast::<u128>().portable_lossless(), usize::MAX as u128);
+
+    let value = NonZeroIsize::MIN;
+    assert_eq!(value.cast::<NonZeroI128>().portable_lossless().get(), isize::MIN as i128);
+    assert_eq!(value.cast::<i128>().portable_lossless(), isize::MIN as i128);
+
+    assert_eq!(NonZeroU32::MAX.cast::<NonZeroI64>().portable_lossless().get(), u32::MAX.into());
+    assert_eq!(NonZeroU64::MAX.cast::<u128>().portable_lossless(), u64::MAX.into());
+    assert_eq!(NonZeroI32::MIN.cast::<f64>().portable_lossless(), f64::from(i32::MIN));
+    assert_eq!(NonZeroI64::MIN.cast::<NonZeroI128>().portable_lossless().get(), i64::MIN.into());
+}
+
+#[test]
+fn bounds() {
+    fn to_usize(value: impl CastToPortableLossless<usize>) -> usize {
+        value.cast().portable_lossless()
+    }
+
+    fn to_i128(value: impl CastToPortableLossless<i128>) -> i128 {
+        // The Lossless supertrait is available as well
+        value.cast().lossless()
+    }
+
+    assert_eq!(to_usize(7u8), 7);
+    assert_eq!(to_usize(u16::MAX), 65_535);
+    assert_eq!(to_usize(NonZeroU16::new(9).unwrap()), 9);
+    assert_eq!(to_usize(3usize), 3);
+    assert_eq!(to_i128(isize::MIN), isize::MIN as i128);
+    assert_eq!(to_i128(u64::MAX), u64::MAX.into());
+}
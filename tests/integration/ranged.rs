@@ -0,0 +1,84 @@
+//! These tests cover casts to and from the `Ranged` type
+
+use cove::prelude::*;
+use cove::types::Ranged;
+use core::num::{NonZeroI8, NonZeroU16, NonZeroU8};
+
+type Percent = Ranged<u8, 0, 100>;
+type Channel = Ranged<i16, -8, 7>;
+
+#[test]
+fn construction() {
+    assert_eq!(Percent::new(100).unwrap().get(), 100u8);
+    assert!(Percent::new(101).is_none());
+    assert_eq!(Percent::MIN.get(), 0u8);
+    assert_eq!(Percent::MAX.get(), 100u8);
+    assert_eq!(Channel::MIN.get(), -8i16);
+    assert_eq!(Ranged::<u128, 5, { i128::MAX }>::MAX.get(), i128::MAX as u128);
+}
+
+#[test]
+fn into_ranged() {
+    // Lossless
+    assert_eq!(37u64.cast::<Percent>().unwrap().get(), 37u8);
+    assert_eq!((-8i128).cast::<Channel>().unwrap().get(), -8i16);
+    assert_eq!(6.0f32.cast::<Channel>().unwrap().get(), 6i16);
+    assert_eq!(NonZeroU16::new(100).unwrap().cast::<Percent>().unwrap().get(), 100u8);
+
+    // Outside the primitive type
+    assert_eq!(300u32.cast::<Percent>().unwrap_err().from, 300u32);
+    assert_eq!((-1i8).cast::<Percent>().unwrap_err().from, -1i8);
+
+    // Outside the range
+    assert_eq!(101u8.cast::<Percent>().unwrap_err().from, 101u8);
+    assert_eq!(8isize.cast::<Channel>().unwrap_err().from, 8isize);
+    assert_eq!(NonZeroI8::new(-9).unwrap().cast::<Channel>().unwrap_err().from.get(), -9i8);
+
+    // Not an integer
+    assert!(6.5f64.cast::<Channel>().is_err());
+    assert!(f32::NAN.cast::<Percent>().is_err());
+}
+
+#[test]
+fn from_ranged() {
+    let percent = Percent::new(99).unwrap();
+    assert_eq!(percent.cast::<i8>().unwrap(), 99i8);
+    assert_eq!(percent.cast::<f32>().unwrap(), 99f32);
+    assert_eq!(percent.cast::<NonZeroU8>().unwrap().get(), 99u8);
+
+    let channel = Channel::new(-3).unwrap();
+    assert_eq!(channel.cast::<u8>().unwrap_err().to, 253u8);
+    assert_eq!(channel.cast::<u8>().lossy(), 253u8);
+    assert_eq!(Channel::new(0).unwrap().cast::<NonZeroI8>().unwrap_err().from.get(), 0i16);
+}
+
+#[test]
+fn closest() {
+    // Into the range: clamp to the bounds
+    assert_eq!(250u16.cast::<Percent>().closest().get(), 100u8);
+    assert_eq!((-4i32).cast::<Percent>().closest().get(), 0u8);
+    assert_eq!(u128::MAX.cast::<Channel>().closest().get(), 7i16);
+    assert_eq!(f64::NEG_INFINITY.cast::<Channel>().closest().get(), -8i16);
+    assert_eq!(2.5f32.cast::<Channel>().closest().get(), 3i16);
+
+    // Out of the range: as for the primitive
+    assert_eq!(Channel::new(-3).unwrap().cast::<u8>().closest(), 0u8);
+    assert_eq!(Channel::new(0).unwrap().cast::<NonZeroU8>().closest().get(), 1u8);
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn lossless() {
+    // Out of the range: the range proves the cast lossless
+    assert_eq!(Percent::new(100).unwrap().cast::<i8>().lossless(), 100i8);
+    assert_eq!(Channel::new(-8).unwrap().cast::<i8>().lossless(), -8i8);
+    assert_eq!(Channel::new(7).unwrap().cast::<f32>().lossless(), 7f32);
+    assert_eq!(Ranged::<u32, 0, 16_777_216>::MAX.cast::<f32>().lossless(), 16_777_216f32);
+    assert_eq!(Ranged::<u64, 1, 255>::MIN.cast::<NonZeroU8>().lossless().get(), 1u8);
+    assert_eq!(Ranged::<i64, -5, -1>::MAX.cast::<NonZeroI8>().lossless().get(), -1i8);
+
+    // Into the range: the range of the source proves the cast lossless
+    assert_eq!(200u8.cast::<Ranged<u16, 0, 255>>().lossless().get(), 200u16);
+    assert_eq!((-128i8).cast::<Ranged<i64, -200, 200>>().lossless().get(), -128i64);
+    assert_eq!(NonZeroU8::new(3).unwrap().cast::<Ranged<u8, 1, 255>>().lossless().get(), 3u8);
+}
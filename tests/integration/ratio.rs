@@ -0,0 +1,155 @@
+//! These tests cover the rational type `Ratio`, checking it exhaustively against f64 arithmetic for
+//! 8-bit integers and its approximation of floats against a brute-force search
+
+use cove::prelude::*;
+use cove::errors::{LossKind, LossyCastError};
+use cove::types::Ratio;
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn exhaustive() {
+    for numer in i8::MIN ..= i8::MAX {
+        for denom in 1 ..= i8::MAX {
+            let ratio = Ratio::<i8>::new(numer, denom).unwrap();
+            let value = f64::from(numer) / f64::from(denom);
+            let dyadic = ratio.denom().count_ones() == 1;
+
+            // Into floats, which are correctly rounded by a single division
+            assert_eq!(ratio.cast::<f64>().is_ok(), dyadic);
+            assert_eq!(ratio.cast::<f64>().lossy(), value);
+            assert_eq!(ratio.cast::<f32>().lossy(), f32::from(numer) / f32::from(denom));
+
+            // Into integers, truncating and then wrapping or rounding and then saturating
+            let casted = ratio.cast::<u8>();
+            assert_eq!(casted.is_ok(), ratio.denom() == 1 && numer >= 0);
+            assert_eq!(casted.lossy(), value.trunc() as i8 as u8);
+            assert_eq!(casted.closest(), value.round() as u8);
+            assert_eq!(ratio.cast::<i64>().closest(), value.round() as i64);
+
+            // From floats, exactly when dyadic and otherwise approximating back to the ratio, since
+            // the float is much closer to it than to any other
+            let result = value.cast::<Ratio<i8>>();
+            assert_eq!(result.is_ok(), dyadic);
+            assert_eq!(result.closest(), ratio);
+            assert_eq!(value.cast::<Ratio<i128>>().unwrap().cast::<f64>().unwrap(), value);
+        }
+    }
+}
+
+#[test]
+fn approximation() {
+    // The closest ratio to a float, by brute force over every numerator and denominator
+    fn closest(value: f64) -> (f64, Ratio<i8>) {
+        let mut best = (f64::INFINITY, Ratio::<i8>::ZERO);
+
+        for numer in i8::MIN ..= i8::MAX {
+            for denom in 1 ..= i8::MAX {
+                let distance = (f64::from(numer) / f64::from(denom) - value).abs();
+
+                if distance < best.0 {
+                    best = (distance, Ratio::<i8>::new(numer, denom).unwrap());
+                }
+            }
+        }
+
+        best
+    }
+
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+
+    for _ in 0 .. 2000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        let value = (state >> 11) as f64 / (1u64 << 53) as f64 * 300.0 - 150.0;
+        let (distance, expected) = closest(value);
+        let found = value.cast::<Ratio<i8>>().closest();
+        let error = (found.cast::<f64>().closest() - value).abs();
+
+        // Ties may resolve to either ratio of equal distance
+        assert!(found == expected || error == distance, "{value}: {found} vs {expected}");
+    }
+}
+
+#[test]
+fn from_floats() {
+    // Floats decompose into their exact dyadic value
+    let ratio = (-0.375f32).cast::<Ratio<i16>>().unwrap();
+    assert_eq!((ratio.numer(), ratio.denom()), (-3, 8));
+    assert_eq!(0.0f64.cast::<Ratio<u8>>().unwrap(), Ratio::<u8>::ZERO);
+    assert_eq!((-0.0f64).cast::<Ratio<i8>>().unwrap(), Ratio::<i8>::ZERO);
+    assert_eq!(f64::MIN_POSITIVE.cast::<Ratio<i128>>().closest(), Ratio::<i128>::ZERO);
+
+    // Values out of range saturate and NaN yields zero
+    let error = 300.5f64.cast::<Ratio<u8>>().unwrap_err();
+    assert_eq!(error, LossyCastError {from: 300.5, to: Ratio::<u8>::MAX});
+    assert_eq!(error.kind(), LossKind::Overflow);
+    assert_eq!((-1e30f64).cast::<Ratio<i64>>().closest(), Ratio::<i64>::MIN);
+    assert_eq!((-0.5f64).cast::<Ratio<u8>>().closest(), Ratio::<u8>::ZERO);
+    assert_eq!(f64::INFINITY.cast::<Ratio<i32>>().closest(), Ratio::<i32>::MAX);
+    assert_eq!(f64::NAN.cast::<Ratio<i32>>().unwrap_err().kind(), LossKind::NaN);
+    assert_eq!(f64::NAN.cast::<Ratio<i32>>().closest(), Ratio::<i32>::ZERO);
+
+    // Values which do not fit are approximated
+    let pi = core::f64::consts::PI.cast::<Ratio<i16>>();
+    assert_eq!(pi.closest(), Ratio::<i16>::new(355, 113).unwrap());
+    assert_eq!(pi.unwrap_err().kind(), LossKind::Precision);
+    assert_eq!(0.1f64.cast::<Ratio<u32>>().closest(), Ratio::<u32>::new(1, 10).unwrap());
+    assert_eq!(1e-3f64.cast::<Ratio<u8>>().closest(), Ratio::<u8>::ZERO);
+    assert_eq!(3e-3f64.cast::<Ratio<u8>>().closest(), Ratio::<u8>::new(1, 255).unwrap());
+}
+
+#[test]
+fn from_integers() {
+    assert_eq!(7u8.cast::<Ratio<i16>>().lossless(), Ratio::<i16>::from_integer(7));
+    let ratio = i32::MIN.cast::<Ratio<i64>>().portable_lossless();
+    assert_eq!(ratio, Ratio::<i64>::from_integer(-1 << 31));
+    let ratio = u64::MAX.cast::<Ratio<u128>>().lossless();
+    assert_eq!(ratio, Ratio::<u128>::from_integer(u128::from(u64::MAX)));
+
+    // Integers out of range wrap or saturate as for the primitive integers
+    let error = 300u16.cast::<Ratio<u8>>().unwrap_err();
+    assert_eq!(error, LossyCastError {from: 300, to: Ratio::<u8>::from_integer(44)});
+    assert_eq!(error.kind(), LossKind::Overflow);
+    assert_eq!(error.closest(), Ratio::<u8>::MAX);
+    assert_eq!((-1i32).cast::<Ratio<u32>>().closest(), Ratio::<u32>::ZERO);
+    assert_eq!(5u16.cast::<Ratio<i16>>().unwrap(), Ratio::<i16>::from_integer(5));
+}
+
+#[test]
+fn into_numbers() {
+    // Into integers across the full range
+    assert_eq!(Ratio::<i128>::MIN.cast::<i128>().unwrap(), i128::MIN);
+    assert_eq!(Ratio::<i64>::new(-7, 2).unwrap().cast::<i32>().closest(), -4);
+    assert_eq!(Ratio::<i64>::new(-7, 2).unwrap().cast::<i32>().lossy(), -3);
+    assert_eq!(Ratio::<u128>::new(u128::MAX, 2).unwrap().cast::<u128>().closest(), 1 << 127);
+
+    let error = Ratio::<u8>::new(1, 3).unwrap().cast::<u8>().unwrap_err();
+    assert_eq!(error.kind(), LossKind::Fraction);
+    assert!((error.loss().to_f64() - 1.0 / 3.0).abs() < 1e-15);
+
+    // Into floats, rounding correctly even beyond the precision of the operands
+    let ratio = Ratio::<u128>::new(u128::MAX, 3).unwrap();
+    assert_eq!(ratio.cast::<f64>().unwrap_err().to, u128::MAX as f64 / 3.0);
+    assert_eq!(Ratio::<i64>::new(1, 1 << 62).unwrap().cast::<f32>().unwrap(), 2f32.powi(-62));
+    let ratio = Ratio::<u128>::new(1, u128::MAX).unwrap();
+    assert_eq!(ratio.cast::<f32>().closest(), f32::MIN_POSITIVE / 4.0);
+}
+
+#[test]
+fn construction() {
+    // Values are kept in lowest terms with a positive denominator
+    let ratio = Ratio::<i32>::new(6, -4).unwrap();
+    assert_eq!((ratio.numer(), ratio.denom()), (-3, 2));
+    assert_eq!(Ratio::<i8>::new(0, -5).unwrap(), Ratio::<i8>::ZERO);
+    assert_eq!(Ratio::<i8>::new(i8::MIN, -128).unwrap(), Ratio::<i8>::from_integer(1));
+    assert_eq!(Ratio::<u8>::new(1, 0), None);
+    assert_eq!(Ratio::<i8>::new(i8::MIN, -1), None);
+    assert_eq!(Ratio::<i8>::new(i8::MIN, -2).unwrap(), Ratio::<i8>::from_integer(64));
+
+    // Display omits a denominator of one
+    assert_eq!(Ratio::<u16>::new(10, 4).unwrap().to_string(), "5/2");
+    assert_eq!(Ratio::<i16>::new(-10, 5).unwrap().to_string(), "-2");
+    assert_eq!(Ratio::<i32>::ZERO.to_string(), "0");
+}
@@ -0,0 +1,160 @@
+//! These tests cover the refined float types `Finite`, `NonNan`, `NonNegative` and `UnitInterval`,
+//! checking them against the casts of the underlying primitives for random values
+
+use cove::prelude::*;
+use cove::errors::{LossKind, LossyCastError};
+use cove::types::{Finite, NonNan, NonNegative, UnitInterval};
+
+/// Yields pseudorandom f64 values covering every exponent, along with NaN, the infinities, zeros
+/// and small integers
+fn values() -> impl Iterator<Item = f64> {
+    let mut state = 0x853c_49e6_748f_ea9bu64;
+
+    let special = [
+        f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 0.0, -0.0, 1.0, -1.0, 0.5, 255.0, 4e9
+    ];
+
+    special.into_iter().chain((0 .. 100_000).map(move |index| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        match index % 2 {
+            0 => f64::from_bits(state),
+            _ => (state >> 40) as f64 / f64::from(1 << (state % 32))
+        }
+    }))
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn from_floats() {
+    for value in values() {
+        let narrowed = value.cast::<f32>();
+
+        // Casts succeed exactly when the primitive cast does and yields a valid value
+        let result = value.cast::<Finite<f32>>();
+        assert_eq!(result.is_ok(), narrowed.is_ok() && narrowed.lossy().is_finite(), "{value}");
+        let closest = narrowed.closest();
+        let expected = if closest.is_nan() {0.0} else {closest.clamp(f32::MIN, f32::MAX)};
+        assert_eq!(result.closest().get(), expected);
+
+        let result = value.cast::<NonNan<f32>>();
+        assert_eq!(result.is_ok(), narrowed.is_ok() && !value.is_nan());
+
+        let result = value.cast::<NonNegative<f64>>();
+        assert_eq!(result.is_ok(), value >= 0.0);
+        assert_eq!(result.lossy().get(), if value.is_nan() {0.0} else {value.max(0.0)});
+
+        let result = value.cast::<UnitInterval<f64>>();
+        assert_eq!(result.is_ok(), (0.0 ..= 1.0).contains(&value));
+        assert_eq!(result.closest().get(), if value.is_nan() {0.0} else {value.clamp(0.0, 1.0)});
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn into_primitives() {
+    for value in values().filter(|value| !value.is_nan()) {
+        let refined = NonNan::<f64>::new(value).unwrap();
+        assert_eq!(refined.cast::<i32>(), value.cast::<i32>().map_err(|error| LossyCastError {
+            from: refined,
+            to: error.to
+        }));
+
+        assert_eq!(refined.cast::<i32>().closest(), value.cast::<i32>().closest());
+        assert_eq!(refined.cast::<f32>().lossy(), value.cast::<f32>().lossy());
+
+        // NonNegative skips the check of the sign, but otherwise behaves as the primitive
+        if let Some(refined) = NonNegative::<f64>::new(value) {
+            assert_eq!(refined.cast::<u8>().ok(), value.cast::<u8>().ok(), "{value}");
+            assert_eq!(refined.cast::<u32>().ok(), value.cast::<u32>().ok(), "{value}");
+            assert_eq!(refined.cast::<u64>().ok(), value.cast::<u64>().ok(), "{value}");
+            assert_eq!(refined.cast::<u64>().lossy(), value.cast::<u64>().lossy());
+            assert_eq!(refined.cast::<u128>().is_ok(), value.cast::<u128>().is_ok());
+            assert_eq!(refined.cast::<u128>().lossy(), value.cast::<u128>().lossy());
+            assert_eq!(refined.cast::<u16>().closest(), value.cast::<u16>().closest());
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        if let Some(refined) = NonNegative::<f32>::new(value as f32) {
+            assert_eq!(refined.cast::<usize>().is_ok(), (value as f32).cast::<usize>().is_ok());
+            assert_eq!(refined.cast::<usize>().lossy(), (value as f32).cast::<usize>().lossy());
+        }
+    }
+}
+
+#[test]
+fn kinds() {
+    assert_eq!(f64::NAN.cast::<Finite<f64>>().unwrap_err().kind(), LossKind::NaN);
+    assert_eq!(f64::INFINITY.cast::<Finite<f64>>().unwrap_err().kind(), LossKind::Infinity);
+    assert_eq!(f64::MAX.cast::<Finite<f32>>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!((-0.5f32).cast::<NonNegative<f32>>().unwrap_err().kind(), LossKind::Underflow);
+    assert_eq!(1.5f64.cast::<UnitInterval<f32>>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!(0.1f64.cast::<UnitInterval<f32>>().unwrap_err().kind(), LossKind::Precision);
+    assert_eq!(u128::MAX.cast::<Finite<f32>>().unwrap_err().kind(), LossKind::Overflow);
+
+    // Overflow to infinity saturates, both for the lossy value and for Closest
+    let error = 1e39f64.cast::<Finite<f32>>().unwrap_err();
+    assert_eq!(error, LossyCastError {from: 1e39, to: Finite::<f32>::MAX});
+    assert_eq!(error.closest(), Finite::<f32>::MAX);
+    assert_eq!((-1e39f64).cast::<NonNan<f32>>().unwrap_err().to.get(), f32::NEG_INFINITY);
+    assert_eq!((-1e39f64).cast::<NonNan<f32>>().closest(), NonNan::<f32>::new(f32::MIN).unwrap());
+}
+
+#[test]
+fn integers() {
+    // Integers are lossless whenever they are valid and cast losslessly to the primitive
+    assert_eq!(i16::MIN.cast::<Finite<f32>>().lossless().get(), -32_768.0);
+    assert_eq!(u32::MAX.cast::<NonNegative<f64>>().lossless().get(), 4_294_967_295.0);
+    assert_eq!(i32::MIN.cast::<NonNan<f64>>().portable_lossless().get(), -2_147_483_648.0);
+    assert_eq!(9u8.cast::<NonNegative<f32>>().portable_lossless().get(), 9.0);
+
+    // Otherwise they are checked, and the lossy value clamped
+    let error = (-5i8).cast::<NonNegative<f32>>().unwrap_err();
+    assert_eq!(error, LossyCastError {from: -5, to: NonNegative::<f32>::MIN});
+    assert_eq!(error.closest(), NonNegative::<f32>::MIN);
+    assert_eq!(0u64.cast::<UnitInterval<f32>>().unwrap(), UnitInterval::<f32>::MIN);
+    assert_eq!(7i128.cast::<UnitInterval<f64>>().closest(), UnitInterval::<f64>::MAX);
+    assert_eq!(u64::MAX.cast::<NonNegative<f64>>().closest().get(), 18_446_744_073_709_551_615.0);
+    assert_eq!(u128::MAX.cast::<Finite<f32>>().unwrap_err().to, Finite::<f32>::MAX);
+    assert_eq!(u128::MAX.cast::<NonNan<f32>>().unwrap_err().to.get(), f32::INFINITY);
+}
+
+#[test]
+fn between() {
+    // Widening is lossless, while narrowing is checked and clamped
+    let value = Finite::<f32>::new(0.1).unwrap();
+    assert_eq!(value.cast::<Finite<f64>>().lossless().get(), f64::from(0.1f32));
+    let value = UnitInterval::<f32>::MAX.cast::<UnitInterval<f64>>().portable_lossless();
+    assert_eq!(value, UnitInterval::<f64>::MAX);
+
+    let value = Finite::<f64>::new(1e300).unwrap();
+    assert_eq!(value.cast::<Finite<f32>>().unwrap_err().to, Finite::<f32>::MAX);
+    assert_eq!(value.cast::<Finite<f32>>().closest(), Finite::<f32>::MAX);
+
+    let value = NonNan::<f64>::new(-1e300).unwrap();
+    assert_eq!(value.cast::<NonNan<f32>>().unwrap_err().to.get(), f32::NEG_INFINITY);
+    assert_eq!(value.cast::<NonNan<f32>>().closest().get(), f32::MIN);
+    assert_eq!(NonNan::<f64>::MAX.cast::<NonNan<f32>>().unwrap(), NonNan::<f32>::MAX);
+
+    let value = UnitInterval::<f64>::new(0.1).unwrap();
+    assert_eq!(value.cast::<UnitInterval<f32>>().unwrap_err().kind(), LossKind::Precision);
+    assert_eq!(value.cast::<UnitInterval<f32>>().closest().get(), 0.1f32);
+}
+
+#[test]
+fn construction() {
+    assert_eq!(Finite::<f64>::new(f64::NAN), None);
+    assert_eq!(Finite::<f32>::new(f32::INFINITY), None);
+    assert_eq!(NonNan::<f32>::new(f32::NEG_INFINITY).unwrap(), NonNan::<f32>::MIN);
+    assert_eq!(NonNegative::<f64>::new(-0.0).unwrap().get(), 0.0);
+    assert_eq!(NonNegative::<f64>::new(-f64::MIN_POSITIVE), None);
+    assert_eq!(UnitInterval::<f32>::new(1.0 + f32::EPSILON), None);
+    assert!(Finite::<f64>::MIN < Finite::<f64>::MAX);
+
+    // Display forwards to the underlying primitive
+    assert_eq!(UnitInterval::<f64>::new(0.25).unwrap().to_string(), "0.25");
+    assert_eq!(format!("{:.1}", NonNan::<f32>::MAX), "inf");
+    assert_eq!(format!("{:>6}", Finite::<f32>::new(-1.5).unwrap()), "  -1.5");
+}
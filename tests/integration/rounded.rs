@@ -0,0 +1,125 @@
+//! These tests cover the `Rounded` trait for floating point primitives to integers and `NonZero*`
+
+use cove::prelude::*;
+use cove::casts::RoundingMode;
+use core::num::{NonZeroI16, NonZeroU32};
+
+#[test]
+fn lossless() {
+    assert_eq!(7f32.cast::<u8>().rounded(RoundingMode::Floor), 7u8);
+    assert_eq!((-7f64).cast::<i64>().rounded(RoundingMode::Ceil), -7i64);
+    assert_eq!(0f64.cast::<usize>().rounded(RoundingMode::HalfEven), 0usize);
+}
+
+#[test]
+fn modes() {
+    assert_eq!((-2.5f32).cast::<i32>().rounded(RoundingMode::Floor), -3i32);
+    assert_eq!((-2.5f32).cast::<i32>().rounded(RoundingMode::Ceil), -2i32);
+    assert_eq!((-2.5f32).cast::<i32>().rounded(RoundingMode::TowardZero), -2i32);
+    assert_eq!((-2.5f32).cast::<i32>().rounded(RoundingMode::HalfAwayFromZero), -3i32);
+    assert_eq!((-2.5f32).cast::<i32>().rounded(RoundingMode::HalfEven), -2i32);
+
+    assert_eq!(3.5f64.cast::<u16>().rounded(RoundingMode::Floor), 3u16);
+    assert_eq!(3.5f64.cast::<u16>().rounded(RoundingMode::Ceil), 4u16);
+    assert_eq!(3.5f64.cast::<u16>().rounded(RoundingMode::TowardZero), 3u16);
+    assert_eq!(3.5f64.cast::<u16>().rounded(RoundingMode::HalfAwayFromZero), 4u16);
+    assert_eq!(3.5f64.cast::<u16>().rounded(RoundingMode::HalfEven), 4u16);
+
+    assert_eq!(3.49f64.cast::<i8>().rounded(RoundingMode::HalfAwayFromZero), 3i8);
+    assert_eq!(3.51f64.cast::<i8>().rounded(RoundingMode::HalfEven), 4i8);
+}
+
+#[test]
+fn saturating() {
+    assert_eq!(255.5f32.cast::<u8>().rounded(RoundingMode::Ceil), 255u8);
+    assert_eq!((-0.5f32).cast::<u8>().rounded(RoundingMode::Floor), 0u8);
+    assert_eq!(1e300f64.cast::<i128>().rounded(RoundingMode::TowardZero), i128::MAX);
+    assert_eq!((-1e300f64).cast::<i128>().rounded(RoundingMode::HalfEven), i128::MIN);
+    assert_eq!(f32::INFINITY.cast::<u64>().rounded(RoundingMode::Floor), u64::MAX);
+    assert_eq!(f64::NEG_INFINITY.cast::<isize>().rounded(RoundingMode::Ceil), isize::MIN);
+}
+
+#[test]
+fn nan() {
+    assert_eq!(f32::NAN.cast::<i8>().rounded(RoundingMode::Ceil), 0i8);
+    assert_eq!(f64::NAN.cast::<u32>().rounded(RoundingMode::Floor), 0u32);
+}
+
+#[test]
+fn nonzero() {
+    assert_eq!(
+        2.5f32.cast::<NonZeroU32>().rounded(RoundingMode::HalfEven),
+        NonZeroU32::new(2).unwrap()
+    );
+
+    assert_eq!(
+        0.7f64.cast::<NonZeroU32>().rounded(RoundingMode::Floor),
+        NonZeroU32::new(1).unwrap()
+    );
+
+    assert_eq!(
+        (-0.7f64).cast::<NonZeroI16>().rounded(RoundingMode::Ceil),
+        NonZeroI16::new(-1).unwrap()
+    );
+
+    assert_eq!(
+        (-1e10f32).cast::<NonZeroI16>().rounded(RoundingMode::Floor),
+        NonZeroI16::new(i16::MIN).unwrap()
+    );
+}
+
+macro_rules! random {
+    ($name:ident as $source:ty => $($target:ty),+) => {
+        #[test]
+        #[allow(
+            clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_possible_wrap
+        )]
+        fn $name () {
+            // Initialization: allocate space for the test buffers and determine the initial seed
+            let mut random = crate::util::random_seed();
+
+            // Perform the tests
+            for _ in 0 .. crate::util::settings::FAST_ITERATIONS {
+                // Generate the test values and next random number; random bits rarely yield
+                // values with a fractional part, so also generate a value in units of 1/8
+                let (buffer, next_random) = crate::util::random_bytes(random);
+                let value = <$source>::from_ne_bytes(buffer);
+                let eighths = <$source>::from(random as i16) / 8.0;
+                random = next_random;
+
+                // Validate that the rounded cast yields the same value as rounding via std and
+                // then using the `as` keyword, which saturates
+                $(
+                    for value in [value, eighths] {
+                        let casted = value.cast::<$target>();
+                        assert_eq!(casted.rounded(RoundingMode::Floor), value.floor() as $target);
+                        assert_eq!(casted.rounded(RoundingMode::Ceil), value.ceil() as $target);
+                        assert_eq!(casted.rounded(RoundingMode::TowardZero), value as $target);
+                        
+                        assert_eq!(
+                            casted.rounded(RoundingMode::HalfAwayFromZero),
+                            value.round() as $target
+                        );
+
+                        assert_eq!(
+                            casted.rounded(RoundingMode::HalfEven),
+                            value.round_ties_even() as $target
+                        );
+                    }
+                )*
+            }
+        }
+    };
+}
+
+random!(
+    random_f32 as f32 =>
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize
+);
+
+random!(
+    random_f64 as f64 =>
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize
+);
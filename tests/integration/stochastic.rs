@@ -0,0 +1,98 @@
+//! These tests cover the `Stochastic` trait for floating point primitives to integers and to f32
+
+use cove::prelude::*;
+use cove::casts::StochasticRng;
+
+/// Simple xorshift generator for exercising Stochastic reproducibly
+struct XorShift(u64);
+
+impl StochasticRng for XorShift {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+/// Generator which always yields the same bits, for exercising the extremes of the probabilities
+struct Constant(u64);
+
+impl StochasticRng for Constant {
+    fn next_u64(&mut self) -> u64 {
+        self.0
+    }
+}
+
+#[test]
+fn lossless() {
+    let mut rng = XorShift(1);
+
+    assert_eq!(7f32.cast::<u8>().stochastic(&mut rng), 7u8);
+    assert_eq!((-7f64).cast::<i64>().stochastic(&mut rng), -7i64);
+    assert_eq!(0.25f64.cast::<f32>().stochastic(&mut rng), 0.25f32);
+    assert!(f64::NAN.cast::<f32>().stochastic(&mut rng).is_nan());
+}
+
+#[test]
+fn extremes() {
+    // A zero draw rounds away from the truncated value whenever there is a fraction
+    assert_eq!(2.001f64.cast::<u8>().stochastic(&mut Constant(0)), 3u8);
+    assert_eq!((-2.001f32).cast::<i8>().stochastic(&mut Constant(0)), -3i8);
+    assert_eq!(
+        0.1f64.cast::<f32>().stochastic(&mut Constant(0)),
+        f32::from_bits(0.1f32.to_bits() - 1)
+    );
+
+    // A maximal draw never does so
+    assert_eq!(2.999f64.cast::<u8>().stochastic(&mut Constant(u64::MAX)), 2u8);
+    assert_eq!((-2.999f32).cast::<i8>().stochastic(&mut Constant(u64::MAX)), -2i8);
+    assert_eq!(0.1f64.cast::<f32>().stochastic(&mut Constant(u64::MAX)), 0.1f32);
+}
+
+#[test]
+fn unbiased() {
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+
+    // Integers: the mean of many casts approaches the origin value
+    let sum: i64 = (0..100_000).map(|_| (-3.3f64).cast::<i64>().stochastic(&mut rng)).sum();
+    assert!((-331_000..-329_000).contains(&sum));
+
+    let sum: u32 = (0..100_000)
+        .map(|_| u32::from(0.9f32.cast::<u8>().stochastic(&mut rng)))
+        .sum();
+    assert!((89_000..91_000).contains(&sum));
+
+    // Floats: 1 + 2^-25 lies a quarter of the way between adjacent f32s
+    let origin = 1.0 + 2f64.powi(-25);
+    let upper = f32::from_bits(1f32.to_bits() + 1);
+    let count = (0..100_000)
+        .map(|_| origin.cast::<f32>().stochastic(&mut rng))
+        .inspect(|&value| assert!(value == 1.0 || value == upper))
+        .filter(|&value| value == upper)
+        .count();
+
+    assert!((24_000..26_000).contains(&count));
+}
+
+#[test]
+fn saturating() {
+    let mut rng = XorShift(7);
+
+    assert_eq!(255.5f32.cast::<u8>().stochastic(&mut Constant(0)), 255u8);
+    assert_eq!((-0.5f32).cast::<u8>().stochastic(&mut Constant(0)), 0u8);
+    assert_eq!(1e300f64.cast::<i128>().stochastic(&mut rng), i128::MAX);
+    assert_eq!(f64::NEG_INFINITY.cast::<isize>().stochastic(&mut rng), isize::MIN);
+    assert_eq!(1e300f64.cast::<f32>().stochastic(&mut rng), f32::MAX);
+    assert_eq!((-1e300f64).cast::<f32>().stochastic(&mut rng), f32::MIN);
+
+    // Just above f32::MAX the other neighbor would be infinity
+    let above = f64::from_bits(f64::from(f32::MAX).to_bits() + 1);
+    assert_eq!(above.cast::<f32>().stochastic(&mut Constant(0)), f32::MAX);
+}
+
+#[test]
+fn nan() {
+    assert_eq!(f32::NAN.cast::<i8>().stochastic(&mut Constant(0)), 0i8);
+    assert_eq!(f64::NAN.cast::<u32>().stochastic(&mut Constant(0)), 0u32);
+}
@@ -0,0 +1,177 @@
+//! These tests cover the 256-bit integer types `u256` and `i256`, checking them against the 128-bit
+//! primitives where their ranges overlap
+
+use cove::prelude::*;
+use cove::casts::RoundingMode;
+use cove::errors::LossKind;
+use cove::types::{i256, u256};
+use core::num::{NonZeroI32, NonZeroU8, NonZeroU128};
+
+#[test]
+fn representation() {
+    // Words and bytes are least significant first in little endian order
+    let value = u256::from_words([1, 2]);
+    assert_eq!(value.to_words(), [1, 2]);
+    assert_eq!(value.to_le_bytes()[0], 1);
+    assert_eq!(value.to_le_bytes()[16], 2);
+    assert_eq!(value.to_be_bytes()[31], 1);
+    assert_eq!(u256::from_be_bytes(value.to_be_bytes()), value);
+    assert_eq!(i256::from_ne_bytes(i256::MIN.to_ne_bytes()), i256::MIN);
+
+    // Ordering is numerical
+    assert!(i256::MIN < (-1i8).cast::<i256>().lossless());
+    assert!((-1i8).cast::<i256>().lossless() < i256::default());
+    assert!(u256::from_words([u128::MAX, 0]) < u256::from_words([0, 1]));
+
+    // Formatting
+    let expected = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+    assert_eq!(u256::MAX.to_string(), expected);
+    let expected = "-57896044618658097711785492504343953926634992332820282019728792003956564819968";
+    assert_eq!(i256::MIN.to_string(), expected);
+    assert_eq!(i256::default().to_string(), "0");
+    assert_eq!(format!("{:x}", u256::from_words([0xab, 1])), "1000000000000000000000000000000ab");
+    assert_eq!(format!("{:#X}", 255u8.cast::<u256>().lossless()), "0xFF");
+    assert_eq!(format!("{:>5}", 42u8.cast::<i256>().lossless()), "   42");
+}
+
+#[test]
+fn integers() {
+    // Lossless casts into the wide types
+    assert_eq!(u128::MAX.cast::<u256>().lossless().to_words(), [u128::MAX, 0]);
+    assert_eq!(i128::MIN.cast::<i256>().lossless().to_words(), [1 << 127, u128::MAX]);
+    assert_eq!(u64::MAX.cast::<i256>().lossless().to_words(), [u128::from(u64::MAX), 0]);
+    assert_eq!(NonZeroU128::MAX.cast::<u256>().lossless(), u128::MAX.cast().lossless());
+    assert_eq!(NonZeroI32::MIN.cast::<i256>().lossless(), i32::MIN.cast().lossless());
+    assert_eq!(usize::MAX.cast::<u256>().portable_lossless(), usize::MAX.cast().lossless());
+    assert_eq!(u256::MAX.cast::<u256>().lossless(), u256::MAX);
+
+    // Lossy casts into the wide types
+    let error = (-1i64).cast::<u256>().unwrap_err();
+    assert_eq!(error.to, u256::MAX);
+    assert_eq!(error.kind(), LossKind::Underflow);
+    assert_eq!((-1i64).cast::<u256>().closest(), u256::MIN);
+    assert_eq!((-1i64).cast::<u256>().wrapping(), u256::MAX);
+    assert_eq!(5i8.cast::<u256>().unwrap(), 5u8.cast().lossless());
+
+    // Between the wide types
+    assert_eq!(u256::MAX.cast::<i256>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!(u256::MAX.cast::<i256>().closest(), i256::MAX);
+    assert_eq!(u256::MAX.cast::<i256>().wrapping(), (-1i8).cast().lossless());
+    assert_eq!(i256::MIN.cast::<u256>().closest(), u256::MIN);
+    assert_eq!(i256::MAX.cast::<u256>().unwrap().to_words(), [u128::MAX, u128::MAX >> 1]);
+    assert_eq!(i256::MAX.cast::<u256>().bitwise(), u256::from_words([u128::MAX, u128::MAX >> 1]));
+    assert_eq!(i256::MIN.cast::<u256>().bitwise(), u256::from_words([0, 1 << 127]));
+
+    // Out of the wide types
+    assert_eq!(u256::from_words([300, 0]).cast::<u16>().unwrap(), 300);
+    assert_eq!(u256::from_words([300, 0]).cast::<u8>().closest(), u8::MAX);
+    assert_eq!(u256::from_words([300, 0]).cast::<u8>().wrapping(), 44);
+    assert_eq!(u256::from_words([0, 1]).cast::<u128>().unwrap_err().to, 0);
+    assert_eq!(i256::MIN.cast::<i128>().closest(), i128::MIN);
+    assert_eq!(i256::MIN.cast::<i128>().unwrap_err().kind(), LossKind::Underflow);
+    assert_eq!(i256::MIN.cast::<i128>().unwrap_err().loss(), -2f64.powi(255) + 2f64.powi(127));
+    assert_eq!((-7i8).cast::<i256>().lossless().cast::<i8>().unwrap(), -7);
+    assert_eq!((-7i8).cast::<i256>().lossless().cast::<u64>().wrapping(), (-7i64) as u64);
+
+    // Into nonzeros
+    assert_eq!(u256::MAX.cast::<NonZeroU8>().closest(), NonZeroU8::MAX);
+    assert_eq!(i256::default().cast::<NonZeroU8>().closest(), NonZeroU8::MIN);
+    assert!(i256::default().cast::<NonZeroI32>().is_err());
+    assert_eq!(i256::MIN.cast::<NonZeroI32>().closest(), NonZeroI32::MIN);
+    let value = (-5i8).cast::<i256>().lossless();
+    assert_eq!(value.cast::<NonZeroI32>().unwrap(), NonZeroI32::new(-5).unwrap());
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn floats() {
+    // Into floats, rounding to nearest with ties to even
+    let two = |power: u32| match power {
+        0 ..= 127 => u256::from_words([1 << power, 0]),
+        _ => u256::from_words([0, 1 << (power - 128)])
+    };
+
+    assert_eq!(two(200).cast::<f64>().unwrap(), 2f64.powi(200));
+    assert_eq!(u256::MAX.cast::<f64>().lossy(), 2f64.powi(256));
+    assert_eq!(u256::MAX.cast::<f64>().unwrap_err().loss(), -1.0);
+    assert_eq!(u256::MAX.cast::<f32>().lossy(), f32::INFINITY);
+    assert_eq!(u256::MAX.cast::<f32>().closest(), f32::MAX);
+    assert_eq!(i256::MIN.cast::<f64>().unwrap(), -2f64.powi(255));
+    assert_eq!(i256::MIN.cast::<f32>().closest(), f32::MIN);
+    assert_eq!(i256::MAX.cast::<f64>().unwrap_err().kind(), LossKind::Precision);
+
+    // The bit beyond the last place of an f64 at 2^200 is 2^147, and the sticky bits lie far below
+    let tie = u256::from_words([0, (1 << 72) | (1 << 19)]);
+    assert_eq!(tie.cast::<f64>().lossy(), 2f64.powi(200));
+    let above = u256::from_words([1, (1 << 72) | (1 << 19)]);
+    assert_eq!(above.cast::<f64>().lossy(), 2f64.powi(200) + 2f64.powi(148));
+
+    // From floats
+    assert_eq!(2f64.powi(200).cast::<u256>().unwrap(), two(200));
+    assert_eq!((-2f64.powi(255)).cast::<i256>().unwrap(), i256::MIN);
+    assert_eq!(2f64.powi(255).cast::<i256>().closest(), i256::MAX);
+    assert_eq!(2f64.powi(255).cast::<i256>().unwrap_err().kind(), LossKind::Overflow);
+    assert_eq!(2f64.powi(256).cast::<u256>().lossy(), u256::MAX);
+    let expected = f32::MAX.cast::<u128>().unwrap().cast().lossless();
+    assert_eq!(f32::MAX.cast::<u256>().unwrap(), expected);
+    assert_eq!(f64::INFINITY.cast::<u256>().closest(), u256::MAX);
+    assert_eq!(f64::NEG_INFINITY.cast::<i256>().closest(), i256::MIN);
+    assert_eq!(f64::NAN.cast::<i256>().closest(), i256::default());
+    assert_eq!((-0.5f32).cast::<u256>().closest(), u256::MIN);
+    assert_eq!((-2.5f64).cast::<i256>().closest(), (-3i8).cast().lossless());
+    assert_eq!((-2.5f64).cast::<i256>().rounded(RoundingMode::HalfEven), (-2i8).cast().lossless());
+    assert_eq!(2.5f32.cast::<u256>().rounded(RoundingMode::Floor), 2u8.cast().lossless());
+    assert_eq!((-2.5f32).cast::<u256>().rounded(RoundingMode::Ceil), u256::MIN);
+    assert_eq!((-1.5f32).cast_split::<u256>().unwrap_err().to, u256::MIN);
+    assert_eq!((-0.5f32).cast_split::<u256>().unwrap(), (u256::MIN, -0.5));
+    assert_eq!((-2f64.powi(255)).cast_split::<i256>().unwrap(), (i256::MIN, 0.0));
+
+    // Wrapping reduces modulo 2^256
+    assert_eq!(2f64.powi(256).cast::<u256>().wrapping(), u256::MIN);
+    assert_eq!((-1f64).cast::<u256>().wrapping(), u256::MAX);
+    assert_eq!((2f64.powi(300) + 2f64.powi(250)).cast::<u256>().wrapping(), two(250));
+    assert_eq!(2f64.powi(255).cast::<i256>().wrapping(), i256::MIN);
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn random() {
+    // Initialization: determine the initial seed
+    let mut random = crate::util::random_seed();
+
+    // Perform the tests
+    for _ in 0 .. crate::util::settings::SLOW_ITERATIONS {
+        // Generate the test values and next random number
+        let (buffer, next_random) = crate::util::random_bytes(random);
+        let integer = i128::from_ne_bytes(buffer);
+        let float = f64::from_ne_bytes(buffer[.. 8].try_into().unwrap());
+        random = next_random;
+
+        // Integers agree with the 128-bit primitives in both directions
+        let wide = integer.cast::<i256>().lossless();
+        assert_eq!(wide.cast::<i128>().unwrap(), integer);
+        assert_eq!(wide.cast::<u128>().is_ok(), integer >= 0);
+        assert_eq!(wide.cast::<u128>().closest(), integer.cast::<u128>().closest());
+        assert_eq!(wide.cast::<u64>().wrapping(), integer.cast::<u64>().wrapping());
+        assert_eq!(wide.cast::<u256>().bitwise().cast::<i128>().wrapping(), integer);
+        assert_eq!(wide.cast::<f64>().lossy(), integer.cast::<f64>().lossy());
+        assert_eq!(wide.cast::<f64>().is_ok(), integer.cast::<f64>().is_ok());
+        assert_eq!(wide.cast::<f32>().lossy(), integer.cast::<f32>().lossy());
+
+        // Floats agree with the 128-bit primitives where those do not overflow, including NaN
+        if float.is_nan() || float.abs() < 2f64.powi(127) {
+            let (casted, primitive) = (float.cast::<i256>(), float.cast::<i128>());
+            let mode = RoundingMode::HalfEven;
+            assert_eq!(casted.is_ok(), primitive.is_ok());
+            assert_eq!(casted.closest(), primitive.closest().cast().lossless());
+            assert_eq!(casted.rounded(mode), primitive.rounded(mode).cast().lossless());
+            assert_eq!(casted.wrapping(), primitive.wrapping().cast().lossless());
+        }
+
+        // Finite values round trip through i256 when within range
+        if float.is_finite() && float.abs() < 2f64.powi(255) {
+            let rounded = float.cast::<i256>().rounded(RoundingMode::TowardZero);
+            assert_eq!(rounded.cast::<f64>().unwrap(), float.trunc());
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! These tests cover the `Within` trait for lossy casts between numerical types
+
+use cove::prelude::*;
+use cove::casts::Tolerance;
+use cove::types::Ranged;
+use core::num::{NonZeroI32, NonZeroU32};
+
+#[test]
+fn lossless() {
+    assert_eq!(7u8.cast::<u16>().within(Tolerance::Absolute(0.0)), Ok(7u16));
+    assert_eq!(7f32.cast::<u8>().within(Tolerance::Ulps(0)), Ok(7u8));
+    assert_eq!(0.5f64.cast::<f32>().within(Tolerance::Relative(0.0)), Ok(0.5f32));
+}
+
+#[test]
+fn absolute() {
+    assert_eq!(2.5f32.cast::<u8>().within(Tolerance::Absolute(0.5)), Ok(2u8));
+    assert!(2.5f32.cast::<u8>().within(Tolerance::Absolute(0.49)).is_err());
+    assert_eq!((-2.5f64).cast::<i64>().within(Tolerance::Absolute(0.5)), Ok(-2i64));
+    assert_eq!(300u16.cast::<u8>().within(Tolerance::Absolute(256.0)), Ok(44u8));
+    assert!(300u16.cast::<u8>().within(Tolerance::Absolute(255.0)).is_err());
+
+    // The difference need not fit in either type, nor be representable by f32
+    let error = u128::MAX.cast::<f32>().unwrap_err();
+    assert_eq!(error.within(Tolerance::Absolute(f64::MAX)), Err(error));
+    assert_eq!(
+        (u64::MAX - 1000).cast::<f64>().within(Tolerance::Absolute(1001.0)),
+        Ok(18_446_744_073_709_551_616f64)
+    );
+    assert!((u64::MAX - 1000).cast::<f64>().within(Tolerance::Absolute(1000.0)).is_err());
+}
+
+#[test]
+fn relative() {
+    let value = 16_777_217u32;
+    assert_eq!(value.cast::<f32>().within(Tolerance::Relative(1e-7)), Ok(16_777_216f32));
+    assert!(value.cast::<f32>().within(Tolerance::Relative(5e-8)).is_err());
+    assert_eq!(99.9f64.cast::<u8>().within(Tolerance::Relative(0.01)), Ok(99u8));
+    assert!(0.9f64.cast::<u8>().within(Tolerance::Relative(0.5)).is_err());
+}
+
+#[test]
+fn ulps() {
+    // Integer targets measure ulps as whole units
+    assert_eq!(1.99f32.cast::<i8>().within(Tolerance::Ulps(1)), Ok(1i8));
+    assert!(1.99f32.cast::<i8>().within(Tolerance::Ulps(0)).is_err());
+
+    // Float targets measure ulps at the lossy value
+    assert_eq!(0.1f64.cast::<f32>().within(Tolerance::Ulps(1)), Ok(0.1f32));
+    assert!(0.1f64.cast::<f32>().within(Tolerance::Ulps(0)).is_err());
+    assert_eq!(i64::MAX.cast::<f64>().within(Tolerance::Ulps(1)), Ok(9.223_372_036_854_776e18));
+    assert_eq!(1e-40f64.cast::<f32>().within(Tolerance::Ulps(1)), Ok(1e-40f32));
+
+    // u128::MAX + 1 is the nearest f64, a single unit away; its ulp is far larger
+    assert!(u128::MAX.cast::<f64>().within(Tolerance::Ulps(1)).is_ok());
+    assert!(u128::MAX.cast::<f64>().within(Tolerance::Absolute(0.5)).is_err());
+    assert!(u128::MAX.cast::<f64>().within(Tolerance::Absolute(1.0)).is_ok());
+    assert!(i128::MIN.cast::<f32>().within(Tolerance::Absolute(0.0)).is_ok());
+    assert!((i128::MIN + 1).cast::<f32>().within(Tolerance::Absolute(0.5)).is_err());
+    assert!((i128::MIN + 1).cast::<f32>().within(Tolerance::Absolute(1.0)).is_ok());
+}
+
+#[test]
+fn special() {
+    assert!(f32::NAN.cast::<u8>().within(Tolerance::Absolute(f64::MAX)).is_err());
+    assert!(f64::INFINITY.cast::<i32>().within(Tolerance::Relative(1.0)).is_err());
+    assert!(f32::NEG_INFINITY.cast::<u64>().within(Tolerance::Absolute(f64::INFINITY)).is_err());
+    assert!(1e300f64.cast::<f32>().within(Tolerance::Ulps(u64::MAX)).is_err());
+    assert!(2.5f64.cast::<u8>().within(Tolerance::Absolute(f64::NAN)).is_err());
+    assert!(2.5f64.cast::<u8>().within(Tolerance::Relative(f64::NAN)).is_err());
+}
+
+#[test]
+fn nonzero() {
+    let value = NonZeroI32::new(-1).unwrap();
+    assert_eq!(value.cast::<u8>().within(Tolerance::Absolute(256.0)), Ok(255u8));
+    assert!(value.cast::<u8>().within(Tolerance::Absolute(255.0)).is_err());
+    assert!(value.cast::<NonZeroU32>().within(Tolerance::Ulps(1 << 32)).is_ok());
+    assert!(value.cast::<NonZeroU32>().within(Tolerance::Ulps(u32::MAX.into())).is_err());
+}
+
+#[test]
+fn ranged() {
+    let value = Ranged::<i32, -300, 300>::new(-300).unwrap();
+    assert!(value.cast::<i8>().within(Tolerance::Absolute(256.0)).is_ok());
+    assert!(value.cast::<i8>().within(Tolerance::Absolute(255.0)).is_err());
+}
@@ -0,0 +1,84 @@
+//! These tests cover casts to, from, and between the `Wrapping` and `Saturating` wrappers from
+//! `core::num`, which should behave exactly as the types they wrap
+
+use cove::prelude::*;
+use cove::casts::Tolerance;
+use cove::errors::{LossKind, LossyCastError};
+use core::num::{NonZeroU8, Saturating, Wrapping};
+
+#[test]
+fn from_wrappers() {
+    // Lossless casts
+    assert_eq!(Wrapping(200u8).cast::<u16>().lossless(), 200u16);
+    assert_eq!(Saturating(-7i16).cast::<i64>().lossless(), -7i64);
+    assert_eq!(Wrapping(u32::MAX).cast::<f64>().lossless(), f64::from(u32::MAX));
+    assert_eq!(Saturating(5u8).cast::<u8>().unwrap(), 5u8);
+
+    // Lossy casts report the wrapper as the source
+    let error = Wrapping(300u32).cast::<u8>().unwrap_err();
+    assert_eq!(error, LossyCastError {from: Wrapping(300u32), to: 44u8});
+    assert_eq!(error.kind(), LossKind::Overflow);
+    assert_eq!(error.loss(), 256.0);
+    assert_eq!(Saturating(-1i16).cast::<u16>().unwrap_err().kind(), LossKind::Underflow);
+    assert_eq!(Wrapping(300u32).cast::<u8>().lossy(), 44u8);
+    assert_eq!(Wrapping(300u32).cast::<u8>().closest(), u8::MAX);
+    assert_eq!(Saturating(-1i16).cast::<u16>().bitwise(), u16::MAX);
+    assert_eq!(Wrapping(-1i32).cast::<f32>().unwrap(), -1f32);
+    assert_eq!(Saturating(u64::MAX).cast::<f32>().closest(), 2f32.powi(64));
+    assert_eq!(Wrapping(2.5f64).cast::<i8>().closest(), 3i8);
+    assert_eq!(Saturating(f32::NAN).cast::<u8>().unwrap_err().kind(), LossKind::NaN);
+    assert_eq!(Wrapping(0u8).cast::<NonZeroU8>().closest(), NonZeroU8::MIN);
+    assert!(Wrapping(1.5f32).cast::<u8>().within(Tolerance::Absolute(1.0)).is_ok());
+}
+
+#[test]
+fn into_wrappers() {
+    // Lossless casts
+    assert_eq!(200u8.cast::<Wrapping<u16>>().lossless(), Wrapping(200u16));
+    assert_eq!((-7i8).cast::<Saturating<f32>>().lossless(), Saturating(-7f32));
+    assert_eq!(5u8.cast::<Wrapping<u8>>().unwrap(), Wrapping(5u8));
+
+    // Lossy casts report the wrapper as the target
+    let error = 300u32.cast::<Wrapping<u8>>().unwrap_err();
+    assert_eq!(error, LossyCastError {from: 300u32, to: Wrapping(44u8)});
+    assert_eq!(error.kind(), LossKind::Overflow);
+    assert_eq!(error.loss(), 256.0);
+    assert_eq!((-1i16).cast::<Saturating<u16>>().lossy(), Saturating(u16::MAX));
+    assert_eq!((-1i16).cast::<Saturating<u16>>().closest(), Saturating(0u16));
+    assert_eq!((-1i16).cast::<Saturating<u16>>().bitwise(), Saturating(u16::MAX));
+    assert_eq!(u16::MAX.cast::<Wrapping<i16>>().bitwise(), Wrapping(-1i16));
+    assert_eq!(1e10f64.cast::<Wrapping<i32>>().closest(), Wrapping(i32::MAX));
+    assert_eq!((-0.5f32).cast::<Saturating<u8>>().closest(), Saturating(0u8));
+    assert_eq!(u128::MAX.cast::<Wrapping<f32>>().lossy(), Wrapping(2f32.powi(128)));
+    assert_eq!(16_777_217u32.cast::<Wrapping<f32>>().unwrap_err().kind(), LossKind::Precision);
+}
+
+#[test]
+fn between_wrappers() {
+    assert_eq!(Wrapping(200u8).cast::<Saturating<u16>>().lossless(), Saturating(200u16));
+    assert_eq!(Saturating(7u8).cast::<Wrapping<u8>>().lossless(), Wrapping(7u8));
+    assert_eq!(Wrapping(300u16).cast::<Saturating<u8>>().lossy(), Saturating(44u8));
+    assert_eq!(Wrapping(300u16).cast::<Saturating<u8>>().closest(), Saturating(u8::MAX));
+    assert_eq!(Saturating(-1i8).cast::<Wrapping<u8>>().bitwise(), Wrapping(u8::MAX));
+    assert_eq!(Wrapping(-1i8).cast::<Wrapping<i64>>().lossless(), Wrapping(-1i64));
+
+    let error = Saturating(-2i32).cast::<Wrapping<u32>>().unwrap_err();
+    assert_eq!(error.from, Saturating(-2i32));
+    assert_eq!(error.to, Wrapping(u32::MAX - 1));
+    assert_eq!(error.kind(), LossKind::Underflow);
+}
+
+#[test]
+fn exhaustive() {
+    // Every cast agrees with the cast of the wrapped values
+    for value in i16::MIN ..= i16::MAX {
+        let (wrapping, saturating) = (Wrapping(value), Saturating(value));
+        assert_eq!(wrapping.cast::<u8>().lossy(), value.cast::<u8>().lossy());
+        assert_eq!(wrapping.cast::<u8>().closest(), value.cast::<u8>().closest());
+        assert_eq!(saturating.cast::<i8>().is_ok(), value.cast::<i8>().is_ok());
+        assert_eq!(saturating.cast::<u16>().bitwise(), value.cast::<u16>().bitwise());
+        assert_eq!(value.cast::<Wrapping<u8>>().lossy().0, value.cast::<u8>().lossy());
+        assert_eq!(value.cast::<Saturating<i8>>().closest().0, value.cast::<i8>().closest());
+        assert_eq!(wrapping.cast::<Saturating<i32>>().lossless().0, i32::from(value));
+    }
+}
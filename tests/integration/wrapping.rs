@@ -0,0 +1,154 @@
+//! These tests cover the `Wrapping` trait for primitives and `NonZero*` to primitive integers
+
+use cove::prelude::*;
+use core::num::{NonZeroI32, NonZeroU128};
+
+#[test]
+fn integer() {
+    assert_eq!(7u32.cast::<u8>().wrapping(), 7u8);
+    assert_eq!(256u16.cast::<u8>().wrapping(), 0u8);
+    assert_eq!((-2i8).cast::<u64>().wrapping(), u64::MAX - 1);
+    assert_eq!(u128::MAX.cast::<i16>().wrapping(), -1i16);
+    assert_eq!(NonZeroI32::new(-129).unwrap().cast::<i8>().wrapping(), 127i8);
+    assert_eq!(NonZeroU128::new(1 << 64).unwrap().cast::<u64>().wrapping(), 0u64);
+}
+
+#[test]
+fn float() {
+    assert_eq!(255.99f32.cast::<u8>().wrapping(), 255u8);
+    assert_eq!(256.5f64.cast::<u8>().wrapping(), 0u8);
+    assert_eq!((-0.9f32).cast::<u8>().wrapping(), 0u8);
+    assert_eq!((-1f32).cast::<u8>().wrapping(), 255u8);
+    assert_eq!(128f64.cast::<i8>().wrapping(), -128i8);
+    assert_eq!(4_294_967_296f64.cast::<u32>().wrapping(), 0u32);
+    assert_eq!(4_294_967_297f64.cast::<u32>().wrapping(), 1u32);
+    assert_eq!(1e300f64.cast::<u128>().wrapping(), 0u128);
+    assert_eq!(f32::MAX.cast::<u128>().wrapping(), 0xFFFF_FF00_0000_0000_0000_0000_0000_0000);
+    assert_eq!(f32::MIN.cast::<i128>().wrapping(), 1i128 << 104);
+    assert_eq!(f32::MIN_POSITIVE.cast::<u8>().wrapping(), 0u8);
+}
+
+#[test]
+fn special() {
+    assert_eq!(f32::NAN.cast::<u16>().wrapping(), 0u16);
+    assert_eq!(f64::INFINITY.cast::<i64>().wrapping(), 0i64);
+    assert_eq!(f64::NEG_INFINITY.cast::<usize>().wrapping(), 0usize);
+}
+
+macro_rules! random {
+    ($name:ident as $source:ty => $($target:ty),+) => {
+        #[test]
+        #[allow(
+            clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_sign_loss,
+            clippy::cast_lossless
+        )]
+        fn $name () {
+            // Initialization: allocate space for the test buffers and determine the initial seed
+            let mut random = crate::util::random_seed();
+
+            // Perform the tests
+            for _ in 0 .. crate::util::settings::FAST_ITERATIONS {
+                // Generate the test value and next random number
+                let (buffer, next_random) = crate::util::random_bytes(random);
+                let value = <$source>::from_ne_bytes(buffer);
+                random = next_random;
+
+                // Validate that the cast yields the same value as the reference for each type
+                $(
+                    assert_eq!(
+                        value.cast::<$target>().wrapping(),
+                        Reference::<$target>::reference(value)
+                    );
+                )*
+            }
+        }
+    };
+}
+
+/// Helper trait for producing the reference implementation of wrapping casts
+trait Reference<T> {
+    /// Returns `self` wrapped to `T` without using cove
+    fn reference(self) -> T;
+}
+
+macro_rules! reference_impl {
+    (integer $($source:ty),+ => $targets:tt) => {
+        $(reference_impl!(@integer $source => $targets);)*
+    };
+
+    // The reference wrapping for integers is the `as` keyword
+    (@integer $source:ty => {$($target:ty),+}) => {
+        $(
+            #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+            #[allow(clippy::cast_sign_loss, clippy::cast_lossless)]
+            impl Reference<$target> for $source {
+                fn reference(self) -> $target {
+                    self as $target
+                }
+            }
+        )*
+    };
+
+    (float $($source:ty),+ => $targets:tt) => {
+        $(reference_impl!(@float $source => $targets);)*
+    };
+
+    (@float $source:ty => {$($target:ty),+}) => {
+        $(
+            #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+            #[allow(clippy::cast_sign_loss, clippy::cast_lossless)]
+            impl Reference<$target> for $source {
+                fn reference(self) -> $target {
+                    // Truncate and take the remainder in f64, which is exact, then apply the 
+                    // sign via two's complement and reinterpret via the `as` keyword
+                    let value = self as f64;
+                    let bits = i32::try_from(core::mem::size_of::<$target>() * 8).unwrap();
+                    let remainder = value.trunc() % 2f64.powi(bits);
+                    match remainder.is_finite() {
+                        true if remainder < 0.0 => ((-remainder) as u128).wrapping_neg() as $target,
+                        true => remainder as u128 as $target,
+                        false => 0
+                    }
+                }
+            }
+        )*
+    };
+}
+
+reference_impl!(
+    integer u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize => 
+    {u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize}
+);
+
+reference_impl!(
+    float f32, f64 => 
+    {u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize}
+);
+
+random!(random_u8    as u8    => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+random!(random_u16   as u16   => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+random!(random_u32   as u32   => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+random!(random_u64   as u64   => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+random!(random_u128  as u128  => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+random!(random_usize as usize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+random!(random_i8    as i8    => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+random!(random_i16   as i16   => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+random!(random_i32   as i32   => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+random!(random_i64   as i64   => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+random!(random_i128  as i128  => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+random!(random_isize as isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+random!(random_f32   as f32   => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+random!(random_f64   as f64   => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// The prelude's Wrapping trait remains usable when core::num::Wrapping is imported by name
+mod shadowed {
+    use cove::prelude::*;
+    use core::num::Wrapping;
+
+    #[test]
+    fn core_wrapping() {
+        assert_eq!(300u32.cast::<u8>().wrapping(), 44u8);
+        assert_eq!(Wrapping(300u32).cast::<u8>().lossy().cast::<u16>().wrapping(), 44u16);
+        assert_eq!(Wrapping(-1i16).cast::<i64>().lossless(), -1i64);
+    }
+}